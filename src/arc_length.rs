@@ -0,0 +1,366 @@
+//! Arc-length computations.
+//!
+//! The arc length of a spline is the distance travelled along the curve between two sampling
+//! values. It is computed by integrating the norm of the derivative of the curve with an adaptive
+//! Gauss–Legendre quadrature, segment by segment.
+//!
+//! If you need to convert between sampling values and distances many times for the same spline,
+//! build an [`ArcLengthTable`] once and share it.
+
+use crate::interpolate::{InnerSpace, Interpolate, Scalar};
+use crate::key::Key;
+use crate::segment::Shape;
+use crate::spline::{sample_segment, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Relative tolerance of the adaptive quadrature.
+const TOLERANCE: f64 = 1e-6;
+
+/// Maximum number of subdivisions of the adaptive quadrature.
+const MAX_DEPTH: u32 = 16;
+
+/// Maximum number of iterations when inverting the arc length of a segment.
+const MAX_INVERSION_ITERATIONS: u32 = 32;
+
+/// Nodes and weights of the 5-point Gauss–Legendre quadrature on `[-1; 1]`.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+  (0., 0.568_888_888_888_888_9),
+  (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+  (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+  (-0.906_179_845_938_664, 0.236_926_885_056_189_08),
+  (0.906_179_845_938_664, 0.236_926_885_056_189_08),
+];
+
+impl<T, V> Spline<T, V> {
+  /// Compute the arc length of the spline between two sampling values.
+  ///
+  /// If `t1` is lower than `t0`, the length between `t1` and `t0` is returned. Discontinuities
+  /// (such as the ones introduced by [`Interpolation::Step`]) don’t contribute to the length.
+  ///
+  /// # Return
+  ///
+  /// `None` if `t0` or `t1` lies outside of the spline, or if a segment between them cannot be
+  /// sampled (see [`Spline::sample`]).
+  ///
+  /// [`Interpolation::Step`]: crate::interpolation::Interpolation::Step
+  pub fn arc_length(&self, t0: T, t1: T) -> Option<T>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let keys = self.keys();
+    let (t0, t1) = if t1 < t0 { (t1, t0) } else { (t0, t1) };
+
+    if keys.len() < 2 || t0 < keys[0].t || t1 > keys[keys.len() - 1].t {
+      return None;
+    }
+
+    let start = keys.partition_point(|key| key.t <= t0).max(1) - 1;
+    let mut length = T::zero();
+
+    for i in start..keys.len() - 1 {
+      let cp0 = &keys[i];
+      let cp1 = &keys[i + 1];
+
+      if cp0.t >= t1 {
+        break;
+      }
+
+      let a = if t0 > cp0.t { t0 } else { cp0.t };
+      let b = if t1 < cp1.t { t1 } else { cp1.t };
+      let shape = Shape::new(keys, i)?;
+
+      length =
+        length + segment_length(&shape, a.normalize(cp0.t, cp1.t), b.normalize(cp0.t, cp1.t));
+    }
+
+    Some(length)
+  }
+
+  /// Sample the spline at a given distance along the curve.
+  ///
+  /// Contrary to [`Spline::sample`], which is parameterized by the sampling value, this method is
+  /// parameterized by the arc length: moving `s` at a constant rate moves along the curve at a
+  /// constant speed, whatever the interpolation modes and the spacing of the keys.
+  ///
+  /// A table of the arc lengths of every segment is computed on each call, which is *O(n)*. If you
+  /// need to sample the same spline many times, build an [`ArcLengthTable`] once and sample at
+  /// [`ArcLengthTable::t_at_length`] instead.
+  ///
+  /// # Return
+  ///
+  /// `None` if `s` is negative or greater than the total length of the spline, or if a segment
+  /// cannot be sampled (see [`Spline::sample`]).
+  pub fn sample_at_distance(&self, s: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    let keys = self.keys();
+    let lengths = cumulative_lengths(keys)?;
+    let t = t_at_distance(keys, &lengths, s)?;
+    let i = segment_at_distance(&lengths, s);
+
+    sample_segment(keys, i, t)
+  }
+}
+
+/// Lookup table mapping sampling values to arc lengths, and back.
+///
+/// The table is built once from a [`Spline`] by sampling the arc length of every segment at a
+/// given resolution. Queries are then answered in *O(log n)* by linearly interpolating between the
+/// entries of the table: the higher the resolution, the more accurate the results.
+///
+/// The table doesn’t borrow the spline, so it can be stored and shared freely – e.g. among all the
+/// entities following the same path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLengthTable<T> {
+  ts: Vec<T>,
+  lengths: Vec<T>,
+}
+
+impl<T> ArcLengthTable<T> {
+  /// Build the table of a spline, with `resolution` entries per segment.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has less than two keys, or if a segment cannot be sampled (see
+  /// [`Spline::sample`]).
+  pub fn new<V>(spline: &Spline<T, V>, resolution: usize) -> Option<Self>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let keys = spline.keys();
+
+    if keys.len() < 2 {
+      return None;
+    }
+
+    let resolution = resolution.max(1);
+    let step = T::one() / T::from_f64(resolution as f64);
+    let mut ts = Vec::with_capacity((keys.len() - 1) * resolution + 1);
+    let mut lengths = Vec::with_capacity(ts.capacity());
+    let mut length = T::zero();
+
+    ts.push(keys[0].t);
+    lengths.push(length);
+
+    for i in 0..keys.len() - 1 {
+      let cp0 = &keys[i];
+      let cp1 = &keys[i + 1];
+      let shape = Shape::new(keys, i)?;
+      let mut nt = T::zero();
+
+      for j in 1..=resolution {
+        let next = if j == resolution { T::one() } else { nt + step };
+
+        length = length + segment_length(&shape, nt, next);
+        ts.push(cp0.t + (cp1.t - cp0.t) * next);
+        lengths.push(length);
+        nt = next;
+      }
+    }
+
+    Some(ArcLengthTable { ts, lengths })
+  }
+
+  /// Total length of the spline.
+  pub fn total_length(&self) -> T
+  where
+    T: Copy,
+  {
+    self.lengths[self.lengths.len() - 1]
+  }
+
+  /// Arc length from the beginning of the spline to the sampling value `t`.
+  ///
+  /// Return `None` if `t` lies outside of the spline.
+  pub fn length_at_t(&self, t: T) -> Option<T>
+  where
+    T: Scalar,
+  {
+    lookup(&self.ts, &self.lengths, t)
+  }
+
+  /// Sampling value at which the arc length from the beginning of the spline reaches `s`.
+  ///
+  /// Return `None` if `s` is negative or greater than [`ArcLengthTable::total_length`].
+  pub fn t_at_length(&self, s: T) -> Option<T>
+  where
+    T: Scalar,
+  {
+    lookup(&self.lengths, &self.ts, s)
+  }
+}
+
+// Find x in the sorted xs and linearly interpolate the corresponding ys.
+fn lookup<T>(xs: &[T], ys: &[T], x: T) -> Option<T>
+where
+  T: Scalar,
+{
+  if x < xs[0] || x > xs[xs.len() - 1] {
+    return None;
+  }
+
+  let i = xs.partition_point(|entry| *entry < x);
+
+  if i == 0 {
+    return Some(ys[0]);
+  }
+
+  let (x0, x1) = (xs[i - 1], xs[i]);
+  let (y0, y1) = (ys[i - 1], ys[i]);
+
+  Some(y0 + (y1 - y0) * x.normalize(x0, x1))
+}
+
+/// Cumulative arc lengths of a spline, at every key.
+///
+/// The first length is always zero and the last one is the total length of the spline.
+pub(crate) fn cumulative_lengths<T, V>(keys: &[Key<T, V>]) -> Option<Vec<T>>
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  if keys.len() < 2 {
+    return None;
+  }
+
+  let mut lengths = Vec::with_capacity(keys.len());
+  let mut length = T::zero();
+  lengths.push(length);
+
+  for i in 0..keys.len() - 1 {
+    let shape = Shape::new(keys, i)?;
+    length = length + segment_length(&shape, T::zero(), T::one());
+    lengths.push(length);
+  }
+
+  Some(lengths)
+}
+
+/// Index of the segment in which a distance lies, given the cumulative lengths of a spline.
+pub(crate) fn segment_at_distance<T>(lengths: &[T], s: T) -> usize
+where
+  T: PartialOrd,
+{
+  lengths
+    .partition_point(|length| *length <= s)
+    .clamp(1, lengths.len() - 1)
+    - 1
+}
+
+/// Sampling value at a given distance along a spline, given its cumulative lengths.
+pub(crate) fn t_at_distance<T, V>(keys: &[Key<T, V>], lengths: &[T], s: T) -> Option<T>
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  if s < T::zero() || s > lengths[lengths.len() - 1] {
+    return None;
+  }
+
+  let i = segment_at_distance(lengths, s);
+  let cp0 = &keys[i];
+  let cp1 = &keys[i + 1];
+  let shape = Shape::new(keys, i)?;
+  let nt = invert_segment_length(&shape, s - lengths[i], lengths[i + 1] - lengths[i]);
+
+  Some(cp0.t + (cp1.t - cp0.t) * nt)
+}
+
+/// Find the normalized time at which the length of a segment reaches `target`.
+///
+/// A Newton iteration is used, falling back to bisection whenever it leaves the bracketing interval.
+fn invert_segment_length<T, V>(shape: &Shape<T, V>, target: T, total: T) -> T
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  if total <= T::zero() {
+    return T::zero();
+  }
+
+  let tolerance = T::from_f64(TOLERANCE) * total;
+  let mut low = T::zero();
+  let mut high = T::one();
+  let mut nt = target / total;
+
+  for _ in 0..MAX_INVERSION_ITERATIONS {
+    let error = segment_length(shape, T::zero(), nt) - target;
+
+    if error.abs() <= tolerance {
+      break;
+    }
+
+    if error > T::zero() {
+      high = nt;
+    } else {
+      low = nt;
+    }
+
+    let speed = shape.derivatives(nt)[1].norm();
+    let next = if speed > T::zero() {
+      nt - error / speed
+    } else {
+      low
+    };
+
+    nt = if next > low && next < high {
+      next
+    } else {
+      (low + high) * T::from_f64(0.5)
+    };
+  }
+
+  nt
+}
+
+/// Length of a segment between two normalized times.
+pub(crate) fn segment_length<T, V>(shape: &Shape<T, V>, a: T, b: T) -> T
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  let speed = |nt| shape.derivatives(nt)[1].norm();
+  let whole = gauss_legendre(&speed, a, b);
+
+  adaptive_gauss_legendre(&speed, a, b, whole, MAX_DEPTH)
+}
+
+fn gauss_legendre<T, F>(f: &F, a: T, b: T) -> T
+where
+  T: Scalar,
+  F: Fn(T) -> T,
+{
+  let half = T::from_f64(0.5);
+  let center = (a + b) * half;
+  let radius = (b - a) * half;
+
+  GAUSS_LEGENDRE
+    .iter()
+    .fold(T::zero(), |sum, &(node, weight)| {
+      sum + T::from_f64(weight) * f(center + radius * T::from_f64(node))
+    })
+    * radius
+}
+
+fn adaptive_gauss_legendre<T, F>(f: &F, a: T, b: T, whole: T, depth: u32) -> T
+where
+  T: Scalar,
+  F: Fn(T) -> T,
+{
+  let middle = (a + b) * T::from_f64(0.5);
+  let left = gauss_legendre(f, a, middle);
+  let right = gauss_legendre(f, middle, b);
+  let refined = left + right;
+
+  if depth == 0 || (refined - whole).abs() <= T::from_f64(TOLERANCE) * refined.abs() {
+    refined
+  } else {
+    adaptive_gauss_legendre(f, a, middle, left, depth - 1)
+      + adaptive_gauss_legendre(f, middle, b, right, depth - 1)
+  }
+}
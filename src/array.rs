@@ -0,0 +1,168 @@
+//! Fixed-capacity splines.
+//!
+//! A [`ConstSpline`] stores up to `N` keys inline, in an array, along with the number of keys in
+//! use. It never allocates – neither when created nor when keys are added or removed – so that it
+//! can be used in code paths where allocating is not allowed, such as audio callbacks or interrupt
+//! handlers, where [`Spline`] can’t be built or edited.
+//!
+//! The crate itself still depends on `alloc` in `no_std` builds: targets without any allocator are
+//! not supported yet.
+//!
+//! [`Spline`]: crate::spline::Spline
+
+use crate::interpolate::{Interpolate, Interpolator};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::{clamped_sample_hinted, sample_hinted, KeyMut};
+
+/// A spline holding at most `N` keys, stored inline.
+///
+/// Keys are kept sorted by sampling value, and sampling follows the same rules as
+/// [`Spline`](crate::spline::Spline). The slots past the keys in use hold keys made of default
+/// values, which are never observed.
+#[derive(Clone, Copy, Debug)]
+pub struct ConstSpline<T, V, const N: usize> {
+  keys: [Key<T, V>; N],
+  len: usize,
+}
+
+impl<T, V, const N: usize> ConstSpline<T, V, N> {
+  /// Create an empty spline.
+  pub fn new() -> Self
+  where
+    T: Default,
+    V: Default,
+  {
+    ConstSpline {
+      keys: core::array::from_fn(|_| {
+        Key::new(T::default(), V::default(), Interpolation::default())
+      }),
+      len: 0,
+    }
+  }
+
+  /// Maximum number of keys the spline can hold.
+  pub const fn capacity(&self) -> usize {
+    N
+  }
+
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Check whether the spline cannot hold any more key.
+  pub fn is_full(&self) -> bool {
+    self.len == N
+  }
+
+  /// Retrieve the keys of the spline.
+  pub fn keys(&self) -> &[Key<T, V>] {
+    &self.keys[..self.len]
+  }
+
+  /// Add a key into the spline, keeping the keys sorted.
+  ///
+  /// If the spline is full, the key is given back as an error.
+  pub fn add(&mut self, key: Key<T, V>) -> Result<(), Key<T, V>>
+  where
+    T: PartialOrd,
+  {
+    if self.is_full() {
+      return Err(key);
+    }
+
+    // insert after the keys with the same sampling value, as sorting a spline is stable
+    let index = self.keys().partition_point(|k| k.t <= key.t);
+    self.keys[index..=self.len].rotate_right(1);
+    self.keys[index] = key;
+    self.len += 1;
+
+    Ok(())
+  }
+
+  /// Remove a key from the spline.
+  pub fn remove(&mut self, index: usize) -> Option<Key<T, V>>
+  where
+    T: Default,
+    V: Default,
+  {
+    if index >= self.len {
+      return None;
+    }
+
+    // move the removed key past the keys in use, where it's replaced by a default one
+    self.keys[index..self.len].rotate_left(1);
+    self.len -= 1;
+    let key = core::mem::replace(
+      &mut self.keys[self.len],
+      Key::new(T::default(), V::default(), Interpolation::default()),
+    );
+
+    Some(key)
+  }
+
+  /// Remove all keys.
+  pub fn clear(&mut self) {
+    self.len = 0;
+  }
+
+  /// Get a key at a given index.
+  pub fn get(&self, index: usize) -> Option<&Key<T, V>> {
+    self.keys().get(index)
+  }
+
+  /// Mutably get a key at a given index.
+  ///
+  /// See [`KeyMut`] for further details.
+  pub fn get_mut(&mut self, index: usize) -> Option<KeyMut<'_, T, V>> {
+    self.keys[..self.len].get_mut(index).map(KeyMut::new)
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`](crate::spline::Spline::sample) for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    sample_hinted(self.keys(), &mut None, t)
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`](crate::spline::Spline::clamped_sample) for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    clamped_sample_hinted(self.keys(), &mut None, t)
+  }
+}
+
+impl<T, V, const N: usize> Default for ConstSpline<T, V, N>
+where
+  T: Default,
+  V: Default,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, V, const N: usize> PartialEq for ConstSpline<T, V, N>
+where
+  T: PartialEq,
+  V: PartialEq,
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.keys() == other.keys()
+  }
+}
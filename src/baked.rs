@@ -0,0 +1,203 @@
+//! Splines baked into polynomials.
+//!
+//! Sampling a [`Spline`] computes the shape of the sampled segment on every call: Catmull-Rom
+//! tangents, Bézier control points, etc. A [`BakedSpline`] computes them once and stores the
+//! polynomial coefficients of every segment, so that sampling is a binary search followed by a
+//! Horner evaluation. That’s typically worth it in hot loops sampling the same spline many times.
+//!
+//! The polynomials themselves are available with [`Spline::to_polynomials`], for instance to
+//! evaluate splines outside of this crate.
+
+use crate::interpolate::{Linear, Scalar};
+use crate::segment::Shape;
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A [`Spline`] with precomputed segments.
+///
+/// Sampling follows the same rules as [`Spline`] – including the fallback value (see
+/// [`Spline::set_fallback`]) – and gives the same values, up to rounding errors. Baked splines are
+/// immutable: bake the spline again after changing it.
+#[derive(Clone, Debug)]
+pub struct BakedSpline<T, V> {
+  times: Vec<T>,
+  // shape of every segment, if it can be sampled
+  segments: Vec<Option<Shape<T, V>>>,
+  // values of the first and last keys
+  ends: Option<(V, V)>,
+  fallback: Option<V>,
+}
+
+impl<T, V> BakedSpline<T, V> {
+  /// Bake a spline.
+  pub fn new(spline: &Spline<T, V>) -> Self
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let keys = spline.keys();
+
+    BakedSpline {
+      times: keys.iter().map(|key| key.t).collect(),
+      segments: (0..keys.len().saturating_sub(1))
+        .map(|i| Shape::new(keys, i))
+        .collect(),
+      ends: keys
+        .first()
+        .zip(keys.last())
+        .map(|(a, b)| (a.value, b.value)),
+      fallback: spline.fallback().copied(),
+    }
+  }
+
+  /// Number of keys of the baked spline.
+  pub fn len(&self) -> usize {
+    self.times.len()
+  }
+
+  /// Check whether the baked spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.times.is_empty()
+  }
+
+  /// Sample the baked spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    self.sample_keys(t).or(self.fallback)
+  }
+
+  /// Sample the baked spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    self
+      .sample_keys(t)
+      .or_else(|| {
+        let (first, last) = self.ends?;
+
+        if t <= self.times[0] {
+          Some(first)
+        } else if t >= self.times[self.times.len() - 1] {
+          Some(last)
+        } else {
+          None
+        }
+      })
+      .or(self.fallback)
+  }
+
+  // Sample the segments, without any fallback.
+  fn sample_keys(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    if let [time] = self.times[..] {
+      return self.ends.filter(|_| time == t).map(|(first, _)| first);
+    }
+
+    // index of the first key after t
+    let next = self.times.partition_point(|&time| time <= t);
+
+    if next == 0 || next >= self.times.len() {
+      return None;
+    }
+
+    let i = next - 1;
+    let shape = self.segments[i].as_ref()?;
+
+    Some(shape.value(t.normalize(self.times[i], self.times[next])))
+  }
+}
+
+/// Cubic polynomial of a segment.
+///
+/// With `[a, b, c, d]` the coefficients, the segment is `a + b nt + c nt² + d nt³`, where `nt` is
+/// the normalized time going from `0` at `start` to `1` at `end`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polynomial<T, V> {
+  /// Sampling value at the start of the segment.
+  pub start: T,
+  /// Sampling value at the end of the segment.
+  pub end: T,
+  /// Coefficients, by ascending degree, with respect to the normalized time.
+  pub coefficients: [V; 4],
+}
+
+impl<T, V> Polynomial<T, V> {
+  /// Coefficients, by ascending degree, with respect to `t - start` instead of the normalized time.
+  pub fn coefficients_in_t(&self) -> [V; 4]
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let [a, b, c, d] = self.coefficients;
+    let dt = self.end - self.start;
+
+    [
+      a,
+      b.outer_div(dt),
+      c.outer_div(dt * dt),
+      d.outer_div(dt * dt * dt),
+    ]
+  }
+
+  /// Value of the polynomial at a given sampling value.
+  ///
+  /// The polynomial is not restricted to its segment: it can be evaluated at any sampling value.
+  pub fn value(&self, t: T) -> V
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let [a, b, c, d] = self.coefficients;
+    let nt = t.normalize(self.start, self.end);
+
+    a + (b + (c + d.outer_mul(nt)).outer_mul(nt)).outer_mul(nt)
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Polynomials of the segments of the spline, one per pair of consecutive keys.
+  ///
+  /// Linear, Catmull-Rom and Bézier segments are cubic polynomials at most. Step and cosine
+  /// segments are not, and neither are segments that cannot be sampled (see [`Spline::sample`]):
+  /// they yield `None`.
+  pub fn to_polynomials(&self) -> Vec<Option<Polynomial<T, V>>>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let keys = self.keys();
+
+    (0..keys.len().saturating_sub(1))
+      .map(|i| match Shape::new(keys, i)? {
+        Shape::Cubic(coefficients) => Some(Polynomial {
+          start: keys[i].t,
+          end: keys[i + 1].t,
+          coefficients,
+        }),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// Bake the spline (see [`BakedSpline`]).
+  pub fn bake(&self) -> BakedSpline<T, V>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    BakedSpline::new(self)
+  }
+}
@@ -0,0 +1,209 @@
+//! Fluent construction of splines.
+//!
+//! A [`SplineBuilder`] authors a spline key after key, each interpolation mode applying to the key
+//! added last:
+//!
+//! ```
+//! use splines::builder::SplineBuilder;
+//!
+//! let spline = SplineBuilder::new()
+//!   .key(0., 0.)
+//!   .linear()
+//!   .key(1., 5.)
+//!   .bezier(8.)
+//!   .key(2., 10.)
+//!   .build()
+//!   .unwrap();
+//!
+//! assert_eq!(spline.sample(0.5), Some(2.5));
+//! ```
+//!
+//! Mistakes, such as keys added out of order, are reported by [`SplineBuilder::build`].
+
+use crate::interpolation::Interpolation;
+use crate::key::{Key, TangentConstraint};
+use crate::spline::Spline;
+use crate::track::TrackKind;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(feature = "std")]
+use std::fmt;
+
+/// Errors that might occur when building a spline.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BuildError {
+  /// An interpolation mode, or another property of a key, was set before adding any key.
+  NoKey,
+
+  /// The key at this index has a sampling value lower than – or not comparable to – the one of the
+  /// previous key.
+  UnsortedKeys(usize),
+}
+
+impl fmt::Display for BuildError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      BuildError::NoKey => f.write_str("a key property was set before adding any key"),
+
+      BuildError::UnsortedKeys(i) => {
+        write!(f, "the key {} is not after the previous key", i)
+      }
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+/// A builder of [`Spline`].
+///
+/// Keys are added by ascending sampling value with [`SplineBuilder::key`], using
+/// [`Interpolation::Linear`] by default – or the default mode of the kind of the spline (see
+/// [`SplineBuilder::kind`]). The other methods edit the key added last.
+#[derive(Clone, Debug)]
+pub struct SplineBuilder<T, V> {
+  keys: Vec<Key<T, V>>,
+  fallback: Option<V>,
+  kind: Option<TrackKind>,
+  error: Option<BuildError>,
+}
+
+impl<T, V> Default for SplineBuilder<T, V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, V> SplineBuilder<T, V> {
+  /// Create a builder with no key.
+  pub fn new() -> Self {
+    SplineBuilder {
+      keys: Vec::new(),
+      fallback: None,
+      kind: None,
+      error: None,
+    }
+  }
+
+  /// Add a key.
+  ///
+  /// Its interpolation mode is the default one of the kind of the spline if set (see
+  /// [`SplineBuilder::kind`]), or [`Interpolation::default`].
+  pub fn key(mut self, t: T, value: V) -> Self
+  where
+    T: PartialOrd,
+  {
+    let sorted = self.keys.last().is_none_or(|last| last.t <= t);
+
+    if !sorted {
+      self.fail(BuildError::UnsortedKeys(self.keys.len()));
+    }
+
+    let interpolation = self
+      .kind
+      .map_or_else(Interpolation::default, TrackKind::default_interpolation);
+    self.keys.push(Key::new(t, value, interpolation));
+    self
+  }
+
+  /// Set the interpolation mode of the last key.
+  pub fn interpolation(self, interpolation: Interpolation<T, V>) -> Self {
+    self.edit(|key| key.interpolation = interpolation)
+  }
+
+  /// Set the interpolation mode of the last key to [`Interpolation::Step`].
+  pub fn step(self, threshold: T) -> Self {
+    self.interpolation(Interpolation::Step(threshold))
+  }
+
+  /// Set the interpolation mode of the last key to [`Interpolation::Linear`].
+  pub fn linear(self) -> Self {
+    self.interpolation(Interpolation::Linear)
+  }
+
+  /// Set the interpolation mode of the last key to [`Interpolation::Cosine`].
+  pub fn cosine(self) -> Self {
+    self.interpolation(Interpolation::Cosine)
+  }
+
+  /// Set the interpolation mode of the last key to [`Interpolation::CatmullRom`].
+  pub fn catmull_rom(self) -> Self {
+    self.interpolation(Interpolation::CatmullRom)
+  }
+
+  /// Set the interpolation mode of the last key to [`Interpolation::Bezier`].
+  pub fn bezier(self, handle: V) -> Self {
+    self.interpolation(Interpolation::Bezier(handle))
+  }
+
+  /// Set the interpolation mode of the last key to [`Interpolation::StrokeBezier`].
+  pub fn stroke_bezier(self, input: V, output: V) -> Self {
+    self.interpolation(Interpolation::StrokeBezier(input, output))
+  }
+
+  /// Set the interpolation mode with which the last key is reached.
+  ///
+  /// See [`Key::in_interpolation`] for further details.
+  pub fn incoming(self, interpolation: Interpolation<T, V>) -> Self {
+    self.edit(|key| key.in_interpolation = Some(interpolation))
+  }
+
+  /// Set the tangent constraint of the last key.
+  pub fn tangent_constraint(self, constraint: TangentConstraint) -> Self {
+    self.edit(|key| key.tangent_constraint = constraint)
+  }
+
+  /// Set the fallback value of the spline.
+  ///
+  /// See [`Spline::set_fallback`] for further details.
+  pub fn fallback(mut self, fallback: V) -> Self {
+    self.fallback = Some(fallback);
+    self
+  }
+
+  /// Set the semantic tag of the spline.
+  ///
+  /// Keys added afterwards use the default interpolation mode of `kind` (see
+  /// [`TrackKind::default_interpolation`]).
+  pub fn kind(mut self, kind: TrackKind) -> Self {
+    self.kind = Some(kind);
+    self
+  }
+
+  /// Build the spline.
+  ///
+  /// # Error
+  ///
+  /// The first mistake made while building, if any.
+  pub fn build(self) -> Result<Spline<T, V>, BuildError>
+  where
+    T: PartialOrd,
+  {
+    if let Some(error) = self.error {
+      return Err(error);
+    }
+
+    let mut spline = Spline::from_sorted_vec(self.keys);
+    spline.set_fallback(self.fallback);
+    spline.set_kind(self.kind);
+    Ok(spline)
+  }
+
+  // Edit the last key, if any.
+  fn edit(mut self, f: impl FnOnce(&mut Key<T, V>)) -> Self {
+    match self.keys.last_mut() {
+      Some(key) => f(key),
+      None => self.fail(BuildError::NoKey),
+    }
+
+    self
+  }
+
+  // Record an error, only keeping the first one.
+  fn fail(&mut self, error: BuildError) {
+    self.error.get_or_insert(error);
+  }
+}
@@ -1,4 +1,4 @@
-use crate::impl_Interpolate;
+use crate::{impl_InnerSpace, impl_Interpolate, impl_Linear, impl_Planar, impl_Spatial};
 
 use cgmath::{Quaternion, Vector1, Vector2, Vector3, Vector4};
 
@@ -13,3 +13,68 @@ impl_Interpolate!(f64, Vector2<f64>, std::f64::consts::PI);
 impl_Interpolate!(f64, Vector3<f64>, std::f64::consts::PI);
 impl_Interpolate!(f64, Vector4<f64>, std::f64::consts::PI);
 impl_Interpolate!(f64, Quaternion<f64>, std::f64::consts::PI);
+
+impl_Linear!(f32, Vector1<f32>);
+impl_Linear!(f32, Vector2<f32>);
+impl_Linear!(f32, Vector3<f32>);
+impl_Linear!(f32, Vector4<f32>);
+impl_Linear!(f32, Quaternion<f32>);
+
+impl_Linear!(f64, Vector1<f64>);
+impl_Linear!(f64, Vector2<f64>);
+impl_Linear!(f64, Vector3<f64>);
+impl_Linear!(f64, Vector4<f64>);
+impl_Linear!(f64, Quaternion<f64>);
+
+impl_InnerSpace!(f32, Vector1<f32>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f32, Vector2<f32>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f32, Vector3<f32>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f32, Vector4<f32>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f32, Quaternion<f32>, |a, b| cgmath::InnerSpace::dot(a, b));
+
+impl_InnerSpace!(f64, Vector1<f64>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f64, Vector2<f64>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f64, Vector3<f64>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f64, Vector4<f64>, |a, b| cgmath::InnerSpace::dot(a, b));
+impl_InnerSpace!(f64, Quaternion<f64>, |a, b| cgmath::InnerSpace::dot(a, b));
+
+impl_Planar!(f32, Vector2<f32>);
+impl_Planar!(f64, Vector2<f64>);
+
+impl_Spatial!(f32, Vector3<f32>, |a, b| Vector3::cross(a, b));
+impl_Spatial!(f64, Vector3<f64>, |a, b| Vector3::cross(a, b));
+
+#[cfg(any(feature = "serialization", feature = "serde"))]
+mod schema {
+  use crate::schema::{impl_Schema, object_schema, Schema};
+  use cgmath::{Quaternion, Vector1, Vector2, Vector3, Vector4};
+
+  // Schema of a vector, serialized as an object with a field per component.
+  fn vector_schema<S: Schema>(fields: &[&str]) -> String {
+    let fields: Vec<_> = fields
+      .iter()
+      .map(|&name| (name, S::schema_json()))
+      .collect();
+    object_schema(&fields)
+  }
+
+  // Schema of a quaternion, serialized as an object with its vector and scalar parts.
+  fn quaternion_schema<S: Schema>() -> String {
+    object_schema(&[
+      ("v", vector_schema::<S>(&["x", "y", "z"])),
+      ("s", S::schema_json()),
+    ])
+  }
+
+  impl_Schema!(Vector1<f32>, vector_schema::<f32>(&["x"]));
+  impl_Schema!(Vector2<f32>, vector_schema::<f32>(&["x", "y"]));
+  impl_Schema!(Vector3<f32>, vector_schema::<f32>(&["x", "y", "z"]));
+  impl_Schema!(Vector4<f32>, vector_schema::<f32>(&["x", "y", "z", "w"]));
+  impl_Schema!(Quaternion<f32>, quaternion_schema::<f32>());
+
+  impl_Schema!(Vector1<f64>, vector_schema::<f64>(&["x"]));
+  impl_Schema!(Vector2<f64>, vector_schema::<f64>(&["x", "y"]));
+  impl_Schema!(Vector3<f64>, vector_schema::<f64>(&["x", "y", "z"]));
+  impl_Schema!(Vector4<f64>, vector_schema::<f64>(&["x", "y", "z", "w"]));
+  impl_Schema!(Quaternion<f64>, quaternion_schema::<f64>());
+}
@@ -0,0 +1,140 @@
+//! Clustering of near-identical splines.
+//!
+//! Large projects often end up with thousands of authored curves, many of which are copies of each
+//! other with tiny differences. [`Spline::distance`] measures how far apart two splines are, and
+//! [`Clusters::new`] uses it to group near-identical splines, so that only one canonical spline per
+//! group needs to be kept – typically when cooking assets.
+
+use crate::interpolate::{InnerSpace, Interpolate, Scalar};
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+/// Number of intervals between two consecutive keys at which splines are compared.
+const DISTANCE_CHECKS: u32 = 8;
+
+impl<T, V> Spline<T, V> {
+  /// Distance between two splines.
+  ///
+  /// The distance is the maximum distance – i.e. the norm of the difference – between the values
+  /// of both splines, measured at the sampling values of the keys of both splines and at regularly
+  /// spaced points between them. Both splines are sampled with clamping (see
+  /// [`Spline::clamped_sample`]), so that splines covering different ranges can be compared.
+  ///
+  /// # Return
+  ///
+  /// `None` if one of the splines has no key or cannot be sampled at one of the compared points.
+  pub fn distance(&self, other: &Self) -> Option<T>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    let mut ts: Vec<T> = self
+      .keys()
+      .iter()
+      .chain(other.keys())
+      .map(|key| key.t)
+      .collect();
+    ts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Less));
+    ts.dedup();
+
+    let mut distance = T::zero();
+    let mut measure = |t: T| -> Option<()> {
+      let d = (self.clamped_sample(t)? - other.clamped_sample(t)?).norm();
+
+      if d > distance {
+        distance = d;
+      }
+
+      Some(())
+    };
+
+    measure(*ts.first()?)?;
+
+    for w in ts.windows(2) {
+      let (t0, t1) = (w[0], w[1]);
+
+      for k in 1..=DISTANCE_CHECKS {
+        let nt = T::from_f64(k as f64 / DISTANCE_CHECKS as f64);
+        measure(t0 + (t1 - t0) * nt)?;
+      }
+    }
+
+    Some(distance)
+  }
+}
+
+/// Near-identical splines grouped together.
+///
+/// Every group is represented by a canonical spline, which is the first spline of the group in the
+/// input order.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Clusters {
+  canonical: Vec<usize>,
+  remap: Vec<usize>,
+}
+
+impl Clusters {
+  /// Group splines whose [`Spline::distance`] is lower than or equal to `tolerance`.
+  ///
+  /// Splines are visited in order: a spline joins the group of the first canonical spline it’s
+  /// close enough to, or starts a new group. This is *O(n × g)*, where `g` is the number of groups.
+  /// Splines that cannot be sampled are never grouped.
+  pub fn new<T, V>(splines: &[Spline<T, V>], tolerance: T) -> Self
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    let mut canonical: Vec<usize> = Vec::new();
+    let mut remap = Vec::with_capacity(splines.len());
+
+    for (i, spline) in splines.iter().enumerate() {
+      let group = canonical
+        .iter()
+        .position(|&c| spline.distance(&splines[c]).is_some_and(|d| d <= tolerance));
+
+      match group {
+        Some(group) => remap.push(group),
+        None => {
+          remap.push(canonical.len());
+          canonical.push(i);
+        }
+      }
+    }
+
+    Clusters { canonical, remap }
+  }
+
+  /// Indices, in the input, of the canonical splines – one per group.
+  pub fn canonical(&self) -> &[usize] {
+    &self.canonical
+  }
+
+  /// Group of every spline of the input, as an index in [`Clusters::canonical`].
+  pub fn remap(&self) -> &[usize] {
+    &self.remap
+  }
+
+  /// Number of groups.
+  pub fn len(&self) -> usize {
+    self.canonical.len()
+  }
+
+  /// Whether there is no group – i.e. the input was empty.
+  pub fn is_empty(&self) -> bool {
+    self.canonical.is_empty()
+  }
+
+  /// Index, in the input, of the canonical spline replacing the spline at index `i`.
+  ///
+  /// # Panics
+  ///
+  /// If `i` is not an index of the input.
+  pub fn canonical_of(&self, i: usize) -> usize {
+    self.canonical[self.remap[i]]
+  }
+}
@@ -0,0 +1,323 @@
+//! Compressed scalar splines.
+//!
+//! Games ship thousands of animation curves, for which memory matters more than a few cycles per
+//! sample. A [`CompressedSpline`] stores the keys of a scalar spline ([`Spline<T, T>`]) with 16-bit
+//! fixed-point numbers: sampling values are normalized over the duration of the spline, and carried
+//! values – along with Bézier handles – over the range of values of the spline. Keys are decoded on
+//! the fly when sampling, which follows the same rules as [`Spline`] on the decoded keys.
+//!
+//! Compression is lossy: the error is at most half of [`CompressedSpline::time_resolution`] on the
+//! sampling values of the keys and half of [`CompressedSpline::value_resolution`] on their carried
+//! values. The tangent constraints of the keys, only used when editing, are not kept.
+//!
+//! Keys are stored as absolute fixed-point numbers rather than as deltas from the previous key.
+//! Once normalized over the spline, a sampling value already fits in 16 bits – as does a delta in
+//! the worst case, so fixed-width deltas wouldn’t save any memory. Only variable-width deltas
+//! could, but decoding a key would then require decoding all the keys before it: absolute values
+//! keep random access to keys, which sampling relies on to binary search the segment of a sampling
+//! value and to decode only the keys that segment depends on.
+//!
+//! [`Spline<T, T>`]: crate::spline::Spline
+
+use crate::interpolate::{Interpolate, Scalar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::{sample_indexed_segment, IndexedKeys, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Largest fixed-point number.
+const MAX: f64 = u16::MAX as f64;
+
+/// A scalar spline with compressed keys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressedSpline<T> {
+  start: T,
+  duration: T,
+  low: T,
+  range: T,
+  // absolute rather than delta-encoded, for random access
+  ts: Vec<u16>,
+  values: Vec<u16>,
+  modes: Vec<KeyModes>,
+}
+
+// Interpolation modes of a key, with their values in fixed point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct KeyModes {
+  interpolation: Mode,
+  in_interpolation: Option<Mode>,
+}
+
+// Interpolation mode, with its values in fixed point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+  Step(u16),
+  Linear,
+  Cosine,
+  CatmullRom,
+  Bezier(u16),
+  StrokeBezier(u16, u16),
+}
+
+// Encode x, lying in [low; low + range], in fixed point.
+fn encode<T>(x: T, low: T, range: T) -> u16
+where
+  T: Scalar,
+{
+  if range == T::zero() {
+    return 0;
+  }
+
+  let q = ((x - low) / range * T::from_f64(MAX) + T::from_f64(0.5)).floor();
+  q.to_f64().clamp(0., MAX) as u16
+}
+
+// Decode a fixed-point number into [low; low + range].
+fn decode<T>(q: u16, low: T, range: T) -> T
+where
+  T: Scalar,
+{
+  low + range * T::from_f64(q as f64 / MAX)
+}
+
+impl<T> CompressedSpline<T> {
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.ts.len()
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.ts.is_empty()
+  }
+
+  /// Difference between two consecutive encodable sampling values.
+  pub fn time_resolution(&self) -> T
+  where
+    T: Scalar,
+  {
+    self.duration / T::from_f64(MAX)
+  }
+
+  /// Difference between two consecutive encodable carried values.
+  pub fn value_resolution(&self) -> T
+  where
+    T: Scalar,
+  {
+    self.range / T::from_f64(MAX)
+  }
+
+  /// Decode the key at a given index.
+  pub fn get(&self, index: usize) -> Option<Key<T, T>>
+  where
+    T: Scalar,
+  {
+    let modes = self.modes.get(index)?;
+
+    Some(Key {
+      t: self.t(index),
+      value: self.value(index),
+      interpolation: self.decode_mode(modes.interpolation),
+      in_interpolation: modes.in_interpolation.map(|mode| self.decode_mode(mode)),
+      tangent_constraint: Default::default(),
+    })
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<T>
+  where
+    T: Scalar + Interpolate<T>,
+  {
+    let len = self.ts.len();
+
+    if len == 1 {
+      return if self.t(0) == t {
+        Some(self.value(0))
+      } else {
+        None
+      };
+    }
+
+    // index of the first key after t
+    let next = self.ts.partition_point(|&q| self.decode_t(q) <= t);
+
+    if next == 0 || next >= len {
+      return None;
+    }
+
+    // keys are decoded on the fly, only the ones the segment depends on
+    sample_indexed_segment(self, next - 1, t)
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<T>
+  where
+    T: Scalar + Interpolate<T>,
+  {
+    let last = self.ts.len().checked_sub(1)?;
+
+    if t <= self.t(0) {
+      Some(self.value(0))
+    } else if t >= self.t(last) {
+      Some(self.value(last))
+    } else {
+      self.sample(t)
+    }
+  }
+
+  /// Decode all the keys into a [`Spline`].
+  pub fn decompress(&self) -> Spline<T, T>
+  where
+    T: Scalar,
+  {
+    Spline::from_sorted_vec((0..self.len()).filter_map(|i| self.get(i)).collect())
+  }
+
+  fn decode_t(&self, q: u16) -> T
+  where
+    T: Scalar,
+  {
+    decode(q, self.start, self.duration)
+  }
+
+  fn decode_value(&self, q: u16) -> T
+  where
+    T: Scalar,
+  {
+    decode(q, self.low, self.range)
+  }
+
+  fn decode_mode(&self, mode: Mode) -> Interpolation<T, T>
+  where
+    T: Scalar,
+  {
+    match mode {
+      Mode::Step(threshold) => Interpolation::Step(T::from_f64(threshold as f64 / MAX)),
+      Mode::Linear => Interpolation::Linear,
+      Mode::Cosine => Interpolation::Cosine,
+      Mode::CatmullRom => Interpolation::CatmullRom,
+      Mode::Bezier(u) => Interpolation::Bezier(self.decode_value(u)),
+      Mode::StrokeBezier(u, v) => {
+        Interpolation::StrokeBezier(self.decode_value(u), self.decode_value(v))
+      }
+    }
+  }
+}
+
+impl<T> IndexedKeys<T, T> for CompressedSpline<T>
+where
+  T: Scalar,
+{
+  fn len(&self) -> usize {
+    self.ts.len()
+  }
+
+  fn t(&self, i: usize) -> T {
+    self.decode_t(self.ts[i])
+  }
+
+  fn value(&self, i: usize) -> T {
+    self.decode_value(self.values[i])
+  }
+
+  fn with_interpolation<R>(
+    &self,
+    i: usize,
+    incoming: bool,
+    f: impl FnOnce(&Interpolation<T, T>) -> R,
+  ) -> R {
+    let modes = &self.modes[i];
+    let mode = match modes.in_interpolation {
+      Some(mode) if incoming => mode,
+      _ => modes.interpolation,
+    };
+
+    f(&self.decode_mode(mode))
+  }
+}
+
+impl<T> Spline<T, T> {
+  /// Compress the keys of the spline.
+  ///
+  /// See the [`compress`](crate::compress) module for further details.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has no key or if two keys get the same sampling value once compressed.
+  pub fn compress(&self) -> Option<CompressedSpline<T>>
+  where
+    T: Scalar,
+  {
+    let keys = self.keys();
+    let (first, last) = (keys.first()?, keys.last()?);
+    let (start, duration) = (first.t, last.t - first.t);
+
+    // the range of values covers the Bézier handles as well
+    let (mut low, mut high) = (first.value, first.value);
+    for key in keys {
+      let handles = [Some(&key.interpolation), key.in_interpolation.as_ref()]
+        .into_iter()
+        .flatten()
+        .flat_map(|interpolation| match *interpolation {
+          Interpolation::Bezier(u) => [Some(u), None],
+          Interpolation::StrokeBezier(u, v) => [Some(u), Some(v)],
+          _ => [None, None],
+        })
+        .flatten();
+
+      for x in handles.chain([key.value]) {
+        if x < low {
+          low = x;
+        }
+
+        if x > high {
+          high = x;
+        }
+      }
+    }
+    let range = high - low;
+
+    let encode_mode = |interpolation: &Interpolation<T, T>| match *interpolation {
+      Interpolation::Step(threshold) => Mode::Step(encode(threshold, T::zero(), T::one())),
+      Interpolation::Linear => Mode::Linear,
+      Interpolation::Cosine => Mode::Cosine,
+      Interpolation::CatmullRom => Mode::CatmullRom,
+      Interpolation::Bezier(u) => Mode::Bezier(encode(u, low, range)),
+      Interpolation::StrokeBezier(u, v) => {
+        Mode::StrokeBezier(encode(u, low, range), encode(v, low, range))
+      }
+    };
+
+    let mut compressed = CompressedSpline {
+      start,
+      duration,
+      low,
+      range,
+      ts: Vec::with_capacity(keys.len()),
+      values: Vec::with_capacity(keys.len()),
+      modes: Vec::with_capacity(keys.len()),
+    };
+
+    for key in keys {
+      let t = encode(key.t, start, duration);
+
+      if compressed.ts.last().is_some_and(|&previous| previous == t) {
+        return None;
+      }
+
+      compressed.ts.push(t);
+      compressed.values.push(encode(key.value, low, range));
+      compressed.modes.push(KeyModes {
+        interpolation: encode_mode(&key.interpolation),
+        in_interpolation: key.in_interpolation.as_ref().map(encode_mode),
+      });
+    }
+
+    Some(compressed)
+  }
+}
@@ -0,0 +1,141 @@
+//! Continuity analysis of splines.
+//!
+//! Imported animation data often carries accidental breaks – a key left stepped, a Bézier handle
+//! not aligned with the next segment – which show up as visible pops once played back.
+//! [`Spline::analyze_continuity`] inspects every key and reports how smooth the spline is there,
+//! so that such data can be validated before use.
+
+use crate::interpolate::{InnerSpace, Scalar};
+use crate::segment::Shape;
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Order of continuity of a spline at a key.
+///
+/// Variants are sorted by smoothness, so that they can be compared.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Continuity {
+  /// The value jumps at the key.
+  Discontinuous,
+
+  /// The value is continuous, but the first derivative – the tangent – breaks.
+  C0,
+
+  /// The value and the first derivative are continuous, but the second derivative – the
+  /// acceleration – breaks.
+  C1,
+
+  /// The value and the first and second derivatives are continuous.
+  C2,
+}
+
+/// Continuity of a spline at a key, as reported by [`Spline::analyze_continuity`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyContinuity<T> {
+  /// Index of the key.
+  pub key: usize,
+  /// Sampling value of the key.
+  pub t: T,
+  /// Order of continuity of the spline at the key.
+  ///
+  /// `None` for the first and last keys, and for keys next to a segment that cannot be sampled
+  /// (see [`Spline::sample`]).
+  pub continuity: Option<Continuity>,
+  /// Whether the segment starting at the key is an [`Interpolation::Step`] one jumping from a value
+  /// to another within the segment, i.e. at a threshold strictly between `0` and `1`.
+  ///
+  /// [`Interpolation::Step`]: crate::interpolation::Interpolation::Step
+  pub segment_jumps: bool,
+}
+
+impl<T, V> Spline<T, V> {
+  /// Analyze the continuity of the spline at each of its keys.
+  ///
+  /// The value and the first and second derivatives – with respect to the sampling value – of the
+  /// segments ending and starting at each key are compared; they are considered equal if the norm
+  /// of their difference is at most `tolerance`. This catches, among others, values jumping
+  /// because of [`Interpolation::Step`], tangents breaking between segments of different
+  /// interpolation modes, and accelerations breaking at the junction of Bézier segments.
+  ///
+  /// The report has an entry per key, in order.
+  ///
+  /// [`Interpolation::Step`]: crate::interpolation::Interpolation::Step
+  pub fn analyze_continuity(&self, tolerance: T) -> Vec<KeyContinuity<T>>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let keys = self.keys();
+    let shapes: Vec<_> = (0..keys.len()).map(|i| Shape::new(keys, i)).collect();
+
+    keys
+      .iter()
+      .enumerate()
+      .map(|(i, key)| {
+        let continuity = match (i.checked_sub(1).map(|j| &shapes[j]), &shapes[i]) {
+          (Some(Some(incoming)), Some(outgoing)) => {
+            let dt0 = key.t - keys[i - 1].t;
+            let dt1 = keys[i + 1].t - key.t;
+            let before = end_derivatives(incoming, dt0);
+            let after = start_derivatives(outgoing, dt1);
+            let order = before
+              .iter()
+              .zip(&after)
+              .take_while(|&(&a, &b)| (a - b).norm() <= tolerance)
+              .count();
+
+            Some(match order {
+              0 => Continuity::Discontinuous,
+              1 => Continuity::C0,
+              2 => Continuity::C1,
+              _ => Continuity::C2,
+            })
+          }
+
+          _ => None,
+        };
+
+        let segment_jumps = match shapes[i] {
+          Some(Shape::Step { threshold, a, b }) => {
+            threshold > T::zero() && threshold < T::one() && (a - b).norm() > tolerance
+          }
+          _ => false,
+        };
+
+        KeyContinuity {
+          key: i,
+          t: key.t,
+          continuity,
+          segment_jumps,
+        }
+      })
+      .collect()
+  }
+}
+
+// Value and derivatives, with respect to the sampling value, at which a segment of duration dt
+// starts.
+fn start_derivatives<T, V>(shape: &Shape<T, V>, dt: T) -> [V; 3]
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  let [value, d1, d2] = shape.derivatives(T::zero());
+  [value, d1.outer_div(dt), d2.outer_div(dt * dt)]
+}
+
+// Value and derivatives, with respect to the sampling value, at which a segment of duration dt ends.
+// The end of the segment is approached from the left, so a step segment jumping at its very end is
+// seen holding its first value.
+fn end_derivatives<T, V>(shape: &Shape<T, V>, dt: T) -> [V; 3]
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  let [value, d1, d2] = match *shape {
+    Shape::Step { threshold, .. } if threshold >= T::one() => shape.derivatives(T::zero()),
+    _ => shape.derivatives(T::one()),
+  };
+  [value, d1.outer_div(dt), d2.outer_div(dt * dt)]
+}
@@ -0,0 +1,64 @@
+//! Conversions from and to the curve types of other crates.
+//!
+//! Conversions are implemented with [`From`] and [`TryFrom`] behind integration features:
+//!
+//!   - `kurbo`: [`Spline<f64, kurbo::Vec2>`] from and to [`kurbo::BezPath`].
+//!   - `keyframe`: [`Spline`] to [`keyframe::AnimationSequence`].
+//!   - `flo_curves`: [`Spline<f64, flo_curves::Coord2>`] from and to slices and vectors of
+//!     [`flo_curves::bezier::Curve`].
+//!
+//! Conversions never approximate: when a spline cannot be represented exactly in the target type (or
+//! conversely), a [`ConversionError`] is returned.
+//!
+//! [`Spline`]: crate::spline::Spline
+//! [`Spline<f64, kurbo::Vec2>`]: crate::spline::Spline
+//! [`Spline<f64, flo_curves::Coord2>`]: crate::spline::Spline
+
+#[cfg(any(feature = "kurbo", feature = "flo_curves"))]
+use crate::interpolate::{Linear, Scalar};
+#[cfg(any(feature = "kurbo", feature = "flo_curves"))]
+use crate::key::Key;
+#[cfg(any(feature = "kurbo", feature = "flo_curves"))]
+use crate::segment::Shape;
+use std::fmt;
+
+/// Errors that might occur when converting curves.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConversionError {
+  /// The segment starting at the key at this index has no equivalent in the target type, or it
+  /// cannot be sampled.
+  UnsupportedSegment(usize),
+
+  /// The curve is made of disconnected pieces, which a spline cannot represent.
+  Disconnected,
+}
+
+impl fmt::Display for ConversionError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      ConversionError::UnsupportedSegment(i) => {
+        write!(f, "the segment starting at key {} cannot be converted", i)
+      }
+
+      ConversionError::Disconnected => f.write_str("disconnected curves cannot be converted"),
+    }
+  }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Control points of the segment starting at key `i`, as a cubic Bézier curve.
+#[cfg(any(feature = "kurbo", feature = "flo_curves"))]
+pub(crate) fn cubic_bezier_points<T, V>(
+  keys: &[Key<T, V>],
+  i: usize,
+) -> Result<[V; 4], ConversionError>
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  Shape::new(keys, i)
+    .and_then(|shape| shape.bezier())
+    .ok_or(ConversionError::UnsupportedSegment(i))
+}
@@ -0,0 +1,739 @@
+//! Playback cursors.
+//!
+//! A [`Cursor`] is a playhead moving along a [`Spline`]. Instead of sampling the spline at
+//! arbitrary sampling values, you advance the cursor by a delta (typically, the time elapsed since
+//! the last frame) and sample at its current position. A cursor can be paused, played faster or
+//! slower and can go backward.
+//!
+//! Every time you advance a cursor, you get back an iterator over the [`PlaybackEvent`]s that
+//! happened during the move, such as crossing keys. This is especially useful to trigger
+//! gameplay events when an animation reaches a given key.
+//!
+//! Cursors accumulate the deltas they are advanced by with an [`Accumulator`], so that they don’t
+//! drift even after hours of tiny increments.
+//!
+//! When many entities play the same spline at different speeds or phases but are driven by a
+//! global time rather than advanced frame by frame, a [`Playback`] view applies an affine time
+//! transform at sampling time instead.
+//!
+//! Conversely, when many splines – the tracks of an animation clip, for instance – must be played in
+//! sync, a [`SplineClock`] advances a single playhead and samples all of them at once, guaranteeing
+//! they all see the exact same sampling value.
+
+use crate::interpolate::{Interpolate, Interpolator, Linear, Scalar};
+use crate::segment::Shape;
+use crate::spline::{
+  clamped_sample_hinted, sample_hinted, sample_segment, search_lower_cp, search_lower_cp_near,
+  SampledWithKey, Spline,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::ops::Range;
+
+/// Direction in which a [`Cursor`] moves.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+  /// The sampling value increases while playing.
+  Forward,
+  /// The sampling value decreases while playing.
+  Backward,
+}
+
+impl Direction {
+  /// Get the opposite direction.
+  pub fn reversed(self) -> Self {
+    match self {
+      Direction::Forward => Direction::Backward,
+      Direction::Backward => Direction::Forward,
+    }
+  }
+}
+
+impl Default for Direction {
+  /// [`Direction::Forward`] is the default.
+  fn default() -> Self {
+    Direction::Forward
+  }
+}
+
+/// Events that can happen while advancing a [`Cursor`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PlaybackEvent {
+  /// A key was crossed.
+  ///
+  /// `direction` is the direction in which the cursor was actually moving when crossing the key,
+  /// which might differ from [`Cursor::direction`] if the speed is negative.
+  KeyCrossed {
+    /// Index of the crossed key.
+    key: usize,
+    /// Direction of the move.
+    direction: Direction,
+  },
+}
+
+/// Iterator over the [`PlaybackEvent`]s generated by a single call to [`Cursor::advance`].
+///
+/// Events are yielded in the order in which they happened.
+#[derive(Clone, Debug)]
+pub struct PlaybackEvents {
+  keys: Range<usize>,
+  direction: Direction,
+}
+
+impl PlaybackEvents {
+  fn empty() -> Self {
+    PlaybackEvents {
+      keys: 0..0,
+      direction: Direction::Forward,
+    }
+  }
+}
+
+impl Iterator for PlaybackEvents {
+  type Item = PlaybackEvent;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let key = match self.direction {
+      Direction::Forward => self.keys.next(),
+      Direction::Backward => self.keys.next_back(),
+    }?;
+
+    Some(PlaybackEvent::KeyCrossed {
+      key,
+      direction: self.direction,
+    })
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.keys.size_hint()
+  }
+}
+
+impl ExactSizeIterator for PlaybackEvents {}
+
+/// Compensated accumulator of sampling value deltas.
+///
+/// Adding many small deltas to a large sampling value with plain floating-point additions loses
+/// the low-order bits of every delta; after hours of uptime, a looping animation driven that way
+/// noticeably desyncs from, for instance, the audio it’s supposed to follow. This accumulator uses
+/// Kahan summation to keep track of the lost bits and reinject them, so that the accumulated value
+/// stays as close as possible to the exact sum of the deltas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Accumulator<T> {
+  sum: T,
+  compensation: T,
+}
+
+impl<T> Accumulator<T> {
+  /// Create an accumulator starting at `t`.
+  pub fn new(t: T) -> Self
+  where
+    T: Scalar,
+  {
+    Accumulator {
+      sum: t,
+      compensation: T::zero(),
+    }
+  }
+
+  /// Current accumulated value.
+  pub fn t(&self) -> T
+  where
+    T: Copy,
+  {
+    self.sum
+  }
+
+  /// Reset the accumulator to `t`.
+  pub fn set(&mut self, t: T)
+  where
+    T: Scalar,
+  {
+    *self = Self::new(t);
+  }
+
+  /// Add `dt` to the accumulator and return the new accumulated value.
+  pub fn advance(&mut self, dt: T) -> T
+  where
+    T: Scalar,
+  {
+    let y = dt - self.compensation;
+    let sum = self.sum + y;
+
+    self.compensation = (sum - self.sum) - y;
+    self.sum = sum;
+    sum
+  }
+}
+
+impl<T> Default for Accumulator<T>
+where
+  T: Scalar,
+{
+  /// An accumulator starting at zero is the default.
+  fn default() -> Self {
+    Self::new(T::zero())
+  }
+}
+
+/// A playhead moving along a [`Spline`].
+///
+/// The cursor borrows the spline, so that many cursors can play the same spline at different
+/// positions, speeds and directions.
+///
+/// The cursor caches the segment it’s in: as long as it moves inside the same segment – or to a
+/// neighboring one –, sampling doesn’t have to look the segment up again. With
+/// [`Cursor::sample_cached`], the polynomial coefficients of the segment are cached as well.
+#[derive(Debug)]
+pub struct Cursor<'a, T, V> {
+  spline: &'a Spline<T, V>,
+  playhead: Accumulator<T>,
+  speed: T,
+  direction: Direction,
+  paused: bool,
+  segment: Option<usize>,
+  shape: Option<Shape<T, V>>,
+}
+
+impl<'a, T, V> Clone for Cursor<'a, T, V>
+where
+  T: Clone,
+{
+  fn clone(&self) -> Self {
+    Cursor {
+      spline: self.spline,
+      playhead: self.playhead.clone(),
+      speed: self.speed.clone(),
+      direction: self.direction,
+      paused: self.paused,
+      segment: self.segment,
+      shape: None,
+    }
+  }
+}
+
+impl<'a, T, V> Cursor<'a, T, V> {
+  /// Create a new cursor at a given sampling value, playing forward at normal speed.
+  pub fn new(spline: &'a Spline<T, V>, t: T) -> Self
+  where
+    T: Scalar,
+  {
+    let mut cursor = Cursor {
+      spline,
+      playhead: Accumulator::new(t),
+      speed: T::one(),
+      direction: Direction::Forward,
+      paused: false,
+      segment: None,
+      shape: None,
+    };
+
+    cursor.locate();
+    cursor
+  }
+
+  /// Spline this cursor is moving along.
+  pub fn spline(&self) -> &'a Spline<T, V> {
+    self.spline
+  }
+
+  /// Current sampling value of the cursor.
+  pub fn t(&self) -> T
+  where
+    T: Copy,
+  {
+    self.playhead.t()
+  }
+
+  /// Move the cursor to a given sampling value.
+  ///
+  /// Seeking doesn’t generate any [`PlaybackEvent`].
+  pub fn seek(&mut self, t: T)
+  where
+    T: Scalar,
+  {
+    self.playhead.set(t);
+    self.locate();
+  }
+
+  /// Current speed of the cursor.
+  pub fn speed(&self) -> T
+  where
+    T: Copy,
+  {
+    self.speed
+  }
+
+  /// Change the speed of the cursor.
+  ///
+  /// The speed scales the delta passed to [`Cursor::advance`]. A negative speed makes the cursor
+  /// move in the opposite direction of [`Cursor::direction`].
+  pub fn set_speed(&mut self, speed: T) {
+    self.speed = speed;
+  }
+
+  /// Current direction of the cursor.
+  pub fn direction(&self) -> Direction {
+    self.direction
+  }
+
+  /// Change the direction of the cursor.
+  pub fn set_direction(&mut self, direction: Direction) {
+    self.direction = direction;
+  }
+
+  /// Reverse the direction of the cursor.
+  pub fn reverse(&mut self) {
+    self.direction = self.direction.reversed();
+  }
+
+  /// Pause the cursor; advancing a paused cursor doesn’t move it.
+  pub fn pause(&mut self) {
+    self.paused = true;
+  }
+
+  /// Resume a paused cursor.
+  pub fn resume(&mut self) {
+    self.paused = false;
+  }
+
+  /// Check whether the cursor is paused.
+  pub fn is_paused(&self) -> bool {
+    self.paused
+  }
+
+  /// Advance the cursor by `dt`, scaled by its speed and oriented by its direction.
+  ///
+  /// # Return
+  ///
+  /// An iterator over the [`PlaybackEvent`]s that happened during the move. Moving forward from
+  /// `t0` to `t1` crosses the keys in `]t0; t1]`; moving backward crosses the keys in `[t1; t0[`.
+  pub fn advance(&mut self, dt: T) -> PlaybackEvents
+  where
+    T: Scalar,
+  {
+    if self.paused {
+      return PlaybackEvents::empty();
+    }
+
+    let delta = match self.direction {
+      Direction::Forward => dt * self.speed,
+      Direction::Backward => -(dt * self.speed),
+    };
+    let from = self.playhead.t();
+    let to = self.playhead.advance(delta);
+    self.locate();
+
+    let keys = self.spline.keys();
+
+    if to > from {
+      let start = keys.partition_point(|key| key.t <= from);
+      let end = keys.partition_point(|key| key.t <= to);
+
+      PlaybackEvents {
+        keys: start..end,
+        direction: Direction::Forward,
+      }
+    } else if to < from {
+      let start = keys.partition_point(|key| key.t < to);
+      let end = keys.partition_point(|key| key.t < from);
+
+      PlaybackEvents {
+        keys: start..end,
+        direction: Direction::Backward,
+      }
+    } else {
+      PlaybackEvents::empty()
+    }
+  }
+
+  /// Sample the spline at the current position of the cursor.
+  ///
+  /// See [`Spline::sample`] for further details; as with it, the fallback value of the spline is
+  /// returned when the spline cannot be sampled.
+  pub fn sample(&self) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self
+      .sample_with_key()
+      .map(|sampled| sampled.value)
+      .or_else(|| self.spline.1.clone())
+  }
+
+  /// Sample the spline at the current position of the cursor, returning the interpolated value
+  /// along with its associated key.
+  ///
+  /// See [`Spline::sample_with_key`] for further details. Since there is no key to return it with,
+  /// the fallback value of the spline is not used.
+  pub fn sample_with_key(&self) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let key = match self.segment {
+      Some(key) => key,
+      None => {
+        #[cfg(feature = "stats")]
+        crate::stats::record(|stats| stats.out_of_range += 1);
+
+        return None;
+      }
+    };
+    let value = sample_segment(self.spline.keys(), key, self.playhead.t())?;
+
+    Some(SampledWithKey { value, key })
+  }
+
+  /// Sample the spline at the current position of the cursor with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    match self.segment {
+      Some(key) => {
+        sample_segment(self.spline.keys(), key, self.playhead.t()).or_else(|| self.spline.1.clone())
+      }
+      None => self.spline.clamped_sample(self.playhead.t()),
+    }
+  }
+
+  /// Sample the spline at the current position of the cursor, caching the coefficients of the
+  /// current segment.
+  ///
+  /// The first sample taken in a segment computes the polynomial form of the segment – which, for
+  /// [`Interpolation::CatmullRom`] and Bézier interpolations, involves computing tangents and
+  /// basis coefficients. Subsequent samples in the same segment only evaluate that polynomial,
+  /// which is much cheaper when many samples are taken per segment, as in audio or baking
+  /// workloads.
+  ///
+  /// The result is the same as [`Cursor::sample`], up to floating-point rounding.
+  ///
+  /// [`Interpolation::CatmullRom`]: crate::interpolation::Interpolation::CatmullRom
+  pub fn sample_cached(&mut self) -> Option<V>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    self.sample_cached_segment().or(self.spline.1)
+  }
+
+  // Sample the cached segment, without falling back to the fallback value of the spline.
+  fn sample_cached_segment(&mut self) -> Option<V>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let keys = self.spline.keys();
+    let i = match self.segment {
+      Some(i) => i,
+      None => {
+        #[cfg(feature = "stats")]
+        crate::stats::record(|stats| stats.out_of_range += 1);
+
+        return None;
+      }
+    };
+
+    if keys.len() == 1 {
+      return Some(keys[0].value);
+    }
+
+    let shape = match self.shape {
+      Some(shape) => shape,
+      None => {
+        let shape = Shape::new(keys, i)?;
+        self.shape = Some(shape);
+        shape
+      }
+    };
+
+    #[cfg(feature = "stats")]
+    crate::stats::record_sample(&keys[i].interpolation);
+
+    Some(shape.value(self.playhead.t().normalize(keys[i].t, keys[i + 1].t)))
+  }
+
+  /// Update the cached segment after the cursor has moved.
+  fn locate(&mut self)
+  where
+    T: Interpolator,
+  {
+    let keys = self.spline.keys();
+    let previous = self.segment;
+
+    if let Some(i) = previous.and_then(|i| search_lower_cp_near(keys, i, self.playhead.t())) {
+      if previous != Some(i) {
+        self.shape = None;
+      }
+
+      self.segment = Some(i);
+
+      #[cfg(feature = "stats")]
+      crate::stats::record(|stats| stats.cursor_hits += 1);
+
+      return;
+    }
+
+    #[cfg(feature = "stats")]
+    crate::stats::record(|stats| stats.cursor_misses += 1);
+
+    self.segment = search_lower_cp(keys, self.playhead.t());
+    self.shape = None;
+  }
+}
+
+/// A view on a [`Spline`] applying an affine time transform at sampling time.
+///
+/// Sampling a playback at `t` samples the spline at `t * scale + offset`. This lets many entities
+/// play the same shared spline at different speeds and phases without cloning and retiming its
+/// keys. A playback is cheap to create and to copy.
+#[derive(Debug)]
+pub struct Playback<'a, T, V> {
+  spline: &'a Spline<T, V>,
+  offset: T,
+  scale: T,
+}
+
+impl<'a, T, V> Clone for Playback<'a, T, V>
+where
+  T: Clone,
+{
+  fn clone(&self) -> Self {
+    Playback {
+      spline: self.spline,
+      offset: self.offset.clone(),
+      scale: self.scale.clone(),
+    }
+  }
+}
+
+impl<'a, T, V> Copy for Playback<'a, T, V> where T: Copy {}
+
+impl<'a, T, V> Playback<'a, T, V> {
+  /// Create a playback of a spline, sampling at `t * scale + offset`.
+  pub fn new(spline: &'a Spline<T, V>, offset: T, scale: T) -> Self {
+    Playback {
+      spline,
+      offset,
+      scale,
+    }
+  }
+
+  /// Spline being played.
+  pub fn spline(&self) -> &'a Spline<T, V> {
+    self.spline
+  }
+
+  /// Offset of the time transform.
+  pub fn offset(&self) -> T
+  where
+    T: Copy,
+  {
+    self.offset
+  }
+
+  /// Scale of the time transform.
+  pub fn scale(&self) -> T
+  where
+    T: Copy,
+  {
+    self.scale
+  }
+
+  /// Map a time to the sampling value of the spline.
+  pub fn transform(&self, t: T) -> T
+  where
+    T: Scalar,
+  {
+    t * self.scale + self.offset
+  }
+
+  /// Sample the spline at the transformed time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self.spline.sample(self.transform(t))
+  }
+
+  /// Sample the spline at the transformed time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self.spline.clamped_sample(self.transform(t))
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Sample a spline at `t * scale + offset`.
+  ///
+  /// This is a shortcut for sampling a [`Playback`] once.
+  pub fn sample_transformed(&self, t: T, offset: T, scale: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    Playback::new(self, offset, scale).sample(t)
+  }
+
+  /// Create a [`Playback`] of the spline, sampling at `t * scale + offset`.
+  pub fn playback(&self, offset: T, scale: T) -> Playback<'_, T, V> {
+    Playback::new(self, offset, scale)
+  }
+}
+
+/// A playhead shared by many splines, sampled in sync.
+///
+/// Splines subscribe to the clock with [`SplineClock::subscribe`]. Advancing the clock moves its
+/// single playhead – an [`Accumulator`], so that it doesn’t drift – and samples all the splines at
+/// the same sampling value in one call. Every spline keeps track of its current segment, so that
+/// sampling doesn’t have to look it up again as long as the clock moves by small steps.
+#[derive(Clone, Debug)]
+pub struct SplineClock<'a, T, V> {
+  playhead: Accumulator<T>,
+  tracks: Vec<(&'a Spline<T, V>, Option<usize>)>,
+  values: Vec<Option<V>>,
+}
+
+impl<'a, T, V> SplineClock<'a, T, V> {
+  /// Create a new clock at a given sampling value, with no spline.
+  pub fn new(t: T) -> Self
+  where
+    T: Scalar,
+  {
+    SplineClock {
+      playhead: Accumulator::new(t),
+      tracks: Vec::new(),
+      values: Vec::new(),
+    }
+  }
+
+  /// Current sampling value of the clock.
+  pub fn t(&self) -> T
+  where
+    T: Copy,
+  {
+    self.playhead.t()
+  }
+
+  /// Move the clock to a given sampling value.
+  pub fn seek(&mut self, t: T)
+  where
+    T: Scalar,
+  {
+    self.playhead.set(t);
+  }
+
+  /// Subscribe a spline to the clock.
+  ///
+  /// # Return
+  ///
+  /// The index of the spline, which is the index of its values in the slices returned when sampling.
+  pub fn subscribe(&mut self, spline: &'a Spline<T, V>) -> usize {
+    self.tracks.push((spline, None));
+    self.tracks.len() - 1
+  }
+
+  /// Number of subscribed splines.
+  pub fn len(&self) -> usize {
+    self.tracks.len()
+  }
+
+  /// Check whether no spline is subscribed.
+  pub fn is_empty(&self) -> bool {
+    self.tracks.is_empty()
+  }
+
+  /// Spline subscribed at a given index.
+  pub fn spline(&self, index: usize) -> Option<&'a Spline<T, V>> {
+    self.tracks.get(index).map(|&(spline, _)| spline)
+  }
+
+  /// Sample all the splines at the current sampling value of the clock.
+  ///
+  /// Values are returned in the order in which the splines subscribed. See [`Spline::sample`] for
+  /// further details.
+  pub fn sample(&mut self) -> &[Option<V>]
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample_all(false)
+  }
+
+  /// Sample all the splines at the current sampling value of the clock with clamping.
+  ///
+  /// Values are returned in the order in which the splines subscribed. See
+  /// [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&mut self) -> &[Option<V>]
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample_all(true)
+  }
+
+  /// Advance the clock by `dt` and sample all the splines.
+  ///
+  /// See [`SplineClock::sample`] for further details.
+  pub fn advance(&mut self, dt: T) -> &[Option<V>]
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self.playhead.advance(dt);
+    self.sample()
+  }
+
+  /// Advance the clock by `dt` and sample all the splines with clamping.
+  ///
+  /// See [`SplineClock::clamped_sample`] for further details.
+  pub fn advance_clamped(&mut self, dt: T) -> &[Option<V>]
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self.playhead.advance(dt);
+    self.clamped_sample()
+  }
+
+  /// Sample all the splines, with or without clamping.
+  fn sample_all(&mut self, clamped: bool) -> &[Option<V>]
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let t = self.playhead.t();
+
+    self.values.clear();
+    self
+      .values
+      .extend(self.tracks.iter_mut().map(|(spline, hint)| {
+        let value = if clamped {
+          clamped_sample_hinted(spline.keys(), hint, t)
+        } else {
+          sample_hinted(spline.keys(), hint, t)
+        };
+
+        value.or_else(|| spline.fallback().cloned())
+      }));
+
+    &self.values
+  }
+}
@@ -0,0 +1,124 @@
+//! Dead zone and hysteresis on sampled outputs.
+//!
+//! Splines are often used as response curves, mapping raw inputs – such as gamepad or joystick axes
+//! – to the actual response. Such inputs rarely rest exactly where the response is zero, so small
+//! responses must be ignored: that’s the _dead zone_. Responses hovering around the edge of the dead
+//! zone would then chatter, entering and leaving it constantly; _hysteresis_ prevents that by
+//! requiring responses to go further to leave the dead zone than to enter it.
+//!
+//! A [`DeadZone`] sampler applies both to the output of a scalar spline. It is stateful, since
+//! whether a response is in the dead zone depends on the previous ones.
+
+use crate::interpolate::{Interpolate, Interpolator, Scalar};
+use crate::spline::Spline;
+
+/// A sampler applying a dead zone with hysteresis to the output of a scalar [`Spline`].
+///
+/// While in the dead zone, sampling yields zero, whatever the sampled value. The dead zone is
+/// entered when the magnitude of the sampled value falls below [`DeadZone::dead_zone`], and left
+/// when it rises above [`DeadZone::dead_zone`] plus [`DeadZone::hysteresis`].
+#[derive(Clone, Copy, Debug)]
+pub struct DeadZone<'a, T, V> {
+  spline: &'a Spline<T, V>,
+  dead_zone: V,
+  hysteresis: V,
+  inside: bool,
+}
+
+impl<'a, T, V> DeadZone<'a, T, V> {
+  /// Apply a dead zone of the given radius and hysteresis to the output of a spline.
+  ///
+  /// The sampler starts in the dead zone.
+  pub fn new(spline: &'a Spline<T, V>, dead_zone: V, hysteresis: V) -> Self {
+    DeadZone {
+      spline,
+      dead_zone,
+      hysteresis,
+      inside: true,
+    }
+  }
+
+  /// Spline being sampled.
+  pub fn spline(&self) -> &'a Spline<T, V> {
+    self.spline
+  }
+
+  /// Magnitude of the sampled values below which the dead zone is entered.
+  pub fn dead_zone(&self) -> V
+  where
+    V: Copy,
+  {
+    self.dead_zone
+  }
+
+  /// Additional magnitude the sampled values must reach to leave the dead zone.
+  pub fn hysteresis(&self) -> V
+  where
+    V: Copy,
+  {
+    self.hysteresis
+  }
+
+  /// Check whether the last sampled value was in the dead zone.
+  pub fn is_in_dead_zone(&self) -> bool {
+    self.inside
+  }
+
+  /// Go back to the dead zone, as if the sampler was just created.
+  pub fn reset(&mut self) {
+    self.inside = true;
+  }
+
+  /// Update the state of the dead zone with a new sampled value and return the filtered value.
+  pub fn filter(&mut self, value: V) -> V
+  where
+    V: Scalar,
+  {
+    let magnitude = value.abs();
+
+    self.inside = if self.inside {
+      magnitude <= self.dead_zone + self.hysteresis
+    } else {
+      magnitude < self.dead_zone
+    };
+
+    if self.inside {
+      V::zero()
+    } else {
+      value
+    }
+  }
+
+  /// Sample the spline and filter the sampled value, yielding zero when in the dead zone.
+  ///
+  /// The state of the dead zone is left untouched when the spline cannot be sampled. See
+  /// [`Spline::sample`] for further details.
+  pub fn sample(&mut self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T> + Scalar,
+  {
+    let value = self.spline.sample(t)?;
+    Some(self.filter(value))
+  }
+
+  /// Sample the spline with clamping and filter the sampled value, yielding zero when in the dead
+  /// zone.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&mut self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T> + Scalar,
+  {
+    let value = self.spline.clamped_sample(t)?;
+    Some(self.filter(value))
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Create a [`DeadZone`] sampler of the spline.
+  pub fn with_dead_zone(&self, dead_zone: V, hysteresis: V) -> DeadZone<'_, T, V> {
+    DeadZone::new(self, dead_zone, hysteresis)
+  }
+}
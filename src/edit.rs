@@ -0,0 +1,580 @@
+//! Structural editing of splines.
+//!
+//! Operations in this module change the keys of splines while preserving the shape of their curves,
+//! such as cutting a spline in two with [`Spline::split_at`], trimming it with
+//! [`Spline::truncate`], appending a spline to another one with [`Spline::concat`] or making
+//! control points explicit with [`Spline::to_bezier`]. Others move keys in time, such as
+//! [`Spline::shift_t`], [`Spline::normalize_domain`], [`Spline::equalize_spacing`] and
+//! [`Spline::retime`], or transform their values, such as [`Spline::map`]; [`Spline::map_t`]
+//! changes the type of the sampling values.
+
+use crate::interpolate::{InnerSpace, Linear, Scalar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::segment::Shape;
+use crate::spline::{keys_from_vec, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// How the keys of the second spline are shifted in time by [`Spline::concat`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TimeShift<T> {
+  /// Keep the keys of the second spline where they are.
+  None,
+  /// Shift the keys of the second spline so that it starts where the first spline ends.
+  Append,
+  /// Shift the keys of the second spline by a given amount.
+  By(T),
+}
+
+impl<T> Default for TimeShift<T> {
+  /// [`TimeShift::Append`] is the default.
+  fn default() -> Self {
+    TimeShift::Append
+  }
+}
+
+/// How [`Spline::concat`] resolves the last key of the first spline and the first key of the
+/// second spline when they have the same sampling value.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Junction {
+  /// Merge both keys into a single one, reached as the last key of the first spline and left as the
+  /// first key of the second spline.
+  ///
+  /// The value of the last key of the first spline is kept.
+  Merge,
+  /// Keep the last key of the first spline only.
+  KeepFirst,
+  /// Keep the first key of the second spline only.
+  KeepSecond,
+  /// Keep both keys.
+  KeepBoth,
+}
+
+impl Default for Junction {
+  /// [`Junction::Merge`] is the default.
+  fn default() -> Self {
+    Junction::Merge
+  }
+}
+
+/// Options of [`Spline::concat`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ConcatPolicy<T> {
+  /// How the keys of the second spline are shifted in time.
+  pub shift: TimeShift<T>,
+  /// How the keys at the junction are resolved.
+  pub junction: Junction,
+}
+
+impl<T, V> Spline<T, V> {
+  /// Append the keys of another spline to this one.
+  ///
+  /// The keys of `other` are first shifted in time according to `policy.shift`. If the last key of
+  /// this spline and the first key of `other` then have the same sampling value, they are resolved
+  /// according to `policy.junction`. If the splines overlap otherwise, their keys are interleaved.
+  ///
+  /// The fallback value and the tag of this spline, if any, are kept (see [`Spline::set_fallback`]
+  /// and [`Spline::set_kind`]).
+  pub fn concat(self, other: Self, policy: ConcatPolicy<T>) -> Self
+  where
+    T: Scalar,
+    V: Clone,
+  {
+    let Spline(mut keys, fallback, kind) = self;
+    let shift = match policy.shift {
+      TimeShift::None => T::zero(),
+      TimeShift::Append => match (keys.last(), other.0.first()) {
+        (Some(last), Some(first)) => last.t - first.t,
+        _ => T::zero(),
+      },
+      TimeShift::By(shift) => shift,
+    };
+    let mut others = other.0.into_iter().map(|mut key| {
+      key.t = key.t + shift;
+      key
+    });
+    let mut first = others.next();
+
+    if let (Some(last), Some(first_key)) = (keys.last(), first.as_ref()) {
+      if last.t == first_key.t {
+        match policy.junction {
+          Junction::Merge => {
+            let i = keys.len() - 1;
+            let interpolation = first_key.interpolation.clone();
+            set_interpolation(&mut keys, i, interpolation);
+            first = None;
+          }
+
+          Junction::KeepFirst => first = None,
+
+          Junction::KeepSecond => {
+            keys.pop();
+          }
+
+          Junction::KeepBoth => (),
+        }
+      }
+    }
+
+    keys.extend(first);
+    keys.extend(others);
+
+    let mut spline = Spline(keys, fallback, kind);
+    spline.internal_sort();
+    spline
+  }
+
+  /// Shift all keys in time by `dt`.
+  ///
+  /// Since step thresholds are normalized, they don’t need to be changed.
+  pub fn shift_t(&mut self, dt: T)
+  where
+    T: Scalar,
+  {
+    for key in &mut self.0 {
+      key.t = key.t + dt;
+    }
+  }
+
+  /// Scale the sampling values of all keys by `factor`, stretching the spline in time around `0`.
+  ///
+  /// Since step thresholds are normalized, they don’t need to be changed. `factor` is expected to be
+  /// positive: a negative factor mirrors the keys in time, and they are sorted again, but their
+  /// interpolation modes then apply to different segments.
+  pub fn scale_t(&mut self, factor: T)
+  where
+    T: Scalar,
+  {
+    for key in &mut self.0 {
+      key.t = key.t * factor;
+    }
+
+    if factor < T::zero() {
+      self.internal_sort();
+    }
+  }
+
+  /// Remap the sampling values of all keys linearly so that the spline spans exactly `[0, 1]`.
+  ///
+  /// This is typically needed by consumers expecting a unit domain, such as easing curves and
+  /// gradients. Since step thresholds are normalized, they don’t need to be changed. If all keys have
+  /// the same sampling value – e.g. a single key –, they are moved to `0`.
+  pub fn normalize_domain(&mut self)
+  where
+    T: Scalar,
+  {
+    let (start, end) = match (self.0.first(), self.0.last()) {
+      (Some(first), Some(last)) => (first.t, last.t),
+      _ => return,
+    };
+    let duration = end - start;
+
+    for key in &mut self.0 {
+      key.t = if duration > T::zero() {
+        (key.t - start) / duration
+      } else {
+        T::zero()
+      };
+    }
+  }
+
+  /// Redistribute the keys uniformly over the sampling values they span, keeping their values.
+  ///
+  /// The first and last keys stay where they are. Since step thresholds are normalized, they don’t
+  /// need to be changed. This is typically used to turn imported polylines into well-behaved
+  /// Catmull-Rom splines; see [`Spline::equalize_spacing_by_distance`] to take the distances between
+  /// keys into account.
+  pub fn equalize_spacing(&mut self)
+  where
+    T: Scalar,
+  {
+    let count = self.0.len();
+    self.respace(|i| T::from_f64(i as f64 / (count - 1) as f64));
+  }
+
+  /// Redistribute the keys over the sampling values they span, proportionally to the distances
+  /// between their values, keeping their values.
+  ///
+  /// The distances between consecutive values – i.e. the chord lengths – approximate the arc length
+  /// of the curve, and are exactly the arc length of polylines. Moving along the spline at a constant
+  /// rate then moves along the curve at a roughly constant speed. If all keys have the same value,
+  /// they are distributed uniformly, as with [`Spline::equalize_spacing`].
+  pub fn equalize_spacing_by_distance(&mut self)
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let mut distances = Vec::with_capacity(self.0.len());
+    let mut total = T::zero();
+
+    distances.push(total);
+
+    for pair in self.0.windows(2) {
+      total = total + (pair[1].value - pair[0].value).norm();
+      distances.push(total);
+    }
+
+    if total > T::zero() {
+      self.respace(|i| distances[i] / total);
+    } else {
+      self.equalize_spacing();
+    }
+  }
+
+  // Move key i at the fraction f(i) of the sampling values spanned by the keys.
+  fn respace(&mut self, f: impl Fn(usize) -> T)
+  where
+    T: Scalar,
+  {
+    let (start, end) = match (self.0.first(), self.0.last()) {
+      (Some(first), Some(last)) if self.0.len() > 1 => (first.t, last.t),
+      _ => return,
+    };
+    let last = self.0.len() - 1;
+
+    for (i, key) in self.0.iter_mut().enumerate().skip(1).take(last - 1) {
+      key.t = start + (end - start) * f(i);
+    }
+  }
+
+  /// Move all keys in time with an increasing function.
+  ///
+  /// The sampling value of every key is replaced by its image by `f`. Step thresholds are updated
+  /// so that every step segment still switches at the image of its original switching time. The
+  /// shapes of the other segments are defined in normalized time and then follow `f` as it is
+  /// interpolated between keys.
+  ///
+  /// If `f` is not increasing, the keys are sorted again, but their interpolation modes then apply
+  /// to different segments.
+  pub fn retime(&mut self, f: impl Fn(T) -> T)
+  where
+    T: Scalar,
+    V: Clone,
+  {
+    self.0 = keys_from_vec(retime_keys(&self.0, f));
+    self.internal_sort();
+  }
+
+  /// Transform the carried values of the spline.
+  ///
+  /// `f` is applied to the values of the keys, to the control points of Bézier interpolations and
+  /// to the fallback value (see [`Spline::set_fallback`]). When `f` is affine – a change of
+  /// coordinate space or of unit, for instance –, the transformed spline is exactly the transformed
+  /// curve.
+  pub fn map<W>(&self, f: impl Fn(&V) -> W) -> Spline<T, W>
+  where
+    T: Copy,
+  {
+    let map_interpolation = |interpolation: &Interpolation<T, V>| match *interpolation {
+      Interpolation::Step(threshold) => Interpolation::Step(threshold),
+      Interpolation::Linear => Interpolation::Linear,
+      Interpolation::Cosine => Interpolation::Cosine,
+      Interpolation::CatmullRom => Interpolation::CatmullRom,
+      Interpolation::Bezier(ref u) => Interpolation::Bezier(f(u)),
+      Interpolation::StrokeBezier(ref v, ref u) => Interpolation::StrokeBezier(f(v), f(u)),
+    };
+    let keys = self
+      .0
+      .iter()
+      .map(|key| Key {
+        t: key.t,
+        value: f(&key.value),
+        interpolation: map_interpolation(&key.interpolation),
+        in_interpolation: key.in_interpolation.as_ref().map(map_interpolation),
+        tangent_constraint: key.tangent_constraint,
+      })
+      .collect();
+
+    Spline(keys, self.1.as_ref().map(&f), self.2)
+  }
+
+  /// Convert the sampling values of the spline to another type, such as from `f32` to `f64` or from
+  /// seconds to frames.
+  ///
+  /// As with [`Spline::retime`], step thresholds are updated so that every step segment still
+  /// switches at the image of its original switching time. If `f` is not increasing, the keys are
+  /// sorted again.
+  pub fn map_t<U>(&self, f: impl Fn(T) -> U) -> Spline<U, V>
+  where
+    T: Scalar,
+    U: Scalar,
+    V: Clone,
+  {
+    let mut spline = Spline(
+      keys_from_vec(retime_keys(&self.0, f)),
+      self.1.clone(),
+      self.2,
+    );
+    spline.internal_sort();
+    spline
+  }
+
+  /// Convert the Catmull-Rom segments of the spline to equivalent Bézier segments.
+  ///
+  /// Every Catmull-Rom segment that can be sampled gets explicit control points: its first key
+  /// switches to [`Interpolation::Bezier`] and the next key is reached with
+  /// [`Interpolation::StrokeBezier`]. The curve is unchanged. This is typically needed to hand
+  /// splines to tools and file formats that only understand Bézier control points.
+  ///
+  /// Catmull-Rom segments that cannot be sampled – the first one and the last two, lacking
+  /// surrounding keys – are left untouched.
+  pub fn to_bezier(&self) -> Self
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let mut keys = self.0.to_vec();
+
+    // baking a segment only changes the interpolation of its keys, which the shapes of the other
+    // Catmull-Rom segments don’t depend on
+    for i in 0..keys.len() {
+      bake_catmull_rom(&mut keys, i);
+    }
+
+    self.with_keys(keys)
+  }
+
+  /// Split the spline in two at a given sampling value.
+  ///
+  /// The segment containing `t` is subdivided exactly: a key is inserted at `t`, ending the first
+  /// spline and starting the second one, and both splines reproduce the original curve on their
+  /// halves. Bézier and Catmull-Rom segments are subdivided with de Casteljau’s algorithm – so
+  /// Catmull-Rom segments become Bézier ones –, and the Catmull-Rom segments next to the cut, whose
+  /// shapes depend on keys on the other side, are converted to Bézier segments as well.
+  ///
+  /// Splitting at the sampling value of a key doesn’t insert any key: that key ends the first spline
+  /// and starts the second one.
+  ///
+  /// # Return
+  ///
+  /// `None` if `t` is out of the keys, or if it lies in a segment that cannot be split exactly –
+  /// cosine segments, which have no polynomial form, and segments that cannot be sampled (see
+  /// [`Spline::sample`]).
+  pub fn split_at(&self, t: T) -> Option<(Self, Self)>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let keys = self.keys();
+    let last = keys.last()?;
+
+    // index of the first key after t
+    let next = keys.partition_point(|key| key.t <= t);
+
+    if next == 0 || t > last.t {
+      return None;
+    }
+
+    let mut keys = keys.to_vec();
+
+    if keys[next - 1].t == t {
+      let i = next - 1;
+
+      if i > 0 {
+        bake_catmull_rom(&mut keys, i - 1);
+      }
+
+      bake_catmull_rom(&mut keys, i);
+
+      return Some((
+        self.with_keys(keys[..=i].to_vec()),
+        self.with_keys(keys[i..].to_vec()),
+      ));
+    }
+
+    let i = next - 1;
+    let shape = Shape::new(&keys, i)?;
+    let nt = t.normalize(keys[i].t, keys[i + 1].t);
+
+    if i > 0 {
+      bake_catmull_rom(&mut keys, i - 1);
+    }
+
+    bake_catmull_rom(&mut keys, i + 1);
+
+    let (left, right) = match shape {
+      Shape::Step { threshold, a, b } => {
+        let value = if nt < threshold { a } else { b };
+        let right_threshold = (threshold - nt) / (T::one() - nt);
+
+        keys[i].interpolation = Interpolation::Step(threshold / nt);
+
+        (
+          Key::new(t, value, Interpolation::Step(right_threshold)),
+          Key::new(t, value, Interpolation::Step(right_threshold)),
+        )
+      }
+
+      Shape::Cosine { .. } => return None,
+
+      Shape::Cubic(_) if matches!(keys[i].interpolation, Interpolation::Linear) => {
+        let value = shape.value(nt);
+
+        (
+          Key::new(t, value, Interpolation::Linear),
+          Key::new(t, value, Interpolation::Linear),
+        )
+      }
+
+      Shape::Cubic(_) => {
+        let [a, u, v, b] = shape.bezier()?;
+        let lerp = |x: V, y: V| x + (y - x).outer_mul(nt);
+        let (au, uv, vb) = (lerp(a, u), lerp(u, v), lerp(v, b));
+        let (auv, uvb) = (lerp(au, uv), lerp(uv, vb));
+        let m = lerp(auv, uvb);
+
+        set_interpolation(&mut keys, i, Interpolation::Bezier(au));
+        keys[i + 1].in_interpolation = Some(Interpolation::StrokeBezier(vb, vb));
+
+        let mut left = Key::new(t, m, Interpolation::Bezier(uvb));
+        left.in_interpolation = Some(Interpolation::StrokeBezier(auv, auv));
+
+        (left, Key::new(t, m, Interpolation::Bezier(uvb)))
+      }
+    };
+
+    let mut right_keys = Vec::with_capacity(keys.len() - i);
+    right_keys.push(right);
+    right_keys.extend_from_slice(&keys[i + 1..]);
+
+    keys.truncate(i + 1);
+    keys.push(left);
+
+    Some((self.with_keys(keys), self.with_keys(right_keys)))
+  }
+
+  /// Clip the spline to the sampling values between `t0` and `t1`, both included.
+  ///
+  /// The segments containing `t0` and `t1` are split exactly (see [`Spline::split_at`]), so that
+  /// the clipped spline matches the original one over the range. Only the part of the range covered
+  /// by the keys is kept. This is typically used to trim animation clips.
+  ///
+  /// # Return
+  ///
+  /// `None` if `t0` is greater than `t1`, if the range doesn’t overlap the keys, or if a segment
+  /// containing `t0` or `t1` cannot be split exactly.
+  pub fn truncate(&self, t0: T, t1: T) -> Option<Self>
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let (first, last) = (self.0.first()?, self.0.last()?);
+
+    if t0 > t1 || t1 < first.t || t0 > last.t {
+      return None;
+    }
+
+    let spline = if t0 > first.t {
+      self.split_at(t0)?.1
+    } else {
+      self.clone()
+    };
+
+    if t1 < last.t {
+      Some(spline.split_at(t1)?.0)
+    } else {
+      Some(spline)
+    }
+  }
+}
+
+/// Replace the outgoing interpolation of key `i`, keeping the way the key is reached.
+fn set_interpolation<T, V>(keys: &mut [Key<T, V>], i: usize, interpolation: Interpolation<T, V>)
+where
+  T: Copy,
+  V: Clone,
+{
+  let previous_is_bezier = i > 0
+    && matches!(
+      keys[i - 1].interpolation,
+      Interpolation::Bezier(_) | Interpolation::StrokeBezier(..)
+    );
+
+  if previous_is_bezier && keys[i].in_interpolation.is_none() {
+    keys[i].in_interpolation = Some(keys[i].interpolation.clone());
+  }
+
+  keys[i].interpolation = interpolation;
+}
+
+/// Convert the segment starting at key `i` to a Bézier segment if it is a sampleable Catmull-Rom
+/// segment, keeping its shape.
+fn bake_catmull_rom<T, V>(keys: &mut [Key<T, V>], i: usize)
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  if !keys
+    .get(i)
+    .is_some_and(|key| matches!(key.interpolation, Interpolation::CatmullRom))
+  {
+    return;
+  }
+
+  if let Some([_, u, v, _]) = Shape::new(keys, i).and_then(|shape| shape.bezier()) {
+    set_interpolation(keys, i, Interpolation::Bezier(u));
+    keys[i + 1].in_interpolation = Some(Interpolation::StrokeBezier(v, v));
+  }
+}
+
+/// Convert the sampling values of keys with a function, updating step thresholds so that step
+/// segments switch at the images of their original switching times.
+fn retime_keys<T, U, V>(keys: &[Key<T, V>], f: impl Fn(T) -> U) -> Vec<Key<U, V>>
+where
+  T: Scalar,
+  U: Scalar,
+  V: Clone,
+{
+  let convert = |threshold: T| U::from_f64(threshold.to_f64());
+
+  keys
+    .iter()
+    .enumerate()
+    .map(|(i, key)| {
+      let t = f(key.t);
+      let interpolation = match (key.interpolation.clone(), keys.get(i + 1)) {
+        (Interpolation::Step(threshold), Some(next)) => {
+          let next_t = f(next.t);
+
+          if next_t == t {
+            Interpolation::Step(convert(threshold))
+          } else {
+            let switch = f(key.t + threshold * (next.t - key.t));
+            Interpolation::Step((switch - t) / (next_t - t))
+          }
+        }
+
+        (interpolation, _) => map_threshold(interpolation, convert),
+      };
+
+      Key {
+        t,
+        value: key.value.clone(),
+        interpolation,
+        in_interpolation: key
+          .in_interpolation
+          .clone()
+          .map(|interpolation| map_threshold(interpolation, convert)),
+        tangent_constraint: key.tangent_constraint,
+      }
+    })
+    .collect()
+}
+
+/// Convert the type of the step threshold of an interpolation mode.
+fn map_threshold<T, U, V>(
+  interpolation: Interpolation<T, V>,
+  f: impl Fn(T) -> U,
+) -> Interpolation<U, V> {
+  match interpolation {
+    Interpolation::Step(threshold) => Interpolation::Step(f(threshold)),
+    Interpolation::Linear => Interpolation::Linear,
+    Interpolation::Cosine => Interpolation::Cosine,
+    Interpolation::CatmullRom => Interpolation::CatmullRom,
+    Interpolation::Bezier(u) => Interpolation::Bezier(u),
+    Interpolation::StrokeBezier(v, u) => Interpolation::StrokeBezier(v, u),
+  }
+}
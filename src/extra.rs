@@ -0,0 +1,124 @@
+//! Preservation of tool-specific key data.
+//!
+//! External editors often attach their own data to keys – colors, selection states, locks, etc.
+//! Deserializing a [`Spline`] ignores such unknown fields, so that a round-trip through this crate
+//! silently drops them. An [`AnnotatedSpline`] keeps them instead: every key is paired with a JSON
+//! object holding its unknown fields, which are serialized back along with the key.
+//!
+//! Fields are kept in the order `serde_json` stores them in; enable its `preserve_order` feature to
+//! keep them in their original order.
+
+use crate::key::Key;
+use crate::spline::Spline;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+
+/// Unknown fields of a key.
+pub type Extra = Map<String, Value>;
+
+/// A spline along with the unknown fields of its keys.
+///
+/// It (de)serializes the same way as [`Spline`], except that the unknown fields of every key are
+/// kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnotatedSpline<T, V> {
+  spline: Spline<T, V>,
+  extras: Vec<Extra>,
+}
+
+impl<T, V> AnnotatedSpline<T, V> {
+  /// Annotate a spline, with no extra field for any key.
+  pub fn new(spline: Spline<T, V>) -> Self {
+    let extras = vec![Extra::new(); spline.len()];
+    AnnotatedSpline { spline, extras }
+  }
+
+  /// Build an annotated spline from a spline and the extra fields of its keys.
+  ///
+  /// # Return
+  ///
+  /// `None` if there is not exactly one set of extra fields per key.
+  pub fn from_parts(spline: Spline<T, V>, extras: Vec<Extra>) -> Option<Self> {
+    if spline.len() == extras.len() {
+      Some(AnnotatedSpline { spline, extras })
+    } else {
+      None
+    }
+  }
+
+  /// Annotated spline.
+  pub fn spline(&self) -> &Spline<T, V> {
+    &self.spline
+  }
+
+  /// Extra fields of every key, in the order of the keys.
+  pub fn extras(&self) -> &[Extra] {
+    &self.extras
+  }
+
+  /// Mutably borrow the extra fields of the key at index `i`.
+  pub fn extra_mut(&mut self, i: usize) -> Option<&mut Extra> {
+    self.extras.get_mut(i)
+  }
+
+  /// Split the annotated spline into the spline and the extra fields of its keys.
+  pub fn into_parts(self) -> (Spline<T, V>, Vec<Extra>) {
+    (self.spline, self.extras)
+  }
+}
+
+/// A key along with its unknown fields, both flattened in the same object.
+#[derive(Deserialize, Serialize)]
+struct AnnotatedKey<K> {
+  #[serde(flatten)]
+  key: K,
+  #[serde(flatten)]
+  extra: Extra,
+}
+
+impl<T, V> Serialize for AnnotatedSpline<T, V>
+where
+  T: Serialize,
+  V: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_seq(
+      self
+        .spline
+        .keys()
+        .iter()
+        .zip(&self.extras)
+        .map(|(key, extra)| AnnotatedKey {
+          key,
+          extra: extra.clone(),
+        }),
+    )
+  }
+}
+
+impl<'de, T, V> Deserialize<'de> for AnnotatedSpline<T, V>
+where
+  T: Deserialize<'de> + PartialOrd,
+  V: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let mut keys = Vec::<AnnotatedKey<Key<T, V>>>::deserialize(deserializer)?;
+
+    // sort here, so that extra fields follow their keys
+    keys.sort_by(|k0, k1| k0.key.t.partial_cmp(&k1.key.t).unwrap_or(Ordering::Less));
+
+    let (keys, extras) = keys.into_iter().map(|k| (k.key, k.extra)).unzip();
+
+    Ok(AnnotatedSpline {
+      spline: Spline::from_vec(keys),
+      extras,
+    })
+  }
+}
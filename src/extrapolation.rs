@@ -0,0 +1,236 @@
+//! Procedural extension of splines beyond their keys.
+//!
+//! Outside of its keys, a spline normally yields no value ([`Spline::sample`]) or the value of the
+//! nearest key ([`Spline::clamped_sample`]). Simulation curves often need a richer behavior once
+//! the authored data ends: keep moving along the last tangent, settle towards a rest value, keep
+//! oscillating, etc. An [`Extrapolation`] describes such a rule, and
+//! [`Spline::extended_sample`] applies one rule before the first key and another one after the
+//! last key.
+//!
+//! When the boundary behavior is a per-call choice rather than a property of the spline,
+//! [`Spline::sample_with_policy`] samples with one of the common [`OutOfBounds`] policies.
+
+use crate::interpolate::{Interpolate, Linear, Scalar};
+use crate::key::Key;
+use crate::segment::Shape;
+use crate::spline::Spline;
+#[cfg(any(feature = "serialization", feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// Rule used to extend a spline beyond one of its ends.
+///
+/// In the following, `end` is the value of the key at the end being extended and `dt` is the
+/// distance between the sampling value and that key.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+  any(feature = "serialization", feature = "serde"),
+  derive(Deserialize, Serialize),
+  serde(rename_all = "snake_case")
+)]
+pub enum Extrapolation<T, V> {
+  /// Hold `end`, as [`Spline::clamped_sample`] does.
+  Hold,
+
+  /// Continue along the tangent of the spline at `end`.
+  Tangent,
+
+  /// Decay exponentially from `end` to `target`, with the given time constant: the distance to
+  /// `target` is divided by *e* every `time_constant`.
+  Decay {
+    /// Value reached when `dt` goes to infinity.
+    target: V,
+    /// Time constant of the decay.
+    time_constant: T,
+  },
+
+  /// Oscillate sinusoidally around `end`.
+  Oscillate {
+    /// Amplitude of the oscillation.
+    amplitude: V,
+    /// Period of the oscillation.
+    period: T,
+  },
+}
+
+impl<T, V> Default for Extrapolation<T, V> {
+  /// [`Extrapolation::Hold`] is the default.
+  fn default() -> Self {
+    Extrapolation::Hold
+  }
+}
+
+/// Policy used when sampling a spline outside of its keys.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+  any(feature = "serialization", feature = "serde"),
+  derive(Deserialize, Serialize),
+  serde(rename_all = "snake_case")
+)]
+pub enum OutOfBounds {
+  /// Yield no value, as [`Spline::sample`] does.
+  None,
+
+  /// Yield the value of the nearest key, as [`Spline::clamped_sample`] does.
+  Clamp,
+
+  /// Loop over the keys: sampling values are wrapped into the range of the keys, so that the
+  /// spline repeats itself. The last key is never reached, since it wraps to the first one.
+  Wrap,
+
+  /// Ping-pong over the keys: sampling values are reflected at both ends, so that the spline is
+  /// played forward, then backward, then forward again, etc.
+  Mirror,
+
+  /// Continue along the tangent of the spline at the nearest end, as
+  /// [`Spline::extrapolated_sample`] does.
+  Extrapolate,
+}
+
+impl Default for OutOfBounds {
+  /// [`OutOfBounds::None`] is the default.
+  fn default() -> Self {
+    OutOfBounds::None
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Sample a spline at a given time, choosing the behavior outside of its keys.
+  ///
+  /// Inside the keys, this behaves the same way as [`Spline::sample`]. Outside of them, `policy`
+  /// is applied. [`OutOfBounds::Wrap`] and [`OutOfBounds::Mirror`] behave like
+  /// [`OutOfBounds::Clamp`] on a spline with a single key, or with all its keys at the same time.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has no key, if the spline cannot be sampled at the resulting time (see
+  /// [`Spline::sample`]) or if `policy` is [`OutOfBounds::None`] and `t` is out of the keys.
+  pub fn sample_with_policy(&self, t: T, policy: OutOfBounds) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + Linear<T>,
+  {
+    let keys = self.keys();
+    let t0 = keys.first()?.t;
+    let span = keys.last()?.t - t0;
+
+    match policy {
+      OutOfBounds::None => self.sample(t),
+
+      OutOfBounds::Clamp => self.clamped_sample(t),
+
+      OutOfBounds::Wrap if span > T::zero() => {
+        let dt = t - t0;
+        self.clamped_sample(t0 + dt - span * (dt / span).floor())
+      }
+
+      OutOfBounds::Mirror if span > T::zero() => {
+        let period = span + span;
+        let dt = t - t0;
+        let dt = dt - period * (dt / period).floor();
+        let dt = if dt > span { period - dt } else { dt };
+
+        self.clamped_sample(t0 + dt)
+      }
+
+      OutOfBounds::Wrap | OutOfBounds::Mirror => self.clamped_sample(t),
+
+      OutOfBounds::Extrapolate => self.extrapolated_sample(t),
+    }
+  }
+
+  /// Sample a spline at a given time, extending it with procedural rules beyond its keys.
+  ///
+  /// Inside the keys, this behaves the same way as [`Spline::sample`]. Before the first key,
+  /// `before` is applied; after the last key, `after` is applied.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has no key, if the spline cannot be sampled at `t` (see
+  /// [`Spline::sample`]) or if [`Extrapolation::Tangent`] is used at an end whose segment cannot be
+  /// sampled.
+  pub fn extended_sample(
+    &self,
+    t: T,
+    before: Extrapolation<T, V>,
+    after: Extrapolation<T, V>,
+  ) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + Linear<T>,
+  {
+    let keys = self.keys();
+    let first = keys.first()?;
+    let last = keys.last()?;
+
+    if t < first.t {
+      extrapolate(keys, 0, first.t - t, -T::one(), before)
+    } else if t >= last.t {
+      extrapolate(keys, keys.len() - 1, t - last.t, T::one(), after)
+    } else {
+      self.sample(t)
+    }
+  }
+
+  /// Sample a spline at a given time, linearly extrapolating beyond its keys.
+  ///
+  /// Outside of the keys, the spline continues along the tangent of its first or last segment
+  /// instead of being clamped, which avoids the flat spots clamping produces when predicting
+  /// motion or extending charts. This is a shortcut for [`Spline::extended_sample`] with
+  /// [`Extrapolation::Tangent`] at both ends.
+  pub fn extrapolated_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + Linear<T>,
+  {
+    self.extended_sample(t, Extrapolation::Tangent, Extrapolation::Tangent)
+  }
+}
+
+/// Extrapolate at `dt` away from the key at index `i`, in direction `sign`.
+fn extrapolate<T, V>(
+  keys: &[Key<T, V>],
+  i: usize,
+  dt: T,
+  sign: T,
+  extrapolation: Extrapolation<T, V>,
+) -> Option<V>
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  let end = keys[i].value;
+
+  match extrapolation {
+    Extrapolation::Hold => Some(end),
+
+    Extrapolation::Tangent => {
+      if keys.len() == 1 {
+        return Some(end);
+      }
+
+      // segment touching the end, and normalized time of the end in that segment
+      let (segment, nt) = if i == 0 {
+        (0, T::zero())
+      } else {
+        (i - 1, T::one())
+      };
+      let shape = Shape::new(keys, segment)?;
+      let duration = keys[segment + 1].t - keys[segment].t;
+      let tangent = shape.derivatives(nt)[1].outer_div(duration);
+
+      Some(end + tangent.outer_mul(sign * dt))
+    }
+
+    Extrapolation::Decay {
+      target,
+      time_constant,
+    } => Some(target + (end - target).outer_mul((-dt / time_constant).exp())),
+
+    Extrapolation::Oscillate { amplitude, period } => {
+      let phase = sign * dt * (T::pi() + T::pi()) / period;
+      Some(end + amplitude.outer_mul(phase.sin()))
+    }
+  }
+}
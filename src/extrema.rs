@@ -0,0 +1,425 @@
+//! Extrema of splines.
+//!
+//! Extrema are found analytically: cubic segments (Catmull-Rom, Bézier, linear) reach theirs either
+//! at their ends or where their derivative vanishes, while cosine and step segments reach theirs at
+//! their ends. [`Spline::bounds_over`] restricts that analysis to a range of sampling values, and
+//! [`Spline::is_monotonic`] checks the signs of the derivatives the same way. Strictly monotonic
+//! splines can be inverted with [`Spline::invert`].
+//!
+//! The same analysis, applied per axis, gives exact bounding boxes of 2D segments with
+//! [`Spline::segment_aabb`].
+
+use crate::fit::FitReport;
+use crate::interpolate::{InnerSpace, Interpolate, Linear, Planar, Scalar};
+use crate::interpolation::Interpolation;
+use crate::segment::Shape;
+use crate::solve::quadratic_roots;
+use crate::spline::Spline;
+use std::ops::Range;
+
+/// Number of points at which every segment is sampled by [`Spline::invert`].
+const INVERSION_SAMPLES: u32 = 32;
+
+/// Minimum and maximum values of a scalar spline, along with the sampling values reaching them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Extrema<T> {
+  /// Minimum value.
+  pub min: T,
+  /// Lowest sampling value at which the minimum value is reached.
+  pub min_t: T,
+  /// Maximum value.
+  pub max: T,
+  /// Lowest sampling value at which the maximum value is reached.
+  pub max_t: T,
+}
+
+impl<T> Spline<T, T> {
+  /// Minimum and maximum values of the spline.
+  ///
+  /// This is typically used to get tight ranges of values, for instance to normalize automation
+  /// curves or to scale the axes of a chart.
+  ///
+  /// Segments that cannot be sampled (see [`Spline::sample`]) are ignored, but the values of all
+  /// keys are always considered.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has no key.
+  pub fn extrema(&self) -> Option<Extrema<T>>
+  where
+    T: Scalar + Linear<T>,
+  {
+    let keys = self.keys();
+    let first = keys.first()?;
+    let mut extrema = Extrema {
+      min: first.value,
+      min_t: first.t,
+      max: first.value,
+      max_t: first.t,
+    };
+    let mut consider = |t: T, value: T| {
+      if value < extrema.min {
+        extrema.min = value;
+        extrema.min_t = t;
+      }
+
+      if value > extrema.max {
+        extrema.max = value;
+        extrema.max_t = t;
+      }
+    };
+
+    for (i, key) in keys.iter().enumerate() {
+      consider(key.t, key.value);
+
+      let shape = match Shape::new(keys, i) {
+        Some(shape) => shape,
+        None => continue,
+      };
+      let [(min_nt, min), (max_nt, max)] = segment_extrema(&shape);
+      let dt = keys[i + 1].t - key.t;
+
+      consider(key.t + dt * min_nt, min);
+      consider(key.t + dt * max_nt, max);
+    }
+
+    Some(extrema)
+  }
+
+  /// Minimum and maximum values of the spline over the sampling values of `range`, bounds included.
+  ///
+  /// The bounds are conservative: the spline is guaranteed to never exceed them over `range`. They
+  /// are computed analytically, segment by segment, and are then also tight. This is typically used
+  /// for culling, or to ensure a parameter stays within its limits during a time slice.
+  ///
+  /// Only the part of `range` covered by the keys is considered. As with [`Spline::extrema`],
+  /// segments that cannot be sampled are ignored, but the values of the keys inside `range` are
+  /// always considered.
+  ///
+  /// # Return
+  ///
+  /// `None` if `range` doesn’t overlap the keys, or only overlaps segments that cannot be sampled.
+  pub fn bounds_over(&self, range: Range<T>) -> Option<Extrema<T>>
+  where
+    T: Scalar + Linear<T>,
+  {
+    let keys = self.keys();
+    let (first, last) = (keys.first()?, keys.last()?);
+    let start = if range.start > first.t {
+      range.start
+    } else {
+      first.t
+    };
+    let end = if range.end < last.t {
+      range.end
+    } else {
+      last.t
+    };
+
+    if start > end {
+      return None;
+    }
+
+    let mut extrema: Option<Extrema<T>> = None;
+    let mut consider = |t: T, value: T| match extrema {
+      None => {
+        extrema = Some(Extrema {
+          min: value,
+          min_t: t,
+          max: value,
+          max_t: t,
+        })
+      }
+
+      Some(ref mut extrema) => {
+        if value < extrema.min {
+          extrema.min = value;
+          extrema.min_t = t;
+        }
+
+        if value > extrema.max {
+          extrema.max = value;
+          extrema.max_t = t;
+        }
+      }
+    };
+
+    for (i, key) in keys.iter().enumerate() {
+      if key.t >= start && key.t <= end {
+        consider(key.t, key.value);
+      }
+
+      let next = match keys.get(i + 1) {
+        Some(next) if key.t < end && next.t > start && next.t > key.t => next,
+        _ => continue,
+      };
+      let shape = match Shape::new(keys, i) {
+        Some(shape) => shape,
+        None => continue,
+      };
+      let dt = next.t - key.t;
+      let lo = if start > key.t {
+        start.normalize(key.t, next.t)
+      } else {
+        T::zero()
+      };
+      let hi = if end < next.t {
+        end.normalize(key.t, next.t)
+      } else {
+        T::one()
+      };
+      let [(min_nt, min), (max_nt, max)] = segment_extrema_in(&shape, lo, hi);
+
+      consider(key.t + dt * min_nt, min);
+      consider(key.t + dt * max_nt, max);
+    }
+
+    extrema
+  }
+}
+
+impl<T> Spline<T, T> {
+  /// Check whether the spline never decreases.
+  ///
+  /// The check is analytic: the derivative of every segment is checked to never be negative. This
+  /// is typically required to safely use a spline as a remapping or easing function.
+  ///
+  /// Segments that cannot be sampled (see [`Spline::sample`]) are ignored, but the values of the
+  /// keys must never decrease either. Splines with less than two keys are increasing.
+  pub fn is_increasing(&self) -> bool
+  where
+    T: Scalar + Linear<T>,
+  {
+    self.is_monotonic_by(|value| value)
+  }
+
+  /// Check whether the spline never increases.
+  ///
+  /// See [`Spline::is_increasing`] for further details.
+  pub fn is_decreasing(&self) -> bool
+  where
+    T: Scalar + Linear<T>,
+  {
+    self.is_monotonic_by(|value| T::zero() - value)
+  }
+
+  /// Check whether the spline never reverses, i.e. it is either increasing or decreasing.
+  ///
+  /// See [`Spline::is_increasing`] for further details.
+  pub fn is_monotonic(&self) -> bool
+  where
+    T: Scalar + Linear<T>,
+  {
+    self.is_increasing() || self.is_decreasing()
+  }
+
+  /// Invert a strictly monotonic spline, mapping its values back to sampling values.
+  ///
+  /// This is typically used to build reverse lookup curves, such as distance to time out of time
+  /// to distance. The inverse of a curved segment is generally not a polynomial, so the inverse is
+  /// approximated: every segment is sampled at regularly spaced points, whose sampling values and
+  /// values are swapped, and the swapped points are reduced to a Bézier spline staying within
+  /// `tolerance` of them (see [`Spline::reduce`]). Decreasing splines give decreasing inverses.
+  ///
+  /// # Return
+  ///
+  /// The inverse spline along with how well it fits the swapped points, or `None` if the spline has
+  /// less than two keys, if it is not strictly monotonic – step segments, which are flat, are then
+  /// rejected – or if one of its segments cannot be sampled (see [`Spline::sample`]).
+  pub fn invert(&self, tolerance: T) -> Option<(Self, FitReport<T>)>
+  where
+    T: Scalar + Interpolate<T> + InnerSpace<T>,
+  {
+    let keys = self.keys();
+    let (first, last) = (keys.first()?, keys.last()?);
+    let increasing = first.value < last.value;
+    let is_strict = keys.windows(2).all(|pair| {
+      !matches!(pair[0].interpolation, Interpolation::Step(_))
+        && if increasing {
+          pair[0].value < pair[1].value
+        } else {
+          pair[0].value > pair[1].value
+        }
+    });
+
+    if keys.len() < 2 || !is_strict || !self.is_monotonic() {
+      return None;
+    }
+
+    let mut points = Vec::with_capacity(keys.len() * INVERSION_SAMPLES as usize);
+
+    for (i, pair) in keys.windows(2).enumerate() {
+      let shape = Shape::new(keys, i)?;
+      let dt = pair[1].t - pair[0].t;
+
+      for k in 0..INVERSION_SAMPLES {
+        let nt = T::from_f64(k as f64 / INVERSION_SAMPLES as f64);
+        points.push((shape.value(nt), pair[0].t + dt * nt));
+      }
+    }
+
+    points.push((last.value, last.t));
+
+    if !increasing {
+      points.reverse();
+    }
+
+    Spline::reduce(&points, tolerance)
+  }
+
+  // Check whether the spline never decreases once its values are mapped with f.
+  fn is_monotonic_by(&self, f: impl Fn(T) -> T) -> bool
+  where
+    T: Scalar + Linear<T>,
+  {
+    let keys = self.keys();
+
+    keys.windows(2).enumerate().all(|(i, pair)| {
+      f(pair[0].value) <= f(pair[1].value)
+        && Shape::new(keys, i).is_none_or(|shape| segment_is_increasing(&shape.map(&f)))
+    })
+  }
+}
+
+/// Axis-aligned bounding box of 2D values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb<T> {
+  /// Lower corner, as `[x, y]`.
+  pub min: [T; 2],
+  /// Upper corner, as `[x, y]`.
+  pub max: [T; 2],
+}
+
+impl<T> Aabb<T> {
+  /// Smallest box containing both boxes.
+  pub fn union(&self, other: &Self) -> Self
+  where
+    T: PartialOrd + Copy,
+  {
+    let min = |a: T, b: T| if b < a { b } else { a };
+    let max = |a: T, b: T| if b > a { b } else { a };
+
+    Aabb {
+      min: [
+        min(self.min[0], other.min[0]),
+        min(self.min[1], other.min[1]),
+      ],
+      max: [
+        max(self.max[0], other.max[0]),
+        max(self.max[1], other.max[1]),
+      ],
+    }
+  }
+
+  /// Check whether a point lies inside the box, boundary included.
+  pub fn contains(&self, x: T, y: T) -> bool
+  where
+    T: PartialOrd,
+  {
+    self.min[0] <= x && x <= self.max[0] && self.min[1] <= y && y <= self.max[1]
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Exact axis-aligned bounding box of the segment starting at key `i`.
+  ///
+  /// The box is computed analytically, by finding the extrema of every axis – where its derivative
+  /// vanishes on Bézier, Catmull-Rom and linear segments – rather than by sampling. It is then as
+  /// tight as possible, which makes culling and picking exact.
+  ///
+  /// # Return
+  ///
+  /// `None` if `i` is the last key or is out of the keys, or if the segment cannot be sampled (see
+  /// [`Spline::sample`]).
+  pub fn segment_aabb(&self, i: usize) -> Option<Aabb<T>>
+  where
+    T: Scalar + Linear<T>,
+    V: Planar<T>,
+  {
+    let shape = Shape::new(self.keys(), i)?;
+    let [(_, min_x), (_, max_x)] = segment_extrema(&shape.map(V::x));
+    let [(_, min_y), (_, max_y)] = segment_extrema(&shape.map(V::y));
+
+    Some(Aabb {
+      min: [min_x, min_y],
+      max: [max_x, max_y],
+    })
+  }
+}
+
+/// Minimum and maximum of a scalar segment, along with the lowest normalized times reaching them.
+pub(crate) fn segment_extrema<T>(shape: &Shape<T, T>) -> [(T, T); 2]
+where
+  T: Scalar + Linear<T>,
+{
+  segment_extrema_in(shape, T::zero(), T::one())
+}
+
+/// Minimum and maximum of a scalar segment between the normalized times `lo` and `hi`, along with
+/// the lowest normalized times reaching them.
+pub(crate) fn segment_extrema_in<T>(shape: &Shape<T, T>, lo: T, hi: T) -> [(T, T); 2]
+where
+  T: Scalar + Linear<T>,
+{
+  let start = (lo, shape.value(lo));
+  let mut extrema = [start, start];
+  let mut consider = |nt: T| {
+    let value = shape.value(nt);
+
+    if value < extrema[0].1 {
+      extrema[0] = (nt, value);
+    }
+
+    if value > extrema[1].1 {
+      extrema[1] = (nt, value);
+    }
+  };
+
+  match *shape {
+    Shape::Step { threshold, .. } => {
+      if threshold > lo && threshold < hi {
+        consider(threshold);
+      }
+    }
+
+    Shape::Cosine { .. } => (),
+
+    Shape::Cubic([_, c1, c2, c3]) => {
+      // roots of the derivative c1 + 2 c2 x + 3 c3 x²
+      let roots = quadratic_roots(3. * c3.to_f64(), 2. * c2.to_f64(), c1.to_f64());
+      let (lo, hi) = (lo.to_f64(), hi.to_f64());
+
+      for root in roots.into_iter().flatten() {
+        if root > lo && root < hi {
+          consider(T::from_f64(root));
+        }
+      }
+    }
+  }
+
+  consider(hi);
+  extrema
+}
+
+/// Check whether the derivative of a scalar segment is never negative.
+fn segment_is_increasing<T>(shape: &Shape<T, T>) -> bool
+where
+  T: Scalar + Linear<T>,
+{
+  match *shape {
+    Shape::Step { a, b, .. } | Shape::Cosine { a, b } => a <= b,
+
+    Shape::Cubic([_, _, c2, c3]) => {
+      let zero = T::zero();
+      let slope = |nt: T| shape.derivatives(nt)[1] >= zero;
+
+      // the derivative is a quadratic, reaching its extremum where the second derivative vanishes
+      let vertex_is_increasing = c3 == zero || {
+        let vertex = zero - c2 / (c3 + c3 + c3);
+        vertex <= zero || vertex >= T::one() || slope(vertex)
+      };
+
+      slope(zero) && slope(T::one()) && vertex_is_increasing
+    }
+  }
+}
@@ -0,0 +1,465 @@
+//! Fitting quality.
+//!
+//! Functions building a spline that approximates some data – fitting, simplification, etc. – return
+//! a [`FitReport`] alongside the resulting spline, so that pipelines can automatically reject poor
+//! fits and try again, for instance with more keys or a lower tolerance. A report can also be
+//! computed for any spline and reference data with [`FitReport::measure`].
+//!
+//! [`Spline::fit`] approximates measured data with a least-squares fit, [`Spline::reduce`] turns
+//! densely baked samples into a sparse Bézier spline, and [`Spline::simplify`] removes redundant keys
+//! from a spline while staying within a tolerance.
+
+use crate::interpolate::{InnerSpace, Interpolate, Scalar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+/// Number of intervals between two consecutive keys at which simplified splines are checked.
+const SIMPLIFY_CHECKS: u32 = 8;
+
+/// Errors between a spline and the data it approximates.
+///
+/// Errors are distances – i.e. the norm of the difference – between the values of the spline and
+/// the reference values, measured at the sampling values of the reference data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitReport<T> {
+  /// Maximum error.
+  pub max_error: T,
+  /// Mean error.
+  pub mean_error: T,
+  /// Sampling value at which the maximum error is reached.
+  pub worst_t: T,
+}
+
+impl<T> FitReport<T> {
+  /// Measure how well a spline fits reference `(t, value)` pairs.
+  ///
+  /// The spline is sampled with clamping (see [`Spline::clamped_sample`]).
+  ///
+  /// # Return
+  ///
+  /// `None` if `samples` is empty or if the spline cannot be sampled at one of the sampling values.
+  pub fn measure<V>(spline: &Spline<T, V>, samples: &[(T, V)]) -> Option<Self>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    Self::from_errors(samples.iter().map(|&(t, value)| {
+      let error = (spline.clamped_sample(t)? - value).norm();
+      Some((t, error))
+    }))
+  }
+
+  // Summarize (t, error) pairs; None if there is no pair or if one is missing.
+  fn from_errors(errors: impl IntoIterator<Item = Option<(T, T)>>) -> Option<Self>
+  where
+    T: Scalar,
+  {
+    let mut errors = errors.into_iter();
+    let (first_t, first_error) = errors.next()??;
+    let mut report = FitReport {
+      max_error: first_error,
+      mean_error: first_error,
+      worst_t: first_t,
+    };
+    let mut count = 1;
+
+    for error in errors {
+      let (t, error) = error?;
+
+      if error > report.max_error {
+        report.max_error = error;
+        report.worst_t = t;
+      }
+
+      report.mean_error = report.mean_error + error;
+      count += 1;
+    }
+
+    report.mean_error = report.mean_error / T::from_f64(count as f64);
+    Some(report)
+  }
+
+  /// Check whether the maximum error is lower than or equal to `tolerance`.
+  pub fn is_within(&self, tolerance: T) -> bool
+  where
+    T: PartialOrd,
+  {
+    self.max_error <= tolerance
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Fit a spline of `key_count` keys to `(t, value)` points, minimizing the squared errors.
+  ///
+  /// Keys are regularly spaced over the sampling values of the points and all use `interpolation`;
+  /// their values are then computed with linear least squares. With
+  /// [`Interpolation::CatmullRom`], the first and last keys – which only shape the tangents – are
+  /// placed one interval outside the points, so that all the points can be sampled.
+  ///
+  /// Only [`Interpolation::Step`], [`Interpolation::Linear`], [`Interpolation::Cosine`] and
+  /// [`Interpolation::CatmullRom`] are supported, since the values of splines using them are
+  /// linear combinations of the values of their keys.
+  ///
+  /// # Return
+  ///
+  /// The fitted spline along with how well it fits the points, or `None` if all the points have the
+  /// same sampling value, if `interpolation` is not supported, if there are not enough keys (two, or
+  /// four with Catmull-Rom) or if some keys are not constrained by any point – typically because
+  /// there are too many keys for the points.
+  pub fn fit(
+    points: &[(T, V)],
+    key_count: usize,
+    interpolation: Interpolation<T, V>,
+  ) -> Option<(Self, FitReport<T>)>
+  where
+    T: Scalar + Interpolate<T>,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    // number of keys outside the points on each side
+    let (unit_interpolation, padding) = match interpolation {
+      Interpolation::Step(threshold) => (Interpolation::Step(threshold), 0),
+      Interpolation::Linear => (Interpolation::Linear, 0),
+      Interpolation::Cosine => (Interpolation::Cosine, 0),
+      Interpolation::CatmullRom => (Interpolation::CatmullRom, 1),
+      _ => return None,
+    };
+
+    if key_count < 2 + 2 * padding {
+      return None;
+    }
+
+    let (&(first_t, _), _) = points.split_first()?;
+    let (start, end) = points
+      .iter()
+      .fold((first_t, first_t), |(start, end), &(t, _)| {
+        (
+          if t < start { t } else { start },
+          if t > end { t } else { end },
+        )
+      });
+    if end <= start {
+      return None;
+    }
+
+    let dt = (end - start) / T::from_f64((key_count - 1 - 2 * padding) as f64);
+    let times: Vec<T> = (0..key_count)
+      .map(|j| start + dt * T::from_f64(j as f64 - padding as f64))
+      .collect();
+
+    // segment and normalized time of every point; points are sampled by segment so that the end of
+    // the points can be sampled with Catmull-Rom too
+    let last_segment = key_count - 2 - padding;
+    let segments: Vec<(usize, T)> = points
+      .iter()
+      .map(|&(t, _)| {
+        let i = ((t - start) / dt).floor().to_f64() as usize + padding;
+        let i = if i > last_segment { last_segment } else { i };
+        (i, (t - times[i]) / dt)
+      })
+      .collect();
+
+    // the weight of every key in a sample is the sample of a spline with that key set to one and
+    // all the others to zero
+    let basis = |j: usize| {
+      Spline::from_vec(
+        times
+          .iter()
+          .enumerate()
+          .map(|(k, &t)| {
+            let value = if k == j { T::one() } else { T::zero() };
+            Key::new(t, value, unit_interpolation)
+          })
+          .collect(),
+      )
+    };
+    let mut weights = vec![vec![T::zero(); points.len()]; key_count];
+
+    for (j, weights) in weights.iter_mut().enumerate() {
+      let basis = basis(j);
+
+      for (weight, &(i, nt)) in weights.iter_mut().zip(&segments) {
+        *weight = basis.sample_segment(i, nt)?;
+      }
+    }
+
+    // normal equations
+    let mut matrix = vec![vec![T::zero(); key_count]; key_count];
+    let mut rhs: Vec<Option<V>> = vec![None; key_count];
+
+    for j in 0..key_count {
+      for k in 0..key_count {
+        matrix[j][k] = weights[j]
+          .iter()
+          .zip(&weights[k])
+          .fold(T::zero(), |acc, (&a, &b)| acc + a * b);
+      }
+
+      for (&weight, &(_, value)) in weights[j].iter().zip(points) {
+        let value = value.outer_mul(weight);
+        rhs[j] = Some(rhs[j].map_or(value, |acc| acc + value));
+      }
+    }
+
+    let values = solve_linear_system(matrix, rhs.into_iter().collect::<Option<_>>()?)?;
+    let spline = Spline::from_vec(
+      times
+        .into_iter()
+        .zip(values)
+        .map(|(t, value)| Key::new(t, value, interpolation))
+        .collect(),
+    );
+    let report =
+      FitReport::from_errors(points.iter().zip(&segments).map(|(&(t, value), &(i, nt))| {
+        let error = (spline.sample_segment(i, nt)? - value).norm();
+        Some((t, error))
+      }))?;
+
+    Some((spline, report))
+  }
+
+  /// Reduce densely baked `(t, value)` samples – e.g. an animation baked at 60 Hz – to a sparse
+  /// cubic Bézier spline staying within `max_error` of them.
+  ///
+  /// Keys are picked among the samples, and keep their values. Tangents are estimated from the
+  /// neighboring samples, so that the spline is smooth at its keys; every segment is then made as
+  /// long as possible while all the samples it covers stay within `max_error`. This is the usual
+  /// curve simplification done when importing animations.
+  ///
+  /// Samples must be sorted by sampling value. The error is only guaranteed at the samples.
+  ///
+  /// # Return
+  ///
+  /// The reduced spline along with how well it fits the samples, or `None` if there is no sample.
+  pub fn reduce(samples: &[(T, V)], max_error: T) -> Option<(Self, FitReport<T>)>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    let last = samples.len().checked_sub(1)?;
+
+    // tangents, as derivatives with respect to the sampling values
+    let tangent = |k: usize| {
+      let (before, after) = (k.saturating_sub(1), (k + 1).min(last));
+
+      if before == after {
+        return None;
+      }
+
+      let ((t0, v0), (t1, v1)) = (samples[before], samples[after]);
+      Some((v1 - v0).outer_div(t1 - t0))
+    };
+    // Bézier control points of the segment between the samples at i and j
+    let controls = |i: usize, j: usize| {
+      let ((t0, a), (t1, b)) = (samples[i], samples[j]);
+      let dt = (t1 - t0) / T::from_f64(3.);
+      let u = tangent(i).map_or(a, |m| a + m.outer_mul(dt));
+      let v = tangent(j).map_or(b, |m| b - m.outer_mul(dt));
+      (u, v)
+    };
+    let fits = |i: usize, j: usize| {
+      let ((t0, a), (t1, b)) = (samples[i], samples[j]);
+      let (u, v) = controls(i, j);
+
+      samples[i + 1..j].iter().all(|&(t, value)| {
+        let nt = t.normalize(t0, t1);
+        (V::cubic_bezier(nt, a, u, v, b) - value).norm() <= max_error
+      })
+    };
+
+    let (first_t, first_value) = samples[0];
+    let mut keys = vec![Key::new(first_t, first_value, Interpolation::Linear)];
+    let mut i = 0;
+
+    while i < last {
+      // adjacent samples always fit, since keys keep their values
+      let mut j = i + 1;
+
+      while j < last && fits(i, j + 1) {
+        j += 1;
+      }
+
+      let (u, v) = controls(i, j);
+      let (t, value) = samples[j];
+      let mut key = Key::new(t, value, Interpolation::Linear);
+      key.in_interpolation = Some(Interpolation::StrokeBezier(v, v));
+
+      if let Some(previous) = keys.last_mut() {
+        previous.interpolation = Interpolation::Bezier(u);
+      }
+
+      keys.push(key);
+      i = j;
+    }
+
+    let spline = Spline::from_vec(keys);
+    let report = FitReport::measure(&spline, samples)?;
+
+    Some((spline, report))
+  }
+
+  /// Remove the keys whose absence changes the spline by at most `epsilon`.
+  ///
+  /// Keys are removed in a Ramer–Douglas–Peucker fashion: all the keys between two kept keys are
+  /// removed if the spline stays within `epsilon` of the original one; otherwise, the key nearest to
+  /// the worst error is kept and both sides are simplified the same way. Errors are measured – with
+  /// clamping – at the keys of the original spline and at regularly spaced points between them.
+  /// This is typically used on baked motion capture or telemetry, which have thousands of redundant
+  /// keys.
+  ///
+  /// The first and last keys are always kept. Kept keys are left untouched, interpolation included.
+  ///
+  /// # Return
+  ///
+  /// The simplified spline along with how well it fits the original one, or `None` if the spline
+  /// has no key or cannot be sampled.
+  pub fn simplify(&self, epsilon: T) -> Option<(Self, FitReport<T>)>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    let keys = self.keys();
+    let samples = self.reference_samples()?;
+    let mut kept = vec![true; keys.len()];
+    let mut ranges = vec![(0, keys.len() - 1)];
+    let simplified = |kept: &[bool]| {
+      self.with_keys(
+        keys
+          .iter()
+          .zip(kept)
+          .filter(|(_, &kept)| kept)
+          .map(|(key, _)| *key)
+          .collect(),
+      )
+    };
+
+    while let Some((lo, hi)) = ranges.pop() {
+      if hi - lo < 2 {
+        continue;
+      }
+
+      kept[lo + 1..hi].iter_mut().for_each(|kept| *kept = false);
+
+      // removing keys changes the segments around them as well (Catmull-Rom, Bézier), up to the
+      // kept keys right before and right after
+      let start = kept[..lo].iter().rposition(|&kept| kept).unwrap_or(lo);
+      let end = kept[hi + 1..]
+        .iter()
+        .position(|&kept| kept)
+        .map_or(hi, |i| hi + 1 + i);
+      let (start_t, end_t) = (keys[start].t, keys[end].t);
+      let window: Vec<_> = samples
+        .iter()
+        .copied()
+        .filter(|&(t, _)| t >= start_t && t <= end_t)
+        .collect();
+      let report = FitReport::measure(&simplified(&kept), &window);
+
+      match report {
+        Some(report) if report.is_within(epsilon) => (),
+
+        _ => {
+          kept[lo + 1..hi].iter_mut().for_each(|kept| *kept = true);
+
+          // keep the key nearest to the worst error, or the middle one if the simplified spline
+          // cannot be sampled
+          let split = match report {
+            Some(report) => (lo + 1..hi)
+              .min_by(|&a, &b| {
+                let da = (keys[a].t - report.worst_t).abs();
+                let db = (keys[b].t - report.worst_t).abs();
+                da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+              })
+              .unwrap_or(lo + 1),
+            None => (lo + hi) / 2,
+          };
+
+          ranges.push((split, hi));
+          ranges.push((lo, split));
+        }
+      }
+    }
+
+    let spline = simplified(&kept);
+    let report = FitReport::measure(&spline, &samples)?;
+    Some((spline, report))
+  }
+
+  // Sample the spline at its keys and at regularly spaced points between them.
+  fn reference_samples(&self) -> Option<Vec<(T, V)>>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    let keys = self.keys();
+    let first = keys.first()?;
+    let mut samples = vec![(first.t, self.clamped_sample(first.t)?)];
+
+    for pair in keys.windows(2) {
+      let (t0, t1) = (pair[0].t, pair[1].t);
+
+      for k in 1..=SIMPLIFY_CHECKS {
+        let t = t0 + (t1 - t0) * T::from_f64(k as f64 / SIMPLIFY_CHECKS as f64);
+        samples.push((t, self.clamped_sample(t)?));
+      }
+    }
+
+    Some(samples)
+  }
+}
+
+// Solve a square linear system with Gaussian elimination and partial pivoting.
+fn solve_linear_system<T, V>(mut matrix: Vec<Vec<T>>, mut rhs: Vec<V>) -> Option<Vec<V>>
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  let n = rhs.len();
+
+  for col in 0..n {
+    let pivot = (col..n).max_by(|&a, &b| {
+      matrix[a][col]
+        .abs()
+        .partial_cmp(&matrix[b][col].abs())
+        .unwrap_or(Ordering::Equal)
+    })?;
+
+    if matrix[pivot][col] == T::zero() {
+      return None;
+    }
+
+    matrix.swap(col, pivot);
+    rhs.swap(col, pivot);
+
+    let (pivot_rows, rows) = matrix.split_at_mut(col + 1);
+    let pivot_row = &pivot_rows[col];
+
+    for (row, i) in rows.iter_mut().zip(col + 1..) {
+      let factor = row[col] / pivot_row[col];
+
+      for (a, &b) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+        *a = *a - b * factor;
+      }
+
+      rhs[i] = rhs[i] - rhs[col].outer_mul(factor);
+    }
+  }
+
+  for row in (0..n).rev() {
+    let mut value = rhs[row];
+
+    for k in row + 1..n {
+      value = value - rhs[k].outer_mul(matrix[row][k]);
+    }
+
+    rhs[row] = value.outer_div(matrix[row][row]);
+  }
+
+  Some(rhs)
+}
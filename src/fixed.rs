@@ -0,0 +1,244 @@
+//! Deterministic fixed-point sampling.
+//!
+//! Floating-point sampling can give slightly different results depending on the target, the
+//! compiler and its optimizations – fused multiply-adds, different implementations of `cos`, etc.
+//! Replay systems and lockstep simulations need bit-exact results instead. [`Fixed`] is a 32.32
+//! fixed-point number performing all its arithmetic on integers; splines using it both as sampling
+//! type and as carried value (`Spline<Fixed, Fixed>`) are sampled with integer math only, giving
+//! the same results on every platform.
+//!
+//! All the operations of [`Fixed`] wrap around on overflow – in both debug and release builds, so
+//! that they stay deterministic – and round towards negative infinity.
+
+use crate::interpolate::{Interpolate, Interpolator, Linear};
+#[cfg(not(feature = "std"))]
+use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(any(feature = "serialization", feature = "serde"))]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Number of fractional bits of [`Fixed`].
+const FRAC_BITS: u32 = 32;
+
+/// Coefficients of the Taylor series of `cos(x)` in `x²`, in 32.32 fixed point.
+const COS_COEFFICIENTS: [i64; 7] = [
+  1 << FRAC_BITS,
+  -2147483648,
+  178956971,
+  -5965232,
+  106522,
+  -1184,
+  9,
+];
+
+/// A signed 32.32 fixed-point number.
+///
+/// It covers `[-2³¹; 2³¹[` with a resolution of `2⁻³²`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+  any(feature = "serialization", feature = "serde"),
+  derive(Deserialize, Serialize)
+)]
+pub struct Fixed(i64);
+
+impl Fixed {
+  /// Zero.
+  pub const ZERO: Self = Fixed(0);
+
+  /// One.
+  pub const ONE: Self = Fixed(1 << FRAC_BITS);
+
+  /// The *π* constant.
+  pub const PI: Self = Fixed(13493037705);
+
+  /// Create a number from its raw representation, i.e. the number multiplied by `2³²`.
+  pub const fn from_bits(bits: i64) -> Self {
+    Fixed(bits)
+  }
+
+  /// Raw representation of the number, i.e. the number multiplied by `2³²`.
+  pub const fn to_bits(self) -> i64 {
+    self.0
+  }
+
+  /// Convert from a `f64`, rounding to the nearest representable number.
+  ///
+  /// This conversion is exact and deterministic; values out of range saturate, and NaN is
+  /// converted to zero.
+  pub fn from_f64(x: f64) -> Self {
+    let scaled = x * (1u64 << FRAC_BITS) as f64;
+    let truncated = scaled as i64;
+    let fract = scaled - truncated as f64;
+    // out-of-range values are already saturated by the cast, so the rounding must not overflow
+    let rounded = if fract >= 0.5 {
+      truncated.saturating_add(1)
+    } else if fract <= -0.5 {
+      truncated.saturating_sub(1)
+    } else {
+      truncated
+    };
+
+    Fixed(rounded)
+  }
+
+  /// Convert to a `f64`, possibly losing precision for large numbers.
+  pub fn to_f64(self) -> f64 {
+    self.0 as f64 / (1u64 << FRAC_BITS) as f64
+  }
+
+  /// `cos(π x)`, computed with integer math only.
+  fn cos_pi(self) -> Self {
+    // reduce to [0; 1], using cos(π x) = cos(-π x) and the period of 2
+    let period = 2 << FRAC_BITS;
+    let mut x = self.0.rem_euclid(period);
+
+    if x > Fixed::ONE.0 {
+      x = period - x;
+    }
+
+    // reduce to [0; 1/2], using cos(π x) = -cos(π (1 - x))
+    let half = Fixed::ONE.0 >> 1;
+    let (x, sign) = if x > half {
+      (Fixed::ONE.0 - x, -1)
+    } else {
+      (x, 1)
+    };
+
+    // Taylor series, evaluated with Horner’s method in x²
+    let x = Fixed(x) * Fixed::PI;
+    let x2 = x * x;
+    let cos = COS_COEFFICIENTS
+      .iter()
+      .rev()
+      .fold(Fixed::ZERO, |acc, &c| acc * x2 + Fixed(c));
+
+    Fixed(cos.0.wrapping_mul(sign))
+  }
+}
+
+impl From<i32> for Fixed {
+  fn from(x: i32) -> Self {
+    Fixed((x as i64) << FRAC_BITS)
+  }
+}
+
+impl Add for Fixed {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self {
+    Fixed(self.0.wrapping_add(rhs.0))
+  }
+}
+
+impl Sub for Fixed {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self {
+    Fixed(self.0.wrapping_sub(rhs.0))
+  }
+}
+
+impl Mul for Fixed {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self {
+    Fixed(((self.0 as i128 * rhs.0 as i128) >> FRAC_BITS) as i64)
+  }
+}
+
+impl Div for Fixed {
+  type Output = Self;
+
+  /// Divide two numbers.
+  ///
+  /// # Panics
+  ///
+  /// If `rhs` is zero.
+  #[allow(clippy::suspicious_arithmetic_impl)]
+  fn div(self, rhs: Self) -> Self {
+    Fixed(((self.0 as i128) << FRAC_BITS).div_euclid(rhs.0 as i128) as i64)
+  }
+}
+
+impl Neg for Fixed {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Fixed(self.0.wrapping_neg())
+  }
+}
+
+impl Interpolator for Fixed {
+  fn normalize(self, start: Self, end: Self) -> Self {
+    (self - start) / (end - start)
+  }
+}
+
+impl Linear<Fixed> for Fixed {
+  fn outer_mul(self, t: Fixed) -> Self {
+    self * t
+  }
+
+  fn outer_div(self, t: Fixed) -> Self {
+    self / t
+  }
+}
+
+impl Interpolate<Fixed> for Fixed {
+  fn step(t: Fixed, threshold: Fixed, a: Self, b: Self) -> Self {
+    if t < threshold {
+      a
+    } else {
+      b
+    }
+  }
+
+  fn lerp(t: Fixed, a: Self, b: Self) -> Self {
+    a + (b - a) * t
+  }
+
+  fn cosine(t: Fixed, a: Self, b: Self) -> Self {
+    let cos_nt = Fixed((Fixed::ONE - t.cos_pi()).0 >> 1);
+    Self::lerp(cos_nt, a, b)
+  }
+
+  fn cubic_hermite(
+    t: Fixed,
+    x: (Fixed, Self),
+    a: (Fixed, Self),
+    b: (Fixed, Self),
+    y: (Fixed, Self),
+  ) -> Self {
+    let (one, two, three) = (Fixed::ONE, Fixed::from(2), Fixed::from(3));
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // tangents
+    let m0 = (b.1 - x.1) / (b.0 - x.0) * (b.0 - a.0);
+    let m1 = (y.1 - a.1) / (y.0 - a.0) * (b.0 - a.0);
+
+    a.1 * (two * t3 - three * t2 + one)
+      + m0 * (t3 - two * t2 + t)
+      + b.1 * (three * t2 - two * t3)
+      + m1 * (t3 - t2)
+  }
+
+  fn quadratic_bezier(t: Fixed, a: Self, u: Self, b: Self) -> Self {
+    let one_t = Fixed::ONE - t;
+
+    u + (a - u) * one_t * one_t + (b - u) * t * t
+  }
+
+  fn cubic_bezier(t: Fixed, a: Self, u: Self, v: Self, b: Self) -> Self {
+    let one_t = Fixed::ONE - t;
+    let one_t2 = one_t * one_t;
+    let t2 = t * t;
+
+    a * one_t2 * one_t + (u * one_t2 * t + v * one_t * t2) * Fixed::from(3) + b * t2 * t
+  }
+
+  fn cubic_bezier_mirrored(t: Fixed, a: Self, u: Self, v: Self, b: Self) -> Self {
+    Self::cubic_bezier(t, a, u, b + b - v, b)
+  }
+}
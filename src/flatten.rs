@@ -0,0 +1,135 @@
+//! Adaptive flattening of splines into polylines.
+
+use crate::interpolate::{InnerSpace, Scalar};
+use crate::segment::Shape;
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Maximum number of times a segment can be subdivided.
+const MAX_DEPTH: u32 = 16;
+
+/// Number of intervals a piece of segment is divided into when measuring its deviation.
+const DEVIATION_CHECKS: u32 = 4;
+
+impl<T, V> Spline<T, V> {
+  /// Flatten the spline into a polyline.
+  ///
+  /// The returned points are such that the distance between the curve and the polyline, measured
+  /// at regularly spaced points of every piece of the polyline, is lower than `tolerance`. Curved
+  /// segments (Bézier, Catmull-Rom, etc.) are recursively subdivided until that’s the case, while
+  /// straight segments produce a single line.
+  ///
+  /// Segments that cannot be sampled (see [`Spline::sample`]) are skipped, and discontinuities
+  /// (such as the ones introduced by [`Interpolation::Step`]) are joined by a straight line. An
+  /// empty spline yields no point and a spline with a single key yields the value of that key.
+  ///
+  /// [`Interpolation::Step`]: crate::interpolation::Interpolation::Step
+  pub fn flatten(&self, tolerance: T) -> Vec<V>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let keys = self.keys();
+    let mut points = Vec::new();
+
+    if keys.len() == 1 {
+      points.push(keys[0].value);
+    }
+
+    for i in 0..keys.len().saturating_sub(1) {
+      let shape = match Shape::new(keys, i) {
+        Some(shape) => shape,
+        None => continue,
+      };
+
+      if let Shape::Step { a, b, .. } = shape {
+        if points.is_empty() {
+          points.push(a);
+        }
+
+        points.push(b);
+        continue;
+      }
+
+      let start = shape.value(T::zero());
+      let end = shape.value(T::one());
+
+      if points.is_empty() {
+        points.push(start);
+      }
+
+      subdivide(
+        &shape,
+        (T::zero(), start),
+        (T::one(), end),
+        tolerance,
+        MAX_DEPTH,
+        &mut points,
+      );
+    }
+
+    points
+  }
+}
+
+/// Split the piece of segment between `start` and `end` until it’s close enough to the chord
+/// joining them, pushing every point but `start` to `points`.
+fn subdivide<T, V>(
+  shape: &Shape<T, V>,
+  start: (T, V),
+  end: (T, V),
+  tolerance: T,
+  depth: u32,
+  points: &mut Vec<V>,
+) where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  if depth == 0 || deviation(shape, start, end) <= tolerance {
+    points.push(end.1);
+    return;
+  }
+
+  let nt = (start.0 + end.0) * T::from_f64(0.5);
+  let middle = (nt, shape.value(nt));
+
+  subdivide(shape, start, middle, tolerance, depth - 1, points);
+  subdivide(shape, middle, end, tolerance, depth - 1, points);
+}
+
+/// Maximum distance between the curve and the chord joining `start` and `end`.
+fn deviation<T, V>(shape: &Shape<T, V>, (t0, p0): (T, V), (t1, p1): (T, V)) -> T
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  let chord = p1 - p0;
+  let chord_len2 = chord.dot(chord);
+  let checks = T::from_f64(f64::from(DEVIATION_CHECKS));
+
+  (1..DEVIATION_CHECKS).fold(T::zero(), |max, k| {
+    let nt = t0 + (t1 - t0) * T::from_f64(f64::from(k)) / checks;
+    let p = shape.value(nt) - p0;
+    let u = if chord_len2 > T::zero() {
+      let u = p.dot(chord) / chord_len2;
+
+      if u < T::zero() {
+        T::zero()
+      } else if u > T::one() {
+        T::one()
+      } else {
+        u
+      }
+    } else {
+      T::zero()
+    };
+    let distance = (p - chord.outer_mul(u)).norm();
+
+    if distance > max {
+      distance
+    } else {
+      max
+    }
+  })
+}
@@ -0,0 +1,176 @@
+use crate::convert::{cubic_bezier_points, ConversionError};
+use crate::impl_Spatial;
+use crate::interpolate::{InnerSpace, Interpolate, Linear, Planar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::raw::eval_hermite;
+use crate::spline::Spline;
+use flo_curves::bezier::{BezierCurve, BezierCurveFactory, Curve};
+use flo_curves::{Coord2, Coord3, Coordinate, Coordinate2D, Coordinate3D};
+
+// flo_curves coordinates cannot be divided by scalars, which impl_Interpolate requires
+macro_rules! impl_Interpolate_coordinate {
+  ($v:ty) => {
+    impl Interpolate<f64> for $v {
+      fn step(t: f64, threshold: f64, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: f64, a: Self, b: Self) -> Self {
+        a * (1. - t) + b * t
+      }
+
+      fn cosine(t: f64, a: Self, b: Self) -> Self {
+        Self::lerp((1. - (t * std::f64::consts::PI).cos()) * 0.5, a, b)
+      }
+
+      fn cubic_hermite(
+        t: f64,
+        x: (f64, Self),
+        a: (f64, Self),
+        b: (f64, Self),
+        y: (f64, Self),
+      ) -> Self {
+        let m0 = (b.1 - x.1) * ((b.0 - a.0) / (b.0 - x.0));
+        let m1 = (y.1 - a.1) * ((b.0 - a.0) / (y.0 - a.0));
+
+        eval_hermite(t, a.1, m0, b.1, m1)
+      }
+
+      fn quadratic_bezier(t: f64, a: Self, u: Self, b: Self) -> Self {
+        let one_t = 1. - t;
+
+        u + (a - u) * (one_t * one_t) + (b - u) * (t * t)
+      }
+
+      fn cubic_bezier(t: f64, a: Self, u: Self, v: Self, b: Self) -> Self {
+        let one_t = 1. - t;
+
+        a * (one_t * one_t * one_t)
+          + (u * (one_t * one_t * t) + v * (one_t * t * t)) * 3.
+          + b * (t * t * t)
+      }
+
+      fn cubic_bezier_mirrored(t: f64, a: Self, u: Self, v: Self, b: Self) -> Self {
+        Self::cubic_bezier(t, a, u, b + b - v, b)
+      }
+    }
+  };
+}
+
+impl_Interpolate_coordinate!(Coord2);
+impl_Interpolate_coordinate!(Coord3);
+
+impl Linear<f64> for Coord2 {
+  fn outer_mul(self, t: f64) -> Self {
+    self * t
+  }
+
+  fn outer_div(self, t: f64) -> Self {
+    self * t.recip()
+  }
+}
+
+impl Linear<f64> for Coord3 {
+  fn outer_mul(self, t: f64) -> Self {
+    self * t
+  }
+
+  fn outer_div(self, t: f64) -> Self {
+    self * t.recip()
+  }
+}
+
+impl InnerSpace<f64> for Coord2 {
+  fn dot(self, rhs: Self) -> f64 {
+    Coordinate::dot(&self, &rhs)
+  }
+}
+
+impl InnerSpace<f64> for Coord3 {
+  fn dot(self, rhs: Self) -> f64 {
+    Coordinate::dot(&self, &rhs)
+  }
+}
+
+impl Planar<f64> for Coord2 {
+  fn x(self) -> f64 {
+    Coordinate2D::x(&self)
+  }
+
+  fn y(self) -> f64 {
+    Coordinate2D::y(&self)
+  }
+}
+
+impl_Spatial!(f64, Coord3, |a, b| Coord3(
+  Coordinate3D::y(&a) * Coordinate3D::z(&b) - Coordinate3D::z(&a) * Coordinate3D::y(&b),
+  Coordinate3D::z(&a) * Coordinate3D::x(&b) - Coordinate3D::x(&a) * Coordinate3D::z(&b),
+  Coordinate3D::x(&a) * Coordinate3D::y(&b) - Coordinate3D::y(&a) * Coordinate3D::x(&b)
+));
+
+/// Convert a spline to cubic Bézier curves, one per segment.
+///
+/// Linear, Catmull-Rom and Bézier segments are converted to their exact cubic Bézier form. Step and
+/// cosine segments, as well as segments that cannot be sampled, have no equivalent and yield
+/// [`ConversionError::UnsupportedSegment`].
+impl<V> TryFrom<&Spline<f64, V>> for Vec<Curve<V>>
+where
+  V: Coordinate + Linear<f64>,
+{
+  type Error = ConversionError;
+
+  fn try_from(spline: &Spline<f64, V>) -> Result<Self, Self::Error> {
+    (0..spline.len().saturating_sub(1))
+      .map(|i| {
+        let [a, u, v, b] = cubic_bezier_points(spline.keys(), i)?;
+        Ok(Curve::from_points(a, (u, v), b))
+      })
+      .collect()
+  }
+}
+
+/// Convert consecutive cubic Bézier curves to a spline.
+///
+/// The ends of the curves become keys, with consecutive sampling values starting at `0`, linked by
+/// Bézier segments. Curves that don’t start where the previous one ends yield
+/// [`ConversionError::Disconnected`].
+impl<V> TryFrom<&[Curve<V>]> for Spline<f64, V>
+where
+  V: Coordinate,
+{
+  type Error = ConversionError;
+
+  fn try_from(curves: &[Curve<V>]) -> Result<Self, Self::Error> {
+    let mut keys: Vec<Key<f64, V>> = Vec::with_capacity(curves.len() + 1);
+
+    for curve in curves {
+      let (u, v) = curve.control_points();
+      let t = match keys.last_mut() {
+        Some(last) if last.value != curve.start_point() => {
+          return Err(ConversionError::Disconnected)
+        }
+
+        Some(last) => {
+          last.interpolation = Interpolation::Bezier(u);
+          last.t + 1.
+        }
+
+        None => {
+          keys.push(Key::new(0., curve.start_point(), Interpolation::Bezier(u)));
+          1.
+        }
+      };
+
+      let mut key = Key::new(t, curve.end_point(), Interpolation::Linear);
+      key.in_interpolation = Some(Interpolation::StrokeBezier(v, v));
+      keys.push(key);
+    }
+
+    Ok(Spline::from_vec(keys))
+  }
+}
@@ -0,0 +1,94 @@
+//! Moving frames along 3D paths.
+//!
+//! Orienting objects along a path or extruding geometry along it requires a frame – three
+//! orthonormal axes – at every point of the path. [`Spline::frenet_frame`] computes the Frenet
+//! frame, which is defined by the curve alone but flips at inflection points and is undefined on
+//! straight parts. [`Spline::transported_frame`] computes a parallel-transport frame instead,
+//! which moves a previous frame along the path with as little rotation as possible and never flips.
+
+use crate::interpolate::{Scalar, Spatial};
+use crate::spline::{derivatives_at, Spline};
+
+/// Orthonormal frame attached to a point of a 3D path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frame<V> {
+  /// Unit tangent, pointing in the direction of motion.
+  pub tangent: V,
+  /// Unit normal.
+  pub normal: V,
+  /// Unit binormal, equal to `tangent × normal`.
+  pub binormal: V,
+}
+
+impl<T, V> Spline<T, V> {
+  /// Frenet frame of the curve at a given time.
+  ///
+  /// The normal points towards the center of the osculating circle of the curve, and the binormal
+  /// is orthogonal to the osculating plane.
+  ///
+  /// # Return
+  ///
+  /// `None` in the same situations as [`Spline::sample`], if the curve is stationary at `t` or if
+  /// it’s straight at `t` – i.e. its curvature is zero – in which case the normal is not defined.
+  pub fn frenet_frame(&self, t: T) -> Option<Frame<V>>
+  where
+    T: Scalar,
+    V: Spatial<T>,
+  {
+    let (_, [_, d1, d2]) = derivatives_at(self.keys(), t)?;
+    let tangent = normalize(d1)?;
+    let binormal = normalize(d1.cross(d2))?;
+
+    Some(Frame {
+      tangent,
+      normal: binormal.cross(tangent),
+      binormal,
+    })
+  }
+
+  /// Parallel-transport frame of the curve at a given time.
+  ///
+  /// `previous` is moved to `t` with the smallest rotation aligning its tangent with the tangent of
+  /// the curve at `t`. Starting from any frame – for instance [`Spline::frenet_frame`] at the start
+  /// of the path, or a frame built from an up vector – and transporting it along increasing
+  /// sampling values yields frames that never flip, even on straight parts of the path. Steps
+  /// should be small enough for the tangent to not turn by more than a right angle between them.
+  ///
+  /// # Return
+  ///
+  /// `None` in the same situations as [`Spline::sample`], or if the curve is stationary at `t`.
+  pub fn transported_frame(&self, previous: &Frame<V>, t: T) -> Option<Frame<V>>
+  where
+    T: Scalar,
+    V: Spatial<T>,
+  {
+    let (_, [_, d1, _]) = derivatives_at(self.keys(), t)?;
+    let tangent = normalize(d1)?;
+
+    // remove the component of the previous normal along the new tangent; if both are aligned, the
+    // previous binormal is used to rebuild the normal instead
+    let normal = previous.normal - tangent.outer_mul(previous.normal.dot(tangent));
+    let normal = normalize(normal).or_else(|| normalize(previous.binormal.cross(tangent)))?;
+
+    Some(Frame {
+      tangent,
+      normal,
+      binormal: tangent.cross(normal),
+    })
+  }
+}
+
+/// Normalize a vector, returning `None` if it’s zero.
+fn normalize<T, V>(v: V) -> Option<V>
+where
+  T: Scalar,
+  V: Spatial<T>,
+{
+  let norm = v.norm();
+
+  if norm > T::zero() {
+    Some(v.outer_div(norm))
+  } else {
+    None
+  }
+}
@@ -1,4 +1,7 @@
-use crate::impl_Interpolate;
+use crate::{
+  impl_InnerSpace, impl_Interpolate, impl_InterpolateT, impl_Linear, impl_LinearT, impl_Planar,
+  impl_Spatial,
+};
 use glam::{DQuat, DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec3A, Vec4};
 
 impl_Interpolate!(f32, Vec2, std::f32::consts::PI);
@@ -11,3 +14,88 @@ impl_Interpolate!(f64, DVec2, std::f64::consts::PI);
 impl_Interpolate!(f64, DVec3, std::f64::consts::PI);
 impl_Interpolate!(f64, DVec4, std::f64::consts::PI);
 impl_Interpolate!(f64, DQuat, std::f64::consts::PI);
+
+impl_Linear!(f32, Vec2);
+impl_Linear!(f32, Vec3);
+impl_Linear!(f32, Vec3A);
+impl_Linear!(f32, Vec4);
+impl_Linear!(f32, Quat);
+
+impl_Linear!(f64, DVec2);
+impl_Linear!(f64, DVec3);
+impl_Linear!(f64, DVec4);
+impl_Linear!(f64, DQuat);
+
+impl_InnerSpace!(f32, Vec2, |a, b| Vec2::dot(a, b));
+impl_InnerSpace!(f32, Vec3, |a, b| Vec3::dot(a, b));
+impl_InnerSpace!(f32, Vec3A, |a, b| Vec3A::dot(a, b));
+impl_InnerSpace!(f32, Vec4, |a, b| Vec4::dot(a, b));
+impl_InnerSpace!(f32, Quat, |a, b| Quat::dot(a, b));
+
+impl_InnerSpace!(f64, DVec2, |a, b| DVec2::dot(a, b));
+impl_InnerSpace!(f64, DVec3, |a, b| DVec3::dot(a, b));
+impl_InnerSpace!(f64, DVec4, |a, b| DVec4::dot(a, b));
+impl_InnerSpace!(f64, DQuat, |a, b| DQuat::dot(a, b));
+
+impl_Planar!(f32, Vec2);
+impl_Planar!(f64, DVec2);
+
+impl_Spatial!(f32, Vec3, |a, b| Vec3::cross(a, b));
+impl_Spatial!(f32, Vec3A, |a, b| Vec3A::cross(a, b));
+impl_Spatial!(f64, DVec3, |a, b| DVec3::cross(a, b));
+
+// mixed precision: f64 sampling values with f32 values and conversely
+impl_InterpolateT!(f64, Vec2, f32, std::f64::consts::PI);
+impl_InterpolateT!(f64, Vec3, f32, std::f64::consts::PI);
+impl_InterpolateT!(f64, Vec3A, f32, std::f64::consts::PI);
+impl_InterpolateT!(f64, Vec4, f32, std::f64::consts::PI);
+impl_InterpolateT!(f64, Quat, f32, std::f64::consts::PI);
+impl_InterpolateT!(f32, DVec2, f64, std::f32::consts::PI);
+impl_InterpolateT!(f32, DVec3, f64, std::f32::consts::PI);
+impl_InterpolateT!(f32, DVec4, f64, std::f32::consts::PI);
+impl_InterpolateT!(f32, DQuat, f64, std::f32::consts::PI);
+
+impl_LinearT!(f64, Vec2, f32);
+impl_LinearT!(f64, Vec3, f32);
+impl_LinearT!(f64, Vec3A, f32);
+impl_LinearT!(f64, Vec4, f32);
+impl_LinearT!(f64, Quat, f32);
+impl_LinearT!(f32, DVec2, f64);
+impl_LinearT!(f32, DVec3, f64);
+impl_LinearT!(f32, DVec4, f64);
+impl_LinearT!(f32, DQuat, f64);
+
+impl_InnerSpace!(f64, Vec2, |a, b| Vec2::dot(a, b) as f64);
+impl_InnerSpace!(f64, Vec3, |a, b| Vec3::dot(a, b) as f64);
+impl_InnerSpace!(f64, Vec3A, |a, b| Vec3A::dot(a, b) as f64);
+impl_InnerSpace!(f64, Vec4, |a, b| Vec4::dot(a, b) as f64);
+impl_InnerSpace!(f64, Quat, |a, b| Quat::dot(a, b) as f64);
+impl_InnerSpace!(f32, DVec2, |a, b| DVec2::dot(a, b) as f32);
+impl_InnerSpace!(f32, DVec3, |a, b| DVec3::dot(a, b) as f32);
+impl_InnerSpace!(f32, DVec4, |a, b| DVec4::dot(a, b) as f32);
+impl_InnerSpace!(f32, DQuat, |a, b| DQuat::dot(a, b) as f32);
+
+impl_Planar!(f64, Vec2, f32);
+impl_Planar!(f32, DVec2, f64);
+
+impl_Spatial!(f64, Vec3, |a, b| Vec3::cross(a, b));
+impl_Spatial!(f64, Vec3A, |a, b| Vec3A::cross(a, b));
+impl_Spatial!(f32, DVec3, |a, b| DVec3::cross(a, b));
+
+#[cfg(any(feature = "serialization", feature = "serde"))]
+mod schema {
+  use crate::schema::{impl_Schema, number_array_schema};
+  use glam::{DQuat, DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+  // vectors and quaternions are serialized as arrays of their components
+  impl_Schema!(Vec2, number_array_schema(2));
+  impl_Schema!(Vec3, number_array_schema(3));
+  impl_Schema!(Vec3A, number_array_schema(3));
+  impl_Schema!(Vec4, number_array_schema(4));
+  impl_Schema!(Quat, number_array_schema(4));
+
+  impl_Schema!(DVec2, number_array_schema(2));
+  impl_Schema!(DVec3, number_array_schema(3));
+  impl_Schema!(DVec4, number_array_schema(4));
+  impl_Schema!(DQuat, number_array_schema(4));
+}
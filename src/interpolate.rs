@@ -6,13 +6,38 @@
 //! In order for a type to be used in [`Spline<K, V>`], some properties must be met about the `K`
 //! type must implementing several traits:
 //!
-//!   - [`One`], giving a neutral element for the multiplication monoid.
-//!   - [`Additive`], making the type additive (i.e. one can add or subtract with it).
-//!   - [`Linear`], unlocking linear combinations, required for interpolating.
-//!   - [`Trigo`], a trait giving *π* and *cosine*, required for e.g. cosine interpolation.
+//!   - [`Interpolator`], giving the way to normalize a sampling value between two keys.
+//!   - [`Scalar`], unlocking the arithmetic required by the higher-level features of the crate,
+//!     such as playback cursors.
+//!
+//! Some features also require the carried values `V` to implement extra traits:
+//!
+//!   - [`Linear`], unlocking linear combinations, required for computing derivatives.
+//!   - [`InnerSpace`], giving an inner product, required for geometric quantities such as lengths.
+//!   - [`Planar`], giving access to two coordinates, required for easing curves.
+//!   - [`Spatial`], giving a cross product, required for moving frames along 3D paths.
 //!
 //! Feel free to have a look at current implementors for further help.
 //!
+//! # Mixed precision
+//!
+//! The sampling type and the carried values don’t need to have the same precision: `f32` and
+//! `f64` sampling values can be used with both `f32` and `f64` values – scalars or the types of
+//! [glam](https://crates.io/crates/glam). For instance, `Spline<f64, Vec3>` keeps long timelines
+//! precise while storing compact values, and `Spline<f32, DVec3>` samples precise positions with
+//! `f32` times. The types of cgmath and nalgebra are generic over their scalar type: implementing
+//! mixed precision for them would prevent the compiler from inferring that scalar type, so they
+//! only support the sampling type matching their scalar type.
+//!
+//! Sampling values are always normalized in their own precision (see
+//! [`Interpolator::normalize`]); only the normalized time, lying in `[0; 1]`, and ratios of
+//! sampling values are then converted to the precision of the values, with `as`. Converting from
+//! `f64` to `f32` rounds to the nearest `f32`, which doesn’t lose any meaningful precision on such
+//! quantities. Inner products of values are converted back to the sampling type the same way.
+//!
+//! The `impl_InterpolateT!`, `impl_LinearT!` and `impl_Planar!` macros accept the scalar type of
+//! the values as an extra argument to implement mixed precision for your own types.
+//!
 //! > *Why doesn’t this crate use [num-traits] instead of
 //! > defining its own traits?*
 //!
@@ -20,12 +45,28 @@
 //! currently available easily with [num-traits]. Also, if something changes in [num-traits] with
 //! those traits, it would make this whole crate unstable.
 //!
+//! However, if you want to use exotic floating-point types (for instance, software-emulated
+//! `f128` or double-double types), you can enable the `"num-traits"` feature. It replaces the
+//! implementations for `f32` and `f64` with blanket implementations of all the traits of this
+//! module for any type implementing [num-traits]’ `Real` trait, so that such types work as both
+//! interpolators and carried values without any macro invocation. In `no_std` environments, the
+//! mathematical functions are provided by [libm](https://crates.io/crates/libm).
+//!
+//! > Because those implementations are blanket ones, any implementation of [`Interpolator`],
+//! > [`Scalar`], [`Interpolate`], [`Linear`] or [`InnerSpace`] for a type implementing `Real` –
+//! > written by hand or with the macros of this module – conflicts with them, and doesn’t compile
+//! > anymore once the feature is enabled. As features are additive, that happens as soon as any
+//! > crate of the dependency graph enables it: crates implementing those traits for their own
+//! > `Real` types should rely on the feature instead.
+//!
 //! [`Interpolate`]: crate::interpolate::Interpolate
 //! [`Spline<K, V>`]: crate::spline::Spline
-//! [`One`]: crate::interpolate::One
-//! [`Additive`]: crate::interpolate::Additive
+//! [`Interpolator`]: crate::interpolate::Interpolator
+//! [`Scalar`]: crate::interpolate::Scalar
 //! [`Linear`]: crate::interpolate::Linear
-//! [`Trigo`]: crate::interpolate::Trigo
+//! [`InnerSpace`]: crate::interpolate::InnerSpace
+//! [`Planar`]: crate::interpolate::Planar
+//! [`Spatial`]: crate::interpolate::Spatial
 //! [num-traits]: https://crates.io/crates/num-traits
 
 #[cfg(not(feature = "std"))]
@@ -37,11 +78,13 @@ use core::intrinsics::cosf32;
 #[cfg(not(feature = "std"))]
 use core::intrinsics::cosf64;
 #[cfg(not(feature = "std"))]
-use core::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 #[cfg(feature = "std")]
 use std::f32;
 #[cfg(feature = "std")]
 use std::f64;
+#[cfg(feature = "std")]
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Types that can be used as interpolator in splines.
 ///
@@ -51,6 +94,7 @@ pub trait Interpolator: Sized + Copy + PartialOrd {
   fn normalize(self, start: Self, end: Self) -> Self;
 }
 
+#[cfg(not(feature = "num-traits"))]
 macro_rules! impl_Interpolator {
   ($t:ty) => {
     impl Interpolator for $t {
@@ -61,14 +105,123 @@ macro_rules! impl_Interpolator {
   };
 }
 
+#[cfg(not(feature = "num-traits"))]
 impl_Interpolator!(f32);
+#[cfg(not(feature = "num-traits"))]
 impl_Interpolator!(f64);
 
+/// Interpolators that support basic arithmetic.
+///
+/// Sampling a spline only requires [`Interpolator`], but moving along a spline – e.g. with a
+/// [`Cursor`] – requires to be able to add, subtract and scale sampling values.
+///
+/// [`Cursor`]: crate::cursor::Cursor
+pub trait Scalar:
+  Interpolator
+  + Add<Self, Output = Self>
+  + Sub<Self, Output = Self>
+  + Mul<Self, Output = Self>
+  + Div<Self, Output = Self>
+  + Neg<Output = Self>
+{
+  /// Neutral element of the addition.
+  fn zero() -> Self;
+
+  /// Neutral element of the multiplication.
+  fn one() -> Self;
+
+  /// The *π* constant.
+  fn pi() -> Self;
+
+  /// Convert from a `f64`, possibly losing precision.
+  fn from_f64(x: f64) -> Self;
+
+  /// Convert to a `f64`, possibly losing precision.
+  fn to_f64(self) -> f64;
+
+  /// Absolute value.
+  fn abs(self) -> Self;
+
+  /// Largest integer lower than or equal to the value.
+  fn floor(self) -> Self;
+
+  /// Square root.
+  fn sqrt(self) -> Self;
+
+  /// Cosine.
+  fn cos(self) -> Self;
+
+  /// Sine.
+  fn sin(self) -> Self;
+
+  /// Exponential function.
+  fn exp(self) -> Self;
+}
+
+#[cfg(not(feature = "num-traits"))]
+macro_rules! impl_Scalar {
+  ($t:ty, $pi:expr) => {
+    impl Scalar for $t {
+      fn zero() -> Self {
+        0.
+      }
+
+      fn one() -> Self {
+        1.
+      }
+
+      fn pi() -> Self {
+        $pi
+      }
+
+      fn from_f64(x: f64) -> Self {
+        x as $t
+      }
+
+      fn to_f64(self) -> f64 {
+        self as f64
+      }
+
+      fn abs(self) -> Self {
+        <$t>::abs(self)
+      }
+
+      fn floor(self) -> Self {
+        <$t>::floor(self)
+      }
+
+      fn sqrt(self) -> Self {
+        <$t>::sqrt(self)
+      }
+
+      fn cos(self) -> Self {
+        <$t>::cos(self)
+      }
+
+      fn sin(self) -> Self {
+        <$t>::sin(self)
+      }
+
+      fn exp(self) -> Self {
+        <$t>::exp(self)
+      }
+    }
+  };
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl_Scalar!(f32, f32::consts::PI);
+#[cfg(not(feature = "num-traits"))]
+impl_Scalar!(f64, f64::consts::PI);
+
 /// Values that can be interpolated. Implementing this trait is required to perform sampling on splines.
 ///
 /// `T` is the interpolator used to sample with. Typical implementations use [`f32`] or [`f64`], but
 /// you’re free to use the ones you like.
-pub trait Interpolate<T>: Sized + Copy {
+///
+/// Values only need to be [`Clone`], so that heap-allocated values, such as a vector of blend-shape
+/// weights, can be sampled too. Geometric operations, requiring [`Linear`], still need [`Copy`].
+pub trait Interpolate<T>: Sized + Clone {
   /// Step interpolation.
   fn step(t: T, threshold: T, a: Self, b: Self) -> Self;
 
@@ -100,6 +253,149 @@ pub trait Interpolate<T>: Sized + Copy {
   fn cubic_bezier_mirrored(t: T, a: Self, u: Self, v: Self, b: Self) -> Self;
 }
 
+/// Values that can be linearly combined.
+///
+/// This trait is required to compute derivatives (tangents, for instance) of splines carrying such
+/// values.
+pub trait Linear<T>: Copy + Add<Self, Output = Self> + Sub<Self, Output = Self> {
+  /// Scale the value by an interpolator value.
+  fn outer_mul(self, t: T) -> Self;
+
+  /// Divide the value by an interpolator value.
+  fn outer_div(self, t: T) -> Self;
+}
+
+/// Values living in a space equipped with an inner product.
+///
+/// This trait is required to compute geometric quantities such as lengths or unit tangents.
+pub trait InnerSpace<T>: Linear<T> {
+  /// Inner (dot) product of two values.
+  fn dot(self, rhs: Self) -> T;
+
+  /// Euclidean norm of the value.
+  fn norm(self) -> T
+  where
+    T: Scalar,
+  {
+    self.dot(self).sqrt()
+  }
+}
+
+/// Values with two coordinates.
+///
+/// This trait is required to use 2D splines as easing curves, where the first coordinate is
+/// treated as the time and the second one as the eased value.
+pub trait Planar<T>: Linear<T> {
+  /// First coordinate.
+  fn x(self) -> T;
+
+  /// Second coordinate.
+  fn y(self) -> T;
+}
+
+/// Values living in a three-dimensional space.
+///
+/// This trait is required to compute moving frames (tangent, normal and binormal) along 3D paths.
+pub trait Spatial<T>: InnerSpace<T> {
+  /// Cross product of two values.
+  fn cross(self, rhs: Self) -> Self;
+}
+
+#[macro_export]
+macro_rules! impl_Linear {
+  ($t:ty, $v:ty) => {
+    impl $crate::interpolate::Linear<$t> for $v {
+      fn outer_mul(self, t: $t) -> Self {
+        self * t
+      }
+
+      fn outer_div(self, t: $t) -> Self {
+        self / t
+      }
+    }
+  };
+}
+
+#[macro_export]
+macro_rules! impl_LinearT {
+  ($t:ty, $v:ty) => {
+    impl $crate::interpolate::Linear<$t> for $v {
+      fn outer_mul(self, t: $t) -> Self {
+        self * Self::from(t)
+      }
+
+      fn outer_div(self, t: $t) -> Self {
+        self / Self::from(t)
+      }
+    }
+  };
+
+  ($t:ty, $v:ty, $s:ty) => {
+    impl $crate::interpolate::Linear<$t> for $v {
+      fn outer_mul(self, t: $t) -> Self {
+        self * (t as $s)
+      }
+
+      fn outer_div(self, t: $t) -> Self {
+        self / (t as $s)
+      }
+    }
+  };
+}
+
+#[macro_export]
+macro_rules! impl_InnerSpace {
+  ($t:ty, $v:ty, |$a:ident, $b:ident| $dot:expr) => {
+    impl $crate::interpolate::InnerSpace<$t> for $v {
+      fn dot(self, rhs: Self) -> $t {
+        let $a = self;
+        let $b = rhs;
+        $dot
+      }
+    }
+  };
+}
+
+#[macro_export]
+macro_rules! impl_Planar {
+  ($t:ty, $v:ty) => {
+    impl $crate::interpolate::Planar<$t> for $v {
+      fn x(self) -> $t {
+        self.x
+      }
+
+      fn y(self) -> $t {
+        self.y
+      }
+    }
+  };
+
+  ($t:ty, $v:ty, $s:ty) => {
+    impl $crate::interpolate::Planar<$t> for $v {
+      fn x(self) -> $t {
+        self.x as $t
+      }
+
+      fn y(self) -> $t {
+        self.y as $t
+      }
+    }
+  };
+}
+
+#[macro_export]
+macro_rules! impl_Spatial {
+  ($t:ty, $v:ty, |$a:ident, $b:ident| $cross:expr) => {
+    impl $crate::interpolate::Spatial<$t> for $v {
+      fn cross(self, rhs: Self) -> Self {
+        let $a = self;
+        let $b = rhs;
+        $cross
+      }
+    }
+  };
+}
+
 #[macro_export]
 macro_rules! impl_Interpolate {
   ($t:ty, $v:ty, $pi:expr) => {
@@ -230,8 +526,90 @@ macro_rules! impl_InterpolateT {
       }
     }
   };
+
+  ($t:ty, $v:ty, $s:ty, $pi:expr) => {
+    impl $crate::interpolate::Interpolate<$t> for $v {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        let cos_nt = (1. - (t * $pi).cos()) * 0.5;
+        <Self as $crate::interpolate::Interpolate<$t>>::lerp(cos_nt, a, b)
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        let t = t as $s;
+        a * (1. - t) + b * t
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        // sampler stuff
+        let t = t as $s;
+        let two_t = t * 2.;
+        let three_t = t * 3.;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two_t3 = t2 * two_t;
+        let two_t2 = t * two_t;
+        let three_t2 = t * three_t;
+
+        // tangents; ratios of sampling values are computed before being converted
+        let m0 = (b.1 - x.1) * ((b.0 - a.0) / (b.0 - x.0)) as $s;
+        let m1 = (y.1 - a.1) * ((b.0 - a.0) / (y.0 - a.0)) as $s;
+
+        a.1 * (two_t3 - three_t2 + 1.)
+          + m0 * (t3 - two_t2 + t)
+          + b.1 * (three_t2 - two_t3)
+          + m1 * (t3 - t2)
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        let t = t as $s;
+        let one_t = 1. - t;
+        let one_t2 = one_t * one_t;
+
+        u + (a - u) * one_t2 + (b - u) * t * t
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        let t = t as $s;
+        let one_t = 1. - t;
+        let one_t2 = one_t * one_t;
+        let one_t3 = one_t2 * one_t;
+        let t2 = t * t;
+
+        a * one_t3 + (u * one_t2 * t + v * one_t * t2) * 3. + b * t2 * t
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <Self as $crate::interpolate::Interpolate<$t>>::cubic_bezier(t, a, u, b + b - v, b)
+      }
+    }
+  };
 }
 
+#[cfg(not(feature = "num-traits"))]
 impl_Interpolate!(f32, f32, std::f32::consts::PI);
+#[cfg(not(feature = "num-traits"))]
 impl_Interpolate!(f64, f64, std::f64::consts::PI);
 impl_InterpolateT!(f32, f64, std::f32::consts::PI);
+impl_InterpolateT!(f64, f32, f32, std::f64::consts::PI);
+
+#[cfg(not(feature = "num-traits"))]
+impl_Linear!(f32, f32);
+#[cfg(not(feature = "num-traits"))]
+impl_Linear!(f64, f64);
+impl_LinearT!(f32, f64);
+impl_LinearT!(f64, f32, f32);
+
+#[cfg(not(feature = "num-traits"))]
+impl_InnerSpace!(f32, f32, |a, b| a * b);
+#[cfg(not(feature = "num-traits"))]
+impl_InnerSpace!(f64, f64, |a, b| a * b);
+impl_InnerSpace!(f32, f64, |a, b| (a * b) as f32);
+impl_InnerSpace!(f64, f32, |a, b| (a * b) as f64);
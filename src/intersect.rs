@@ -0,0 +1,250 @@
+//! Intersections of 2D splines.
+
+use crate::extrema::segment_extrema_in;
+use crate::interpolate::{Linear, Planar, Scalar};
+use crate::segment::Shape;
+use crate::solve::segment_roots;
+use crate::spline::Spline;
+use std::cmp::Ordering;
+use std::slice;
+
+/// Maximum number of times a pair of segments can be subdivided.
+const MAX_DEPTH: u32 = 52;
+
+/// Intersection of a spline with another shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Intersection<T, V> {
+  /// Sampling value of the spline at the intersection.
+  pub t: T,
+  /// Intersection point.
+  pub point: V,
+  /// Parameter of the intersection point on the other shape: a value in `[0; 1]` for line
+  /// segments, the sampling value of the other spline for splines.
+  pub u: T,
+}
+
+impl<T, V> Spline<T, V> {
+  /// Intersections of a 2D spline with the line segment going from `a` to `b`.
+  ///
+  /// Every segment of the spline is projected on the normal of the line, and the roots of the
+  /// projection are found analytically on cubic segments (Catmull-Rom, Bézier, linear) and by
+  /// bisection on cosine segments. Roots whose point lies between `a` and `b` are returned, ordered
+  /// by sampling value; the parameter of each point on the line segment is given by
+  /// [`Intersection::u`] (`0` at `a`, `1` at `b`).
+  ///
+  /// Parts of the spline lying on the line only yield their first point, step segments only
+  /// intersect the line with the values of their keys, and segments that cannot be sampled (see
+  /// [`Spline::sample`]) are ignored. If `a` and `b` are equal, there is no intersection.
+  pub fn intersect_segment(&self, a: V, b: V) -> Vec<Intersection<T, V>>
+  where
+    T: Scalar + Linear<T>,
+    V: Planar<T>,
+  {
+    let keys = self.keys();
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let length2 = dx * dx + dy * dy;
+    let mut intersections: Vec<Intersection<T, V>> = Vec::new();
+
+    if length2 == T::zero() {
+      return intersections;
+    }
+
+    // signed distance to the line, scaled by the length of the line segment
+    let side = |v: V| v.x() * dy - v.y() * dx;
+
+    for i in 0..keys.len().saturating_sub(1) {
+      let shape = match Shape::new(keys, i) {
+        Some(shape) => shape,
+        None => continue,
+      };
+      let (t0, t1) = (keys[i].t, keys[i + 1].t);
+
+      for nt in segment_roots(&shape.map(side), side(a)) {
+        let t = if nt == T::one() {
+          t1
+        } else {
+          t0 + (t1 - t0) * nt
+        };
+        let point = shape.value(nt);
+        let u = ((point.x() - a.x()) * dx + (point.y() - a.y()) * dy) / length2;
+
+        // roots at the end of a segment are found again at the start of the next one
+        let duplicate = intersections.last().is_some_and(|last| last.t == t);
+
+        if u >= T::zero() && u <= T::one() && !duplicate {
+          intersections.push(Intersection { t, point, u });
+        }
+      }
+    }
+
+    intersections
+  }
+
+  /// Intersections of two 2D splines.
+  ///
+  /// Every pair of segments is recursively subdivided, discarding the pieces whose exact bounding
+  /// boxes don’t overlap, until both pieces are smaller than `tolerance` on both axes. Intersections
+  /// are then located up to `tolerance`, and intersections closer than `tolerance` to each other
+  /// are merged. They are returned ordered by sampling value of `self`, with the sampling value of
+  /// `other` given by [`Intersection::u`].
+  ///
+  /// Overlapping parts of the splines yield intersections every `tolerance` or so along the
+  /// overlap. Segments that cannot be sampled (see [`Spline::sample`]) are ignored.
+  pub fn intersect_spline(&self, other: &Self, tolerance: T) -> Vec<Intersection<T, V>>
+  where
+    T: Scalar + Linear<T>,
+    V: Planar<T>,
+  {
+    let mut intersections = Vec::new();
+
+    for i in 0..self.len().saturating_sub(1) {
+      let a = match Piece::new(self, i) {
+        Some(a) => a,
+        None => continue,
+      };
+
+      for j in 0..other.len().saturating_sub(1) {
+        if let Some(b) = Piece::new(other, j) {
+          subdivide(&a, &b, tolerance, 0, &mut intersections);
+        }
+      }
+    }
+
+    intersections.sort_by(|a, b| a.t.partial_cmp(&b.t).unwrap_or(Ordering::Less));
+
+    let mut merged: Vec<Intersection<T, V>> = Vec::with_capacity(intersections.len());
+
+    for intersection in intersections {
+      let close = merged.iter().any(|m| {
+        (m.point.x() - intersection.point.x()).abs() <= tolerance
+          && (m.point.y() - intersection.point.y()).abs() <= tolerance
+      });
+
+      if !close {
+        merged.push(intersection);
+      }
+    }
+
+    merged
+  }
+}
+
+/// Part of a segment of a 2D spline, between two normalized times.
+#[derive(Clone, Copy)]
+struct Piece<T, V> {
+  shape: Shape<T, V>,
+  x: Shape<T, T>,
+  y: Shape<T, T>,
+  t0: T,
+  t1: T,
+  lo: T,
+  hi: T,
+}
+
+impl<T, V> Piece<T, V>
+where
+  T: Scalar + Linear<T>,
+  V: Planar<T>,
+{
+  /// Whole segment starting at key `i`.
+  fn new(spline: &Spline<T, V>, i: usize) -> Option<Self> {
+    let keys = spline.keys();
+    let shape = Shape::new(keys, i)?;
+
+    Some(Piece {
+      shape,
+      x: shape.map(V::x),
+      y: shape.map(V::y),
+      t0: keys[i].t,
+      t1: keys[i + 1].t,
+      lo: T::zero(),
+      hi: T::one(),
+    })
+  }
+
+  /// Exact bounding box, as `[min x, min y, max x, max y]`.
+  fn aabb(&self) -> [T; 4] {
+    let [(_, min_x), (_, max_x)] = segment_extrema_in(&self.x, self.lo, self.hi);
+    let [(_, min_y), (_, max_y)] = segment_extrema_in(&self.y, self.lo, self.hi);
+    [min_x, min_y, max_x, max_y]
+  }
+
+  /// Split the piece in two halves.
+  fn split(&self) -> [Self; 2] {
+    let middle = (self.lo + self.hi) * T::from_f64(0.5);
+    [
+      Piece {
+        hi: middle,
+        ..*self
+      },
+      Piece {
+        lo: middle,
+        ..*self
+      },
+    ]
+  }
+
+  /// Normalized time of the middle of the piece.
+  fn middle(&self) -> T {
+    (self.lo + self.hi) * T::from_f64(0.5)
+  }
+
+  /// Sampling value of a normalized time of the segment.
+  fn t(&self, nt: T) -> T {
+    self.t0 + (self.t1 - self.t0) * nt
+  }
+}
+
+/// Recursively subdivide two pieces whose bounding boxes overlap, collecting their intersections.
+fn subdivide<T, V>(
+  a: &Piece<T, V>,
+  b: &Piece<T, V>,
+  tolerance: T,
+  depth: u32,
+  intersections: &mut Vec<Intersection<T, V>>,
+) where
+  T: Scalar + Linear<T>,
+  V: Planar<T>,
+{
+  let box_a = a.aabb();
+  let box_b = b.aabb();
+
+  if box_a[2] < box_b[0] || box_b[2] < box_a[0] || box_a[3] < box_b[1] || box_b[3] < box_a[1] {
+    return;
+  }
+
+  let small = |aabb: [T; 4]| aabb[2] - aabb[0] <= tolerance && aabb[3] - aabb[1] <= tolerance;
+
+  if depth >= MAX_DEPTH || (small(box_a) && small(box_b)) {
+    let nt = a.middle();
+
+    intersections.push(Intersection {
+      t: a.t(nt),
+      point: a.shape.value(nt),
+      u: b.t(b.middle()),
+    });
+
+    return;
+  }
+
+  // only split the pieces that are still too large
+  let (split_a, split_b);
+  let halves_a = if small(box_a) {
+    slice::from_ref(a)
+  } else {
+    split_a = a.split();
+    &split_a[..]
+  };
+  let halves_b = if small(box_b) {
+    slice::from_ref(b)
+  } else {
+    split_b = b.split();
+    &split_b[..]
+  };
+
+  for half_a in halves_a {
+    for half_b in halves_b {
+      subdivide(half_a, half_b, tolerance, depth + 1, intersections);
+    }
+  }
+}
@@ -1,12 +1,32 @@
 //! Spline [`Iterator`], in a nutshell.
 //!
 //! You can iterate over a [`Spline<K, V>`]’s keys with the [`IntoIterator`] trait on
-//! `&Spline<K, V>`. This gives you iterated [`Key<K, V>`] keys.
+//! `&Spline<K, V>`. This gives you iterated [`Key<K, V>`] keys. Consuming a spline with
+//! [`IntoIterator`] yields its keys by value, and [`Spline::iter_mut`] and [`Spline::values_mut`]
+//! allow to edit them in place. [`Spline::drain`] removes a range of keys and yields them.
+//!
+//! You can also iterate over samples taken at a fixed step with [`Spline::iter_samples`].
 //!
 //! [`Spline<K, V>`]: crate::spline::Spline
 //! [`Key<K, V>`]: crate::key::Key
 
+use crate::interpolate::{Interpolate, Scalar};
+use crate::spline::{clamped_sample_hinted, KeyMut};
 use crate::{Key, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use core::slice;
+#[cfg(feature = "std")]
+use std::slice;
+#[cfg(feature = "std")]
+use std::vec;
+
+// Draining iterator of the container of the keys of a spline.
+#[cfg(not(feature = "smallvec"))]
+type KeysDrain<'a, T, V> = vec::Drain<'a, Key<T, V>>;
+#[cfg(feature = "smallvec")]
+type KeysDrain<'a, T, V> = smallvec::Drain<'a, [Key<T, V>; crate::spline::INLINE_KEYS]>;
 
 /// Iterator over spline keys.
 ///
@@ -26,7 +46,7 @@ impl<'a, T, V> Iterator for Iter<'a, T, V> {
   fn next(&mut self) -> Option<Self::Item> {
     let r = self.spline.0.get(self.i);
 
-    if let Some(_) = r {
+    if r.is_some() {
       self.i += 1;
     }
 
@@ -42,3 +62,226 @@ impl<'a, T, V> IntoIterator for &'a Spline<T, V> {
     Iter { spline: self, i: 0 }
   }
 }
+
+/// Owning iterator over spline keys.
+///
+/// This iterator type is guaranteed to iterate over sorted keys.
+pub struct IntoIter<T, V> {
+  keys: vec::IntoIter<Key<T, V>>,
+}
+
+impl<T, V> Iterator for IntoIter<T, V> {
+  type Item = Key<T, V>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.keys.next()
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.keys.size_hint()
+  }
+}
+
+impl<T, V> DoubleEndedIterator for IntoIter<T, V> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.keys.next_back()
+  }
+}
+
+impl<T, V> ExactSizeIterator for IntoIter<T, V> {}
+
+impl<T, V> IntoIterator for Spline<T, V> {
+  type Item = Key<T, V>;
+  type IntoIter = IntoIter<T, V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIter {
+      keys: self.into_keys().into_iter(),
+    }
+  }
+}
+
+/// Iterator over mutable spline keys.
+///
+/// See [`Spline::iter_mut`].
+pub struct IterMut<'a, T, V> {
+  keys: slice::IterMut<'a, Key<T, V>>,
+}
+
+impl<'a, T, V> Iterator for IterMut<'a, T, V> {
+  type Item = KeyMut<'a, T, V>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.keys.next().map(KeyMut::new)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.keys.size_hint()
+  }
+}
+
+impl<'a, T, V> DoubleEndedIterator for IterMut<'a, T, V> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.keys.next_back().map(KeyMut::new)
+  }
+}
+
+impl<'a, T, V> ExactSizeIterator for IterMut<'a, T, V> {}
+
+/// Iterator over the mutable carried values of spline keys.
+///
+/// See [`Spline::values_mut`].
+pub struct ValuesMut<'a, T, V> {
+  keys: slice::IterMut<'a, Key<T, V>>,
+}
+
+impl<'a, T, V> Iterator for ValuesMut<'a, T, V> {
+  type Item = &'a mut V;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.keys.next().map(|key| &mut key.value)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.keys.size_hint()
+  }
+}
+
+impl<'a, T, V> DoubleEndedIterator for ValuesMut<'a, T, V> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.keys.next_back().map(|key| &mut key.value)
+  }
+}
+
+impl<'a, T, V> ExactSizeIterator for ValuesMut<'a, T, V> {}
+
+/// Draining iterator over spline keys.
+///
+/// See [`Spline::drain`].
+pub struct Drain<'a, T, V> {
+  pub(crate) keys: KeysDrain<'a, T, V>,
+}
+
+impl<'a, T, V> Iterator for Drain<'a, T, V> {
+  type Item = Key<T, V>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.keys.next()
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.keys.size_hint()
+  }
+}
+
+impl<'a, T, V> DoubleEndedIterator for Drain<'a, T, V> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.keys.next_back()
+  }
+}
+
+impl<'a, T, V> ExactSizeIterator for Drain<'a, T, V> {}
+
+impl<T, V> Spline<T, V> {
+  /// Iterate over mutable keys, sorted.
+  ///
+  /// As with [`Spline::get_mut`], the sampling values of the keys cannot be changed.
+  pub fn iter_mut(&mut self) -> IterMut<'_, T, V> {
+    IterMut {
+      keys: self.0.iter_mut(),
+    }
+  }
+
+  /// Iterate over the mutable carried values of the keys, sorted.
+  pub fn values_mut(&mut self) -> ValuesMut<'_, T, V> {
+    ValuesMut {
+      keys: self.0.iter_mut(),
+    }
+  }
+
+  /// Iterate over samples taken at a fixed step, from `start` to the last key.
+  ///
+  /// The iterator yields `(t, value)` pairs, where the `t`s are `start`, `start + step`,
+  /// `start + 2 * step`, etc. Each `t` is computed from `start` rather than accumulated, so that
+  /// rounding errors don’t build up, and the last key is included if it falls exactly on a step.
+  /// Use [`Samples::until`] to stop earlier (or later).
+  ///
+  /// Samples are taken with clamping (see [`Spline::clamped_sample`]); at `t`s at which the spline
+  /// cannot be sampled – e.g. near the ends of an [`Interpolation::CatmullRom`] spline –, the
+  /// fallback value of the spline is yielded if set (see [`Spline::set_fallback`]), otherwise
+  /// these `t`s are skipped. If `step` is not positive or if the spline has no key, the iterator is
+  /// empty.
+  ///
+  /// [`Interpolation::CatmullRom`]: crate::interpolation::Interpolation::CatmullRom
+  pub fn iter_samples(&self, start: T, step: T) -> Samples<'_, T, V>
+  where
+    T: Scalar,
+  {
+    let end = self.0.last().map_or(start, |key| key.t);
+
+    Samples {
+      spline: self,
+      start,
+      step,
+      end,
+      k: 0,
+      hint: None,
+    }
+  }
+}
+
+/// Iterator over samples of a spline taken at a fixed step.
+///
+/// This iterator is created with [`Spline::iter_samples`].
+pub struct Samples<'a, T, V>
+where
+  T: 'a,
+  V: 'a,
+{
+  spline: &'a Spline<T, V>,
+  start: T,
+  step: T,
+  end: T,
+  k: usize,
+  hint: Option<usize>,
+}
+
+impl<'a, T, V> Samples<'a, T, V> {
+  /// Stop at `end` (included) instead of the last key of the spline.
+  ///
+  /// Samples taken after the last key are clamped.
+  pub fn until(self, end: T) -> Self {
+    Samples { end, ..self }
+  }
+}
+
+impl<'a, T, V> Iterator for Samples<'a, T, V>
+where
+  T: Scalar,
+  V: Interpolate<T>,
+{
+  type Item = (T, V);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.step <= T::zero() || self.spline.0.is_empty() {
+      return None;
+    }
+
+    loop {
+      let t = self.start + self.step * T::from_f64(self.k as f64);
+
+      if t > self.end {
+        return None;
+      }
+
+      self.k += 1;
+
+      let value =
+        clamped_sample_hinted(&self.spline.0, &mut self.hint, t).or_else(|| self.spline.1.clone());
+
+      if let Some(value) = value {
+        return Some((t, value));
+      }
+    }
+  }
+}
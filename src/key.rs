@@ -29,7 +29,28 @@ pub struct Key<T, V> {
   /// Carried value.
   pub value: V,
   /// Interpolation mode.
+  ///
+  /// This is the mode with which the spline leaves the key, i.e. the one used on the segment
+  /// starting at this key.
   pub interpolation: Interpolation<T, V>,
+  /// Interpolation mode with which the spline reaches the key, if different from
+  /// [`Key::interpolation`].
+  ///
+  /// Only the Bézier interpolation modes of the previous key look at the incoming mode of the next
+  /// key, to find its input tangent. Setting this allows a key to have _broken_ interpolation –
+  /// for instance, to be reached with a cubic Bézier curve while being left stepped, which is
+  /// how animation software such as Maya or Blender model their curves.
+  #[cfg_attr(
+    any(feature = "serialization", feature = "serde"),
+    serde(default = "Option::default", skip_serializing_if = "Option::is_none")
+  )]
+  pub in_interpolation: Option<Interpolation<T, V>>,
+  /// Constraint applied to the tangents of the key when editing them.
+  #[cfg_attr(
+    any(feature = "serialization", feature = "serde"),
+    serde(default, skip_serializing_if = "is_free")
+  )]
+  pub tangent_constraint: TangentConstraint,
 }
 
 impl<T, V> Key<T, V> {
@@ -39,6 +60,57 @@ impl<T, V> Key<T, V> {
       t,
       value,
       interpolation,
+      in_interpolation: None,
+      tangent_constraint: TangentConstraint::default(),
     }
   }
+
+  /// Interpolation mode with which the spline reaches the key.
+  ///
+  /// This is [`Key::in_interpolation`] if set, [`Key::interpolation`] otherwise.
+  pub fn incoming(&self) -> &Interpolation<T, V> {
+    self
+      .in_interpolation
+      .as_ref()
+      .unwrap_or(&self.interpolation)
+  }
+}
+
+/// Constraint applied to the tangents (i.e. the Bézier handles) of a [`Key`] when editing them.
+///
+/// Constraints are enforced by the tangent-editing methods of [`KeyMut`], such as
+/// [`KeyMut::set_out_tangent`]: editing one handle automatically updates the other one. They don’t
+/// have any effect on sampling, which always uses the handles stored in the
+/// [`Interpolation`].
+///
+/// [`KeyMut`]: crate::spline::KeyMut
+/// [`KeyMut::set_out_tangent`]: crate::spline::KeyMut::set_out_tangent
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+  any(feature = "serialization", feature = "serde"),
+  derive(Deserialize, Serialize),
+  serde(rename_all = "snake_case")
+)]
+pub enum TangentConstraint {
+  /// Both handles can be edited independently.
+  Free,
+
+  /// Both handles stay collinear and opposite, but keep their own length.
+  Aligned,
+
+  /// Both handles stay collinear, opposite and of the same length.
+  Mirrored,
+}
+
+impl Default for TangentConstraint {
+  /// [`TangentConstraint::Free`] is the default.
+  fn default() -> Self {
+    TangentConstraint::Free
+  }
+}
+
+// Check whether a tangent constraint is the default one, which is not serialized.
+#[cfg(any(feature = "serialization", feature = "serde"))]
+fn is_free(constraint: &TangentConstraint) -> bool {
+  *constraint == TangentConstraint::Free
 }
@@ -0,0 +1,80 @@
+use crate::convert::ConversionError;
+use crate::interpolate::Scalar;
+use crate::interpolation::Interpolation;
+use crate::spline::Spline;
+use keyframe::{functions::Linear, AnimationSequence, CanTween, EasingFunction, Keyframe};
+
+/// Easing function of step segments.
+struct StepEasing {
+  threshold: f64,
+}
+
+impl EasingFunction for StepEasing {
+  fn y(&self, x: f64) -> f64 {
+    if x < self.threshold {
+      0.
+    } else {
+      1.
+    }
+  }
+}
+
+/// Easing function of cosine segments.
+struct CosineEasing;
+
+impl EasingFunction for CosineEasing {
+  fn y(&self, x: f64) -> f64 {
+    (1. - (x * std::f64::consts::PI).cos()) * 0.5
+  }
+}
+
+/// Convert a spline to an animation sequence, one keyframe per key.
+///
+/// Linear, cosine and step segments are converted to the equivalent easing functions. Catmull-Rom
+/// and Bézier segments depend on more than the two values they link and cannot be expressed as
+/// easing functions; they yield [`ConversionError::UnsupportedSegment`].
+///
+/// Animation sequences hold their first and last values out of their keyframes, which is the
+/// behavior of [`Spline::clamped_sample`].
+impl<T, V> TryFrom<&Spline<T, V>> for AnimationSequence<V>
+where
+  T: Scalar,
+  V: CanTween + Copy,
+{
+  type Error = ConversionError;
+
+  fn try_from(spline: &Spline<T, V>) -> Result<Self, Self::Error> {
+    let keys = spline.keys();
+    let keyframes = keys
+      .iter()
+      .enumerate()
+      .map(|(i, key)| {
+        let t = key.t.to_f64();
+
+        if i + 1 == keys.len() {
+          return Ok(Keyframe::new(key.value, t, Linear));
+        }
+
+        let keyframe = match key.interpolation {
+          Interpolation::Linear => Keyframe::new(key.value, t, Linear),
+
+          Interpolation::Step(threshold) => Keyframe::new(
+            key.value,
+            t,
+            StepEasing {
+              threshold: threshold.to_f64(),
+            },
+          ),
+
+          Interpolation::Cosine => Keyframe::new(key.value, t, CosineEasing),
+
+          _ => return Err(ConversionError::UnsupportedSegment(i)),
+        };
+
+        Ok(keyframe)
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(AnimationSequence::from(keyframes))
+  }
+}
@@ -0,0 +1,136 @@
+use crate::convert::{cubic_bezier_points, ConversionError};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::Spline;
+use crate::{impl_InnerSpace, impl_Interpolate, impl_Linear, impl_Planar};
+use kurbo::{BezPath, PathEl, Vec2};
+
+impl_Interpolate!(f64, Vec2, std::f64::consts::PI);
+impl_Linear!(f64, Vec2);
+impl_InnerSpace!(f64, Vec2, |a, b| Vec2::dot(a, b));
+impl_Planar!(f64, Vec2);
+
+/// Convert a spline to a path, made of a single subpath.
+///
+/// Linear and Bézier segments are converted to lines and Bézier curves, and Catmull-Rom segments to
+/// their cubic Bézier form. Step and cosine segments, as well as segments that cannot be sampled,
+/// have no equivalent and yield [`ConversionError::UnsupportedSegment`].
+///
+/// Sampling values are not kept, since paths have no notion of time.
+impl TryFrom<&Spline<f64, Vec2>> for BezPath {
+  type Error = ConversionError;
+
+  fn try_from(spline: &Spline<f64, Vec2>) -> Result<Self, Self::Error> {
+    let keys = spline.keys();
+    let mut path = BezPath::new();
+
+    if let Some(first) = keys.first() {
+      path.move_to(first.value.to_point());
+    }
+
+    for (i, pair) in keys.windows(2).enumerate() {
+      let (a, b) = (&pair[0], &pair[1]);
+
+      match a.interpolation {
+        Interpolation::Linear => path.line_to(b.value.to_point()),
+
+        Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => match *b.incoming() {
+          Interpolation::Bezier(v) => path.curve_to(
+            u.to_point(),
+            (b.value * 2. - v).to_point(),
+            b.value.to_point(),
+          ),
+          Interpolation::StrokeBezier(v, _) => {
+            path.curve_to(u.to_point(), v.to_point(), b.value.to_point())
+          }
+          _ => path.quad_to(u.to_point(), b.value.to_point()),
+        },
+
+        Interpolation::CatmullRom => {
+          let [_, u, v, b] = cubic_bezier_points(keys, i)?;
+          path.curve_to(u.to_point(), v.to_point(), b.to_point());
+        }
+
+        _ => return Err(ConversionError::UnsupportedSegment(i)),
+      }
+    }
+
+    Ok(path)
+  }
+}
+
+/// Convert a path, made of a single subpath, to a spline.
+///
+/// Every point reached by the path becomes a key, with consecutive sampling values starting at `0`.
+/// Lines are converted to linear segments and curves to Bézier segments, so that the spline has
+/// exactly the same geometry as the path. A closed subpath ends with a line back to its start, if
+/// not already there.
+///
+/// Paths made of several subpaths yield [`ConversionError::Disconnected`].
+impl TryFrom<&BezPath> for Spline<f64, Vec2> {
+  type Error = ConversionError;
+
+  fn try_from(path: &BezPath) -> Result<Self, Self::Error> {
+    let mut keys: Vec<Key<f64, Vec2>> = Vec::new();
+    let mut closed = false;
+
+    for el in path.elements() {
+      let start = keys.first().map(|first| first.value);
+      let (start, last) = match (start, keys.last_mut()) {
+        (Some(start), Some(last)) if !closed => (start, last),
+
+        (None, _) => {
+          if let PathEl::MoveTo(p) = *el {
+            keys.push(Key::new(0., p.to_vec2(), Interpolation::Linear));
+            continue;
+          }
+
+          return Err(ConversionError::Disconnected);
+        }
+
+        _ => return Err(ConversionError::Disconnected),
+      };
+      let t = last.t + 1.;
+
+      let key = match *el {
+        PathEl::MoveTo(_) => return Err(ConversionError::Disconnected),
+
+        PathEl::LineTo(p) => {
+          last.interpolation = Interpolation::Linear;
+          Key::new(t, p.to_vec2(), Interpolation::Linear)
+        }
+
+        PathEl::QuadTo(u, p) => {
+          last.interpolation = Interpolation::Bezier(u.to_vec2());
+
+          let mut key = Key::new(t, p.to_vec2(), Interpolation::Linear);
+          key.in_interpolation = Some(Interpolation::Linear);
+          key
+        }
+
+        PathEl::CurveTo(u, v, p) => {
+          last.interpolation = Interpolation::Bezier(u.to_vec2());
+
+          let mut key = Key::new(t, p.to_vec2(), Interpolation::Linear);
+          key.in_interpolation = Some(Interpolation::StrokeBezier(v.to_vec2(), v.to_vec2()));
+          key
+        }
+
+        PathEl::ClosePath => {
+          closed = true;
+
+          if last.value == start {
+            continue;
+          }
+
+          last.interpolation = Interpolation::Linear;
+          Key::new(t, start, Interpolation::Linear)
+        }
+      };
+
+      keys.push(key);
+    }
+
+    Ok(Spline::from_vec(keys))
+  }
+}
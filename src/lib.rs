@@ -10,6 +10,9 @@
 //!   - [`Interpolation`], the type of possible interpolation for each segment.
 //!   - [`Spline`], a spline from which you can *sample* points by interpolation.
 //!
+//! Those types, along with the most commonly used symbols of the crate, are available at once in
+//! the [`prelude`] module.
+//!
 //! When adding control points, you add new sections. Two control points define a section – i.e.
 //! it’s not possible to define a spline without at least two control points. Every time you add a
 //! new control point, a new section is created. Each section is assigned an interpolation mode that
@@ -62,6 +65,32 @@
 //! assert_eq!(spline.clamped_sample(1.1), Some(10.)); // clamped to the last key
 //! ```
 //!
+//! # Playback
+//!
+//! Animations usually don’t sample at arbitrary sampling values but rather move a playhead along
+//! the spline, frame after frame. The [`Cursor`] type does exactly that: it borrows a [`Spline`],
+//! can be paused, sped up or reversed, and reports the keys it crosses every time it’s advanced.
+//!
+//! ```
+//! # use splines::{Interpolation, Key, Spline};
+//! use splines::cursor::{Cursor, PlaybackEvent};
+//!
+//! # let start = Key::new(0., 0., Interpolation::Linear);
+//! # let end = Key::new(1., 10., Interpolation::Linear);
+//! # let spline = Spline::from_vec(vec![start, end]);
+//! let mut cursor = Cursor::new(&spline, 0.);
+//! assert_eq!(cursor.advance(0.5).count(), 0);
+//! assert_eq!(cursor.sample(), Some(5.));
+//!
+//! let events: Vec<_> = cursor.advance(0.5).collect();
+//! assert!(matches!(events[..], [PlaybackEvent::KeyCrossed { key: 1, .. }]));
+//! ```
+//!
+//! # Presets
+//!
+//! Common gameplay curves – sigmoid ramps, exponential decays, bell curves and LFOs – can be built
+//! directly with the functions of the [`presets`] module.
+//!
 //! # Polymorphic sampling types
 //!
 //! [`Spline`] curves are parametered both by the carried value (being interpolated) but also the
@@ -87,7 +116,10 @@
 //!   - **Serde.**
 //!     - This feature implements both the `Serialize` and `Deserialize` traits from `serde` for all
 //!       types exported by this crate.
+//!     - The serialization format is described by a JSON Schema; see the `schema` module.
 //!     - Enable with the `"serde"` feature.
+//!     - Unknown fields of keys, such as the data attached by external editors, can be kept across
+//!       round-trips with the `extra` module. Enable with the `"serde_json"` feature.
 //!   - **[cgmath](https://crates.io/crates/cgmath) implementors.**
 //!     - Adds some useful implementations of `Interpolate` for some cgmath types.
 //!     - Enable with the `"cgmath"` feature.
@@ -97,6 +129,49 @@
 //!   - **[nalgebra](https://crates.io/crates/nalgebra) implementors.**
 //!     - Adds some useful implementations of `Interpolate` for some nalgebra types.
 //!     - Enable with the `"nalgebra"` feature.
+//!   - **[kurbo](https://crates.io/crates/kurbo), [keyframe](https://crates.io/crates/keyframe)
+//!     and [flo_curves](https://crates.io/crates/flo_curves) conversions.**
+//!     - Adds conversions between splines and the curve types of those crates; see the `convert`
+//!       module.
+//!     - Enable with the `"kurbo"`, `"keyframe"` and `"flo_curves"` features.
+//!   - **[num-traits](https://crates.io/crates/num-traits) blanket implementations.**
+//!     - Implements all the traits required by the crate for any type implementing `Real`, so
+//!       that exotic floating-point types can be used without any macro invocation.
+//!     - Replaces the implementations for `f32` and `f64`: implementing the traits of the crate
+//!       for your own `Real` types then conflicts with it (see the `interpolate` module).
+//!     - Enable with the `"num-traits"` feature.
+//!   - **[rayon](https://crates.io/crates/rayon) parallel sampling.**
+//!     - Adds `Spline::par_sample_many` and `Spline::par_bake`, splitting the sampled times across
+//!       threads.
+//!     - Enable with the `"rayon"` feature.
+//!   - **[arc-swap](https://crates.io/crates/arc-swap) hot-swappable splines.**
+//!     - Adds `SharedSpline`, letting a thread publish new splines while others sample them
+//!       without locking. See the `shared` module.
+//!     - Enable with the `"arc-swap"` feature.
+//!   - **[smallvec](https://crates.io/crates/smallvec) key storage.**
+//!     - Stores the keys of splines of up to eight keys inline, without any heap allocation.
+//!     - Enable with the `"smallvec"` feature.
+//!   - **[bytemuck](https://crates.io/crates/bytemuck) flattened splines.**
+//!     - Flattens splines into arrays of plain old data, ready to be uploaded to GPU buffers or
+//!       memory-mapped. See the `pod` module.
+//!     - Enable with the `"bytemuck"` feature.
+//!   - **Noise modulation.**
+//!     - Adds smooth value noise on top of splines, for organic variation such as wind or idle
+//!       motions. See the `noise` module.
+//!     - Enable with the `"noise"` feature.
+//!   - **Shader code generation.**
+//!     - Generates GLSL and WGSL functions evaluating splines on the GPU. See the `shader` module.
+//!     - Requires the standard library.
+//!     - Enable with the `"shader"` feature.
+//!   - **SIMD batch sampling.**
+//!     - Samples several times of a segment of `f32` splines at once with
+//!       [wide](https://crates.io/crates/wide). See the `simd` module.
+//!     - Enable with the `"wide"` feature.
+//!   - **Sampling statistics.**
+//!     - Counts, per thread, the samples taken per interpolation mode, the cursor cache hits and
+//!       misses and the out-of-range queries. See the [`stats`] module.
+//!     - Requires the standard library.
+//!     - Enable with the `"stats"` feature.
 //!   - **Standard library / no standard library.**
 //!     - It’s possible to compile against the standard library or go on your own without it.
 //!     - Compiling with the standard library is enabled by default.
@@ -104,6 +179,7 @@
 //!     - Enable explicitly with the `"std"` feature.
 //!
 //! [`Interpolation`]: crate::interpolation::Interpolation
+//! [`Cursor`]: crate::cursor::Cursor
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(feature = "std"), feature(alloc))]
@@ -123,19 +199,82 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+pub mod arc_length;
+pub mod array;
+pub mod baked;
+pub mod builder;
 #[cfg(any(feature = "impl-cgmath", feature = "cgmath"))]
 mod cgmath;
+pub mod cluster;
+pub mod compress;
+pub mod continuity;
+#[cfg(any(feature = "flo_curves", feature = "keyframe", feature = "kurbo"))]
+pub mod convert;
+pub mod cursor;
+pub mod deadzone;
+pub mod edit;
+#[cfg(feature = "serde_json")]
+pub mod extra;
+pub mod extrapolation;
+#[cfg(feature = "std")]
+pub mod extrema;
+pub mod fit;
+pub mod fixed;
+mod flatten;
+#[cfg(feature = "flo_curves")]
+mod flo_curves;
+pub mod frame;
 #[cfg(any(feature = "impl-glam", feature = "glam"))]
 mod glam;
 pub mod interpolate;
 pub mod interpolation;
+#[cfg(feature = "std")]
+pub mod intersect;
 pub mod iter;
 pub mod key;
+#[cfg(feature = "keyframe")]
+mod keyframe;
+#[cfg(feature = "kurbo")]
+mod kurbo;
+pub mod lookup;
+mod macros;
 #[cfg(any(feature = "impl-nalgebra", feature = "nalgebra"))]
 mod nalgebra;
+#[cfg(feature = "noise")]
+pub mod noise;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+pub mod periodic;
+#[cfg(feature = "bytemuck")]
+pub mod pod;
+pub mod prelude;
+#[cfg(feature = "std")]
+pub mod presets;
+pub mod quantize;
+pub mod raw;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(any(feature = "serialization", feature = "serde"))]
+pub mod schema;
+mod segment;
+#[cfg(feature = "shader")]
+pub mod shader;
+#[cfg(feature = "arc-swap")]
+pub mod shared;
+#[cfg(feature = "wide")]
+pub mod simd;
+pub mod smooth;
+#[cfg(feature = "std")]
+mod solve;
+pub mod sorted;
 pub mod spline;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod stream;
+pub mod track;
+pub mod uniform;
 
 pub use crate::interpolate::Interpolate;
 pub use crate::interpolation::Interpolation;
-pub use crate::key::Key;
+pub use crate::key::{Key, TangentConstraint};
 pub use crate::spline::Spline;
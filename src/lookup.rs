@@ -0,0 +1,151 @@
+//! Lookup tables accelerating segment searches.
+//!
+//! Sampling a [`Spline`] searches for the segment to sample with a binary search, which is
+//! *O(log n)* with `n` the number of keys. A [`LookupTable`] splits the sampling values spanned by
+//! the keys into regularly sized buckets, and remembers the keys each bucket starts and ends with.
+//! Searching a segment then only requires a binary search among the keys of a single bucket, which
+//! is *O(1)* when keys are reasonably well distributed.
+//!
+//! For splines whose keys are exactly regularly spaced, see [`UniformSpline`] instead.
+//!
+//! [`UniformSpline`]: crate::uniform::UniformSpline
+
+use crate::interpolate::{Interpolate, Scalar};
+use crate::spline::{sample_segment, search_lower_cp, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A sampler of a [`Spline`] accelerating segment searches with buckets.
+///
+/// The table is built once and must be built again if the keys of the spline change – which the
+/// borrow of the spline enforces.
+#[derive(Clone, Debug)]
+pub struct LookupTable<'a, T, V> {
+  spline: &'a Spline<T, V>,
+  start: T,
+  width: T,
+  // index of the last key at or before the start of every bucket, and after the last bucket
+  buckets: Vec<usize>,
+}
+
+impl<'a, T, V> LookupTable<'a, T, V> {
+  /// Build a lookup table with as many buckets as keys.
+  pub fn new(spline: &'a Spline<T, V>) -> Self
+  where
+    T: Scalar,
+  {
+    Self::with_buckets(spline, spline.len())
+  }
+
+  /// Build a lookup table with a given number of buckets.
+  ///
+  /// More buckets mean fewer keys per bucket, and then faster searches, at the cost of memory.
+  pub fn with_buckets(spline: &'a Spline<T, V>, bucket_count: usize) -> Self
+  where
+    T: Scalar,
+  {
+    let keys = spline.keys();
+    let (start, end) = match (keys.first(), keys.last()) {
+      (Some(first), Some(last)) => (first.t, last.t),
+      _ => (T::zero(), T::zero()),
+    };
+    let bucket_count = bucket_count.max(1);
+    let width = (end - start) / T::from_f64(bucket_count as f64);
+    let buckets = (0..=bucket_count)
+      .map(|b| {
+        let edge = start + width * T::from_f64(b as f64);
+        keys.partition_point(|key| key.t <= edge).saturating_sub(1)
+      })
+      .collect();
+
+    LookupTable {
+      spline,
+      start,
+      width,
+      buckets,
+    }
+  }
+
+  /// Spline being sampled.
+  pub fn spline(&self) -> &'a Spline<T, V> {
+    self.spline
+  }
+
+  /// Index of the key starting the segment containing `t`.
+  ///
+  /// # Return
+  ///
+  /// `None` if `t` is out of the keys, which includes the sampling value of the last key. A spline
+  /// made of a single key yields that key at its exact sampling value.
+  pub fn search(&self, t: T) -> Option<usize>
+  where
+    T: Scalar,
+  {
+    let keys = self.spline.keys();
+
+    if keys.len() < 2 || self.width <= T::zero() {
+      return search_lower_cp(keys, t);
+    }
+
+    if t < self.start || t >= keys[keys.len() - 1].t {
+      return None;
+    }
+
+    let last_bucket = self.buckets.len() - 2;
+    let b = ((t - self.start) / self.width).floor().to_f64() as usize;
+    let b = b.min(last_bucket);
+    let (lo, hi) = (self.buckets[b], self.buckets[b + 1]);
+    let next = keys[lo..=hi].partition_point(|key| key.t <= t);
+
+    Some(lo + next - 1)
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self
+      .search(t)
+      .and_then(|i| {
+        let keys = self.spline.keys();
+
+        if i + 1 < keys.len() {
+          sample_segment(keys, i, t)
+        } else {
+          Some(keys[i].value.clone())
+        }
+      })
+      .or_else(|| self.spline.fallback().cloned())
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    let keys = self.spline.keys();
+
+    match (keys.first(), keys.last()) {
+      (Some(first), _) if t <= first.t => Some(first.value.clone()),
+      (_, Some(last)) if t >= last.t => Some(last.value.clone()),
+      _ => self.sample(t),
+    }
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Build a [`LookupTable`] of the spline.
+  pub fn lookup_table(&self) -> LookupTable<'_, T, V>
+  where
+    T: Scalar,
+  {
+    LookupTable::new(self)
+  }
+}
@@ -0,0 +1,78 @@
+//! Macros building splines.
+
+/// Create a [`Spline`](crate::spline::Spline) out of a list of keys.
+///
+/// Every key is written `t => value`, optionally followed by a semicolon and its interpolation
+/// mode: `linear`, `cosine`, `catmull_rom`, `step(threshold)`, `bezier(handle)` or
+/// `stroke_bezier(input, output)`. Keys without interpolation mode use
+/// [`Interpolation::default`](crate::interpolation::Interpolation::default). The keys don’t have
+/// to be sorted.
+///
+/// ```
+/// use splines::{spline, Interpolation, Key, Spline};
+///
+/// let spline = spline![
+///   0. => 0.; linear,
+///   1. => 10.; step(0.5),
+///   2. => -5.,
+/// ];
+///
+/// assert_eq!(
+///   spline,
+///   Spline::from_vec(vec![
+///     Key::new(0., 0., Interpolation::Linear),
+///     Key::new(1., 10., Interpolation::Step(0.5)),
+///     Key::new(2., -5., Interpolation::default()),
+///   ])
+/// );
+/// ```
+#[macro_export]
+macro_rules! spline {
+  ($($t:expr => $value:expr $(; $mode:ident $(($($arg:expr),*))?)?),* $(,)?) => {
+    $crate::spline::Spline::from_vec(
+      [
+        $(
+          $crate::key::Key::new(
+            $t,
+            $value,
+            $crate::__spline_interpolation!($($mode $(($($arg),*))?)?),
+          ),
+        )*
+      ]
+      .into(),
+    )
+  };
+}
+
+// Interpolation mode of a key of spline!.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __spline_interpolation {
+  () => {
+    $crate::interpolation::Interpolation::default()
+  };
+
+  (linear) => {
+    $crate::interpolation::Interpolation::Linear
+  };
+
+  (cosine) => {
+    $crate::interpolation::Interpolation::Cosine
+  };
+
+  (catmull_rom) => {
+    $crate::interpolation::Interpolation::CatmullRom
+  };
+
+  (step($threshold:expr)) => {
+    $crate::interpolation::Interpolation::Step($threshold)
+  };
+
+  (bezier($handle:expr)) => {
+    $crate::interpolation::Interpolation::Bezier($handle)
+  };
+
+  (stroke_bezier($input:expr, $output:expr)) => {
+    $crate::interpolation::Interpolation::StrokeBezier($input, $output)
+  };
+}
@@ -1,4 +1,4 @@
-use crate::impl_Interpolate;
+use crate::{impl_InnerSpace, impl_Interpolate, impl_Linear, impl_Planar, impl_Spatial};
 use nalgebra::{Quaternion, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6};
 
 impl_Interpolate!(f32, Vector1<f32>, std::f32::consts::PI);
@@ -16,3 +16,65 @@ impl_Interpolate!(f64, Vector4<f64>, std::f64::consts::PI);
 impl_Interpolate!(f64, Vector5<f64>, std::f64::consts::PI);
 impl_Interpolate!(f64, Vector6<f64>, std::f64::consts::PI);
 impl_Interpolate!(f64, Quaternion<f64>, std::f64::consts::PI);
+
+impl_Linear!(f32, Vector1<f32>);
+impl_Linear!(f32, Vector2<f32>);
+impl_Linear!(f32, Vector3<f32>);
+impl_Linear!(f32, Vector4<f32>);
+impl_Linear!(f32, Vector5<f32>);
+impl_Linear!(f32, Vector6<f32>);
+impl_Linear!(f32, Quaternion<f32>);
+
+impl_Linear!(f64, Vector1<f64>);
+impl_Linear!(f64, Vector2<f64>);
+impl_Linear!(f64, Vector3<f64>);
+impl_Linear!(f64, Vector4<f64>);
+impl_Linear!(f64, Vector5<f64>);
+impl_Linear!(f64, Vector6<f64>);
+impl_Linear!(f64, Quaternion<f64>);
+
+impl_InnerSpace!(f32, Vector1<f32>, |a, b| Vector1::dot(&a, &b));
+impl_InnerSpace!(f32, Vector2<f32>, |a, b| Vector2::dot(&a, &b));
+impl_InnerSpace!(f32, Vector3<f32>, |a, b| Vector3::dot(&a, &b));
+impl_InnerSpace!(f32, Vector4<f32>, |a, b| Vector4::dot(&a, &b));
+impl_InnerSpace!(f32, Vector5<f32>, |a, b| Vector5::dot(&a, &b));
+impl_InnerSpace!(f32, Vector6<f32>, |a, b| Vector6::dot(&a, &b));
+impl_InnerSpace!(f32, Quaternion<f32>, |a, b| Quaternion::dot(&a, &b));
+
+impl_InnerSpace!(f64, Vector1<f64>, |a, b| Vector1::dot(&a, &b));
+impl_InnerSpace!(f64, Vector2<f64>, |a, b| Vector2::dot(&a, &b));
+impl_InnerSpace!(f64, Vector3<f64>, |a, b| Vector3::dot(&a, &b));
+impl_InnerSpace!(f64, Vector4<f64>, |a, b| Vector4::dot(&a, &b));
+impl_InnerSpace!(f64, Vector5<f64>, |a, b| Vector5::dot(&a, &b));
+impl_InnerSpace!(f64, Vector6<f64>, |a, b| Vector6::dot(&a, &b));
+impl_InnerSpace!(f64, Quaternion<f64>, |a, b| Quaternion::dot(&a, &b));
+
+impl_Planar!(f32, Vector2<f32>);
+impl_Planar!(f64, Vector2<f64>);
+
+impl_Spatial!(f32, Vector3<f32>, |a, b| Vector3::cross(&a, &b));
+impl_Spatial!(f64, Vector3<f64>, |a, b| Vector3::cross(&a, &b));
+
+#[cfg(any(feature = "serialization", feature = "serde"))]
+mod schema {
+  use crate::schema::{impl_Schema, number_array_schema};
+  use nalgebra::{Quaternion, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6};
+
+  // vectors are serialized as arrays of their components, and quaternions as arrays of their
+  // coordinates (i, j, k, w)
+  impl_Schema!(Vector1<f32>, number_array_schema(1));
+  impl_Schema!(Vector2<f32>, number_array_schema(2));
+  impl_Schema!(Vector3<f32>, number_array_schema(3));
+  impl_Schema!(Vector4<f32>, number_array_schema(4));
+  impl_Schema!(Vector5<f32>, number_array_schema(5));
+  impl_Schema!(Vector6<f32>, number_array_schema(6));
+  impl_Schema!(Quaternion<f32>, number_array_schema(4));
+
+  impl_Schema!(Vector1<f64>, number_array_schema(1));
+  impl_Schema!(Vector2<f64>, number_array_schema(2));
+  impl_Schema!(Vector3<f64>, number_array_schema(3));
+  impl_Schema!(Vector4<f64>, number_array_schema(4));
+  impl_Schema!(Vector5<f64>, number_array_schema(5));
+  impl_Schema!(Vector6<f64>, number_array_schema(6));
+  impl_Schema!(Quaternion<f64>, number_array_schema(4));
+}
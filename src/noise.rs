@@ -0,0 +1,113 @@
+//! Noise modulation of splines.
+//!
+//! Wind, idle motions and other organic animations need variation that doesn’t repeat, while
+//! keeping the overall shape that was authored. A [`Noisy`] sampler adds smooth, band-limited
+//! value noise on top of a spline: the spline gives the macro shape and the noise the details.
+
+use crate::interpolate::{Interpolate, Linear, Scalar};
+use crate::spline::Spline;
+
+/// A sampler adding value noise to the values sampled from a [`Spline`].
+///
+/// The noise is a scalar in `[-1; 1]`, varying smoothly with the sampling value and scaled by the
+/// amplitude: it is made of random values picked at regularly spaced lattice points – `frequency`
+/// of them per unit of sampling value – which are interpolated with a smooth step. It’s fully
+/// determined by its seed, so that sampling twice at the same time yields the same value.
+#[derive(Clone, Copy, Debug)]
+pub struct Noisy<'a, T, V> {
+  spline: &'a Spline<T, V>,
+  seed: u64,
+  frequency: T,
+  amplitude: V,
+}
+
+impl<'a, T, V> Noisy<'a, T, V> {
+  /// Modulate a spline with noise.
+  pub fn new(spline: &'a Spline<T, V>, seed: u64, frequency: T, amplitude: V) -> Self {
+    Noisy {
+      spline,
+      seed,
+      frequency,
+      amplitude,
+    }
+  }
+
+  /// Spline being modulated.
+  pub fn spline(&self) -> &'a Spline<T, V> {
+    self.spline
+  }
+
+  /// Noise at a given time, in `[-1; 1]`.
+  pub fn noise(&self, t: T) -> T
+  where
+    T: Scalar,
+  {
+    let x = t * self.frequency;
+    let lattice = x.floor();
+    let f = x - lattice;
+    let i = lattice.to_f64() as i64;
+    let n0 = T::from_f64(lattice_value(self.seed, i));
+    let n1 = T::from_f64(lattice_value(self.seed, i.wrapping_add(1)));
+
+    // quintic smooth step, continuous up to the second derivative
+    let s = f * f * f * (f * (f * T::from_f64(6.) - T::from_f64(15.)) + T::from_f64(10.));
+
+    n0 + (n1 - n0) * s
+  }
+
+  /// Sample the spline at a given time and add noise.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + Linear<T>,
+  {
+    self.spline.sample(t).map(|value| self.modulate(t, value))
+  }
+
+  /// Sample the spline at a given time with clamping and add noise.
+  ///
+  /// The noise keeps varying outside of the keys. See [`Spline::clamped_sample`] for further
+  /// details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + Linear<T>,
+  {
+    self
+      .spline
+      .clamped_sample(t)
+      .map(|value| self.modulate(t, value))
+  }
+
+  fn modulate(&self, t: T, value: V) -> V
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    value + self.amplitude.outer_mul(self.noise(t))
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Create a [`Noisy`] sampler adding value noise to the spline.
+  ///
+  /// `frequency` is the number of random lattice values per unit of sampling value – the higher,
+  /// the faster the noise varies – and `amplitude` scales the noise, which lies in `[-1; 1]`.
+  pub fn modulate_with_noise(&self, seed: u64, frequency: T, amplitude: V) -> Noisy<'_, T, V> {
+    Noisy::new(self, seed, frequency, amplitude)
+  }
+}
+
+/// Random value in `[-1; 1]` associated with the lattice point `i`.
+fn lattice_value(seed: u64, i: i64) -> f64 {
+  // SplitMix64 finalizer
+  let mut z = seed ^ (i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+  z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+  z ^= z >> 31;
+
+  // keep the 53 upper bits, which fit exactly in a f64
+  (z >> 11) as f64 / (1u64 << 52) as f64 - 1.
+}
@@ -0,0 +1,162 @@
+//! Blanket implementations for any [`Real`] type.
+//!
+//! Those implementations replace the ones generated for `f32` and `f64` when the
+//! `"num-traits"` feature is enabled, and conflict with any implementation for another `Real`
+//! type.
+
+use crate::interpolate::{InnerSpace, Interpolate, Interpolator, Linear, Scalar};
+use num_traits::real::Real;
+
+// Convert a constant to a real type; this can only fail for types that cannot represent any
+// floating-point number, which are not real numbers to begin with.
+fn constant<T>(x: f64) -> T
+where
+  T: Real,
+{
+  T::from(x).expect("real type cannot represent a floating-point constant")
+}
+
+impl<T> Interpolator for T
+where
+  T: Real,
+{
+  fn normalize(self, start: Self, end: Self) -> Self {
+    (self - start) / (end - start)
+  }
+}
+
+impl<T> Scalar for T
+where
+  T: Real,
+{
+  fn zero() -> Self {
+    T::zero()
+  }
+
+  fn one() -> Self {
+    T::one()
+  }
+
+  fn pi() -> Self {
+    constant(core::f64::consts::PI)
+  }
+
+  fn from_f64(x: f64) -> Self {
+    constant(x)
+  }
+
+  fn to_f64(self) -> f64 {
+    num_traits::ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
+  }
+
+  fn abs(self) -> Self {
+    Real::abs(self)
+  }
+
+  fn floor(self) -> Self {
+    Real::floor(self)
+  }
+
+  fn sqrt(self) -> Self {
+    Real::sqrt(self)
+  }
+
+  fn cos(self) -> Self {
+    Real::cos(self)
+  }
+
+  fn sin(self) -> Self {
+    Real::sin(self)
+  }
+
+  fn exp(self) -> Self {
+    Real::exp(self)
+  }
+}
+
+impl<T> Interpolate<T> for T
+where
+  T: Real,
+{
+  fn step(t: T, threshold: T, a: Self, b: Self) -> Self {
+    if t < threshold {
+      a
+    } else {
+      b
+    }
+  }
+
+  fn lerp(t: T, a: Self, b: Self) -> Self {
+    a * (T::one() - t) + b * t
+  }
+
+  fn cosine(t: T, a: Self, b: Self) -> Self {
+    let cos_nt = (T::one() - (t * <T as Scalar>::pi()).cos()) * constant(0.5);
+    <Self as Interpolate<T>>::lerp(cos_nt, a, b)
+  }
+
+  fn cubic_hermite(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self {
+    // sampler stuff
+    let two: T = constant(2.);
+    let three: T = constant(3.);
+    let one = T::one();
+    let two_t = t * two;
+    let three_t = t * three;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let two_t3 = t2 * two_t;
+    let two_t2 = t * two_t;
+    let three_t2 = t * three_t;
+
+    // tangents
+    let m0 = (b.1 - x.1) / (b.0 - x.0) * (b.0 - a.0);
+    let m1 = (y.1 - a.1) / (y.0 - a.0) * (b.0 - a.0);
+
+    a.1 * (two_t3 - three_t2 + one)
+      + m0 * (t3 - two_t2 + t)
+      + b.1 * (three_t2 - two_t3)
+      + m1 * (t3 - t2)
+  }
+
+  fn quadratic_bezier(t: T, a: Self, u: Self, b: Self) -> Self {
+    let one_t = T::one() - t;
+    let one_t2 = one_t * one_t;
+
+    u + (a - u) * one_t2 + (b - u) * t * t
+  }
+
+  fn cubic_bezier(t: T, a: Self, u: Self, v: Self, b: Self) -> Self {
+    let one_t = T::one() - t;
+    let one_t2 = one_t * one_t;
+    let one_t3 = one_t2 * one_t;
+    let t2 = t * t;
+
+    a * one_t3 + (u * one_t2 * t + v * one_t * t2) * constant(3.) + b * t2 * t
+  }
+
+  fn cubic_bezier_mirrored(t: T, a: Self, u: Self, v: Self, b: Self) -> Self {
+    <Self as Interpolate<T>>::cubic_bezier(t, a, u, b + b - v, b)
+  }
+}
+
+impl<T> Linear<T> for T
+where
+  T: Real,
+{
+  fn outer_mul(self, t: T) -> Self {
+    self * t
+  }
+
+  fn outer_div(self, t: T) -> Self {
+    self / t
+  }
+}
+
+impl<T> InnerSpace<T> for T
+where
+  T: Real,
+{
+  fn dot(self, rhs: Self) -> T {
+    self * rhs
+  }
+}
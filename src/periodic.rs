@@ -0,0 +1,144 @@
+//! Closed (periodic) splines.
+//!
+//! A [`PeriodicSpline`] loops: its last key connects back to its first one, and sampling values
+//! wrap around its period. Interpolation modes that look at neighboring keys – such as
+//! [`Interpolation::CatmullRom`] or the Bézier ones – use the keys on the other side of the seam,
+//! so that looping paths don’t kink where they close.
+//!
+//! [`Interpolation::CatmullRom`]: crate::interpolation::Interpolation::CatmullRom
+
+use crate::interpolate::{Interpolate, Scalar};
+use crate::key::Key;
+use crate::spline::{sample_segment, search_lower_cp, SampledWithKey, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+/// A spline looping over a period.
+///
+/// The spline is defined over `[t0; t0 + period[`, where `t0` is the sampling value of its first
+/// key. The last segment – the _seam_ – goes from the last key to the first one, which is reached
+/// again at `t0 + period`, using the interpolation mode of the last key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeriodicSpline<T, V> {
+  // keys of the spline, with the last key repeated one period before and the first two keys
+  // repeated one period after, so that the neighbors of every segment are available
+  keys: Vec<Key<T, V>>,
+  period: T,
+}
+
+impl<T, V> PeriodicSpline<T, V> {
+  /// Close a spline over a period.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has no key or if `period` is not greater than the duration of the spline
+  /// (there would be no room left for the seam).
+  pub fn new(spline: Spline<T, V>, period: T) -> Option<Self>
+  where
+    T: Scalar,
+    V: Clone,
+  {
+    let keys = spline.keys();
+    let first = keys.first()?;
+    let last = keys.last()?;
+
+    if period.partial_cmp(&(last.t - first.t)) != Some(Ordering::Greater) {
+      return None;
+    }
+
+    let n = keys.len();
+    let shifted = |key: &Key<T, V>, periods: usize| Key {
+      t: key.t + period * T::from_f64(periods as f64),
+      ..key.clone()
+    };
+    let mut unrolled = Vec::with_capacity(n + 3);
+
+    unrolled.push(Key {
+      t: last.t - period,
+      ..last.clone()
+    });
+    unrolled.extend_from_slice(keys);
+    unrolled.extend((0..2).map(|j| shifted(&keys[j % n], 1 + j / n)));
+
+    Some(PeriodicSpline {
+      keys: unrolled,
+      period,
+    })
+  }
+
+  /// Period of the spline.
+  pub fn period(&self) -> T
+  where
+    T: Copy,
+  {
+    self.period
+  }
+
+  /// Keys of the spline, excluding the repetitions used for the seam.
+  pub fn keys(&self) -> &[Key<T, V>] {
+    &self.keys[1..self.keys.len() - 2]
+  }
+
+  /// Number of keys of the spline.
+  pub fn len(&self) -> usize {
+    self.keys.len() - 3
+  }
+
+  /// Whether the spline has no key; always `false`, since a periodic spline has at least one key.
+  pub fn is_empty(&self) -> bool {
+    false
+  }
+
+  /// Sample the spline at a given time, wrapped around the period.
+  ///
+  /// Contrary to [`Spline::sample`], any sampling value can be used.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self.sample_with_key(t).map(|sampled| sampled.value)
+  }
+
+  /// Sample the spline at a given time, wrapped around the period, returning the interpolated value
+  /// along with its associated key.
+  ///
+  /// The key is the lower key of the segment that was sampled; on the seam, this is the last key.
+  pub fn sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    let t = self.wrap(t);
+    let i = search_lower_cp(&self.keys, t)?;
+    let value = sample_segment(&self.keys, i, t)?;
+    let n = self.len();
+
+    Some(SampledWithKey {
+      value,
+      key: (i + n - 1) % n,
+    })
+  }
+
+  /// Wrap a sampling value into `[t0; t0 + period[`.
+  pub fn wrap(&self, t: T) -> T
+  where
+    T: Scalar,
+  {
+    let t0 = self.keys[1].t;
+    let dt = t - t0;
+
+    let wrapped = t0 + dt - self.period * (dt / self.period).floor();
+
+    // values just below the seam can round up to the end of the period
+    if wrapped >= t0 + self.period {
+      t0
+    } else {
+      wrapped
+    }
+  }
+}
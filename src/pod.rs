@@ -0,0 +1,206 @@
+//! Flattened splines made of plain old data.
+//!
+//! A [`PodSpline`] is a snapshot of a spline as separate arrays of [`bytemuck::Pod`] values,
+//! which can be cast to bytes with [`bytemuck::cast_slice`] and uploaded to GPU buffers or written
+//! to memory-mapped files as is. For the key at index `i`:
+//!
+//!   - `ts[i]` is its sampling value and `values[i]` its carried value.
+//!   - `modes[i]` holds the tags of its interpolation mode, of its incoming interpolation mode and of
+//!     its tangent constraint; see the constants of this module.
+//!   - `thresholds[i]` holds the thresholds of its interpolation mode and of its incoming
+//!     interpolation mode, when they are [`Interpolation::Step`], and zero otherwise.
+//!   - `tangents[i]` holds the handles of its interpolation mode, then the handles of its incoming
+//!     interpolation mode, when they are Bézier ones. The single handle of
+//!     [`Interpolation::Bezier`] comes first, and unused handles are zeroed.
+
+use crate::interpolation::Interpolation;
+use crate::key::{Key, TangentConstraint};
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use bytemuck::{Pod, Zeroable};
+
+/// Tag of [`Interpolation::Step`].
+pub const STEP: u32 = 0;
+/// Tag of [`Interpolation::Linear`].
+pub const LINEAR: u32 = 1;
+/// Tag of [`Interpolation::Cosine`].
+pub const COSINE: u32 = 2;
+/// Tag of [`Interpolation::CatmullRom`].
+pub const CATMULL_ROM: u32 = 3;
+/// Tag of [`Interpolation::Bezier`].
+pub const BEZIER: u32 = 4;
+/// Tag of [`Interpolation::StrokeBezier`].
+pub const STROKE_BEZIER: u32 = 5;
+/// Tag of a missing incoming interpolation mode.
+pub const NONE: u32 = u32::MAX;
+
+/// Tags of the interpolation modes and tangent constraint of a key.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Pod, Zeroable)]
+pub struct PodModes {
+  /// Tag of the interpolation mode.
+  pub interpolation: u32,
+  /// Tag of the incoming interpolation mode, or [`NONE`].
+  pub in_interpolation: u32,
+  /// Tangent constraint: `0` for [`TangentConstraint::Free`], `1` for
+  /// [`TangentConstraint::Aligned`] and `2` for [`TangentConstraint::Mirrored`].
+  pub tangent_constraint: u32,
+}
+
+/// A spline flattened into arrays of plain old data.
+///
+/// See the [`pod`](crate::pod) module for the layout of the arrays.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodSpline<T, V> {
+  /// Sampling values of the keys.
+  pub ts: Vec<T>,
+  /// Carried values of the keys.
+  pub values: Vec<V>,
+  /// Tags of the interpolation modes and tangent constraints of the keys.
+  pub modes: Vec<PodModes>,
+  /// Step thresholds of the interpolation modes and incoming interpolation modes of the keys.
+  pub thresholds: Vec<[T; 2]>,
+  /// Bézier handles of the interpolation modes and incoming interpolation modes of the keys.
+  pub tangents: Vec<[V; 4]>,
+}
+
+// Flatten an interpolation mode into its tag, threshold and handles.
+fn flatten<T, V>(interpolation: &Interpolation<T, V>) -> (u32, T, [V; 2])
+where
+  T: Pod,
+  V: Pod,
+{
+  let (zero_t, zero_v) = (T::zeroed(), V::zeroed());
+
+  match *interpolation {
+    Interpolation::Step(threshold) => (STEP, threshold, [zero_v; 2]),
+    Interpolation::Linear => (LINEAR, zero_t, [zero_v; 2]),
+    Interpolation::Cosine => (COSINE, zero_t, [zero_v; 2]),
+    Interpolation::CatmullRom => (CATMULL_ROM, zero_t, [zero_v; 2]),
+    Interpolation::Bezier(u) => (BEZIER, zero_t, [u, zero_v]),
+    Interpolation::StrokeBezier(u, v) => (STROKE_BEZIER, zero_t, [u, v]),
+  }
+}
+
+// Rebuild an interpolation mode out of its tag, threshold and handles.
+fn unflatten<T, V>(tag: u32, threshold: T, [u, v]: [V; 2]) -> Option<Interpolation<T, V>> {
+  match tag {
+    STEP => Some(Interpolation::Step(threshold)),
+    LINEAR => Some(Interpolation::Linear),
+    COSINE => Some(Interpolation::Cosine),
+    CATMULL_ROM => Some(Interpolation::CatmullRom),
+    BEZIER => Some(Interpolation::Bezier(u)),
+    STROKE_BEZIER => Some(Interpolation::StrokeBezier(u, v)),
+    _ => None,
+  }
+}
+
+impl<T, V> PodSpline<T, V> {
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.ts.len()
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.ts.is_empty()
+  }
+
+  /// Rebuild the spline.
+  ///
+  /// # Return
+  ///
+  /// `None` if the arrays don’t have the same length or if a tag is unknown.
+  pub fn to_spline(&self) -> Option<Spline<T, V>>
+  where
+    T: Pod + PartialOrd,
+    V: Pod,
+  {
+    let len = self.ts.len();
+
+    if [
+      self.values.len(),
+      self.modes.len(),
+      self.thresholds.len(),
+      self.tangents.len(),
+    ]
+    .iter()
+    .any(|&l| l != len)
+    {
+      return None;
+    }
+
+    let keys = (0..len)
+      .map(|i| {
+        let modes = &self.modes[i];
+        let [threshold, in_threshold] = self.thresholds[i];
+        let [u, v, in_u, in_v] = self.tangents[i];
+        let in_interpolation = match modes.in_interpolation {
+          NONE => None,
+          tag => Some(unflatten(tag, in_threshold, [in_u, in_v])?),
+        };
+        let tangent_constraint = match modes.tangent_constraint {
+          0 => TangentConstraint::Free,
+          1 => TangentConstraint::Aligned,
+          2 => TangentConstraint::Mirrored,
+          _ => return None,
+        };
+
+        Some(Key {
+          t: self.ts[i],
+          value: self.values[i],
+          interpolation: unflatten(modes.interpolation, threshold, [u, v])?,
+          in_interpolation,
+          tangent_constraint,
+        })
+      })
+      .collect::<Option<_>>()?;
+
+    Some(Spline::from_vec(keys))
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Flatten the spline into arrays of plain old data.
+  ///
+  /// The fallback value and the tag of the spline are not kept.
+  pub fn to_pod(&self) -> PodSpline<T, V>
+  where
+    T: Pod,
+    V: Pod,
+  {
+    let mut pod = PodSpline {
+      ts: Vec::with_capacity(self.len()),
+      values: Vec::with_capacity(self.len()),
+      modes: Vec::with_capacity(self.len()),
+      thresholds: Vec::with_capacity(self.len()),
+      tangents: Vec::with_capacity(self.len()),
+    };
+
+    for key in self.keys() {
+      let (tag, threshold, [u, v]) = flatten(&key.interpolation);
+      let (in_tag, in_threshold, [in_u, in_v]) = match key.in_interpolation {
+        Some(ref interpolation) => flatten(interpolation),
+        None => (NONE, T::zeroed(), [V::zeroed(); 2]),
+      };
+      let tangent_constraint = match key.tangent_constraint {
+        TangentConstraint::Free => 0,
+        TangentConstraint::Aligned => 1,
+        TangentConstraint::Mirrored => 2,
+      };
+
+      pod.ts.push(key.t);
+      pod.values.push(key.value);
+      pod.modes.push(PodModes {
+        interpolation: tag,
+        in_interpolation: in_tag,
+        tangent_constraint,
+      });
+      pod.thresholds.push([threshold, in_threshold]);
+      pod.tangents.push([u, v, in_u, in_v]);
+    }
+
+    pod
+  }
+}
@@ -0,0 +1,26 @@
+//! Convenience re-exports.
+//!
+//! This module gathers the most commonly used symbols of the crate, so that you can import them
+//! all at once:
+//!
+//! ```
+//! use splines::prelude::*;
+//!
+//! let spline = Spline::from_vec(vec![
+//!   Key::new(0., 0., Interpolation::Linear),
+//!   Key::new(1., 10., Interpolation::default()),
+//! ]);
+//! let mut cursor = Cursor::new(&spline, 0.);
+//! cursor.advance(0.5);
+//!
+//! assert_eq!(cursor.sample(), Some(5.));
+//! ```
+
+pub use crate::builder::SplineBuilder;
+pub use crate::cursor::{Cursor, Direction, PlaybackEvent};
+pub use crate::interpolate::{
+  InnerSpace, Interpolate, Interpolator, Linear, Planar, Scalar, Spatial,
+};
+pub use crate::interpolation::Interpolation;
+pub use crate::key::{Key, TangentConstraint};
+pub use crate::spline::Spline;
@@ -0,0 +1,232 @@
+//! Ready-made curves.
+//!
+//! Gameplay code keeps reaching for the same handful of curves: ramps, decays, bells and
+//! low-frequency oscillators (LFOs). The functions of this module build keyed [`Spline`]s
+//! approximating them, so that they can be sampled, edited, played with a
+//! [`Cursor`](crate::cursor::Cursor) or serialized like any other spline.
+//!
+//! Smooth curves are approximated by cubic Bézier segments ([`Interpolation::StrokeBezier`])
+//! whose handles follow the exact derivative of the curve. Segments are subdivided until the
+//! absolute error, measured at regularly spaced points of every segment, is lower than the
+//! `tolerance` passed to the function. Piecewise linear curves are represented exactly.
+//!
+//! All the curves start at the sampling value `0`. A non-positive duration or period yields an empty spline.
+//!
+//! This module requires the standard library.
+
+use crate::interpolate::Scalar;
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::Spline;
+use std::f64::consts::PI;
+
+/// Maximum number of times a segment can be subdivided.
+const MAX_DEPTH: u32 = 16;
+
+/// Number of intervals each segment is divided into when measuring the approximation error.
+const ERROR_CHECKS: u32 = 8;
+
+/// Lowest steepness accepted by [`sigmoid`].
+const MIN_STEEPNESS: f64 = 1e-3;
+
+/// Logistic (sigmoid) ramp going from `from` to `to` over `duration`.
+///
+/// `steepness` is the rate of the logistic function over the normalized duration: `0` would be
+/// a linear ramp (lower values are clamped to a small positive one), while values around `10` give
+/// the typical S shape. The curve is rescaled so that it starts exactly at `from` and ends exactly
+/// at `to`.
+pub fn sigmoid<T>(duration: T, from: T, to: T, steepness: T, tolerance: T) -> Spline<T, T>
+where
+  T: Scalar,
+{
+  let duration = duration.to_f64();
+  let (from, to) = (from.to_f64(), to.to_f64());
+  let k = steepness.to_f64().max(MIN_STEEPNESS);
+  let logistic = |x: f64| 1. / (1. + (-k * (x - 0.5)).exp());
+  let (s0, s1) = (logistic(0.), logistic(1.));
+  let scale = (to - from) / (s1 - s0);
+
+  approximate(duration, tolerance, |t| {
+    let s = logistic(t / duration);
+    (from + (s - s0) * scale, k * s * (1. - s) * scale / duration)
+  })
+}
+
+/// Exponential decay going from `from` towards `to` over `duration`.
+///
+/// The distance to `to` is halved every `half_life`; the curve only reaches `to` asymptotically.
+pub fn exponential_decay<T>(duration: T, from: T, to: T, half_life: T, tolerance: T) -> Spline<T, T>
+where
+  T: Scalar,
+{
+  let (from, to) = (from.to_f64(), to.to_f64());
+  let rate = 2f64.ln() / half_life.to_f64();
+
+  approximate(duration.to_f64(), tolerance, |t| {
+    let d = (from - to) * (-rate * t).exp();
+    (to + d, -rate * d)
+  })
+}
+
+/// Gaussian bell curve of a given `height`, centered in the middle of `duration`.
+///
+/// `width` is the standard deviation of the bell. The curve goes back to `0` away from the center,
+/// but it’s not clamped to `0` at its ends.
+pub fn bell<T>(duration: T, height: T, width: T, tolerance: T) -> Spline<T, T>
+where
+  T: Scalar,
+{
+  let duration = duration.to_f64();
+  let height = height.to_f64();
+  let variance = width.to_f64() * width.to_f64();
+  let center = duration * 0.5;
+
+  approximate(duration, tolerance, |t| {
+    let x = t - center;
+    let v = height * (-x * x / (2. * variance)).exp();
+    (v, -x / variance * v)
+  })
+}
+
+/// Sawtooth LFO: a single period rising linearly from `low` to `high`.
+///
+/// The curve is exact. Loop over the period to get the wrap back to `low`.
+pub fn sawtooth<T>(period: T, low: T, high: T) -> Spline<T, T>
+where
+  T: Scalar,
+{
+  if period <= T::zero() {
+    return Spline::from_vec(Vec::new());
+  }
+
+  Spline::from_vec(vec![
+    Key::new(T::zero(), low, Interpolation::Linear),
+    Key::new(period, high, Interpolation::Linear),
+  ])
+}
+
+/// Triangle LFO: a single period rising linearly from `low` to `high` and back.
+///
+/// The curve is exact.
+pub fn triangle<T>(period: T, low: T, high: T) -> Spline<T, T>
+where
+  T: Scalar,
+{
+  if period <= T::zero() {
+    return Spline::from_vec(Vec::new());
+  }
+
+  Spline::from_vec(vec![
+    Key::new(T::zero(), low, Interpolation::Linear),
+    Key::new(period * T::from_f64(0.5), high, Interpolation::Linear),
+    Key::new(period, low, Interpolation::Linear),
+  ])
+}
+
+/// Sine LFO: a single period oscillating between `low` and `high`.
+///
+/// The curve starts halfway between `low` and `high`, rising.
+pub fn sine<T>(period: T, low: T, high: T, tolerance: T) -> Spline<T, T>
+where
+  T: Scalar,
+{
+  let period = period.to_f64();
+  let center = (low.to_f64() + high.to_f64()) * 0.5;
+  let amplitude = (high.to_f64() - low.to_f64()) * 0.5;
+  let omega = 2. * PI / period;
+
+  approximate(period, tolerance, |t| {
+    let x = omega * t;
+    (center + amplitude * x.sin(), amplitude * omega * x.cos())
+  })
+}
+
+/// Approximate a smooth curve over `[0; duration]`.
+///
+/// `f` returns the value and the derivative of the curve.
+fn approximate<T, F>(duration: f64, tolerance: T, f: F) -> Spline<T, T>
+where
+  T: Scalar,
+  F: Fn(f64) -> (f64, f64),
+{
+  if duration.is_nan() || duration <= 0. {
+    return Spline::from_vec(Vec::new());
+  }
+
+  let tolerance = tolerance.to_f64();
+  let start = (0., f(0.));
+  let end = (duration, f(duration));
+  let mut points = vec![start];
+
+  subdivide(&f, start, end, tolerance, MAX_DEPTH, &mut points);
+
+  let keys = points
+    .iter()
+    .enumerate()
+    .map(|(i, &(t, (value, slope)))| {
+      let before = i.checked_sub(1).map_or(0., |j| t - points[j].0);
+      let after = points.get(i + 1).map_or(0., |next| next.0 - t);
+      let input = value - slope * before / 3.;
+      let output = value + slope * after / 3.;
+
+      Key::new(
+        T::from_f64(t),
+        T::from_f64(value),
+        Interpolation::StrokeBezier(T::from_f64(input), T::from_f64(output)),
+      )
+    })
+    .collect();
+
+  Spline::from_vec(keys)
+}
+
+/// Split the segment between `start` and `end` until it approximates `f` well enough, pushing
+/// every point but `start` to `points`.
+fn subdivide<F>(
+  f: &F,
+  start: (f64, (f64, f64)),
+  end: (f64, (f64, f64)),
+  tolerance: f64,
+  depth: u32,
+  points: &mut Vec<(f64, (f64, f64))>,
+) where
+  F: Fn(f64) -> (f64, f64),
+{
+  if depth == 0 || segment_error(f, start, end) <= tolerance {
+    points.push(end);
+    return;
+  }
+
+  let t = (start.0 + end.0) * 0.5;
+  let middle = (t, f(t));
+
+  subdivide(f, start, middle, tolerance, depth - 1, points);
+  subdivide(f, middle, end, tolerance, depth - 1, points);
+}
+
+/// Maximum distance between `f` and the Hermite segment interpolating it between two points.
+fn segment_error<F>(
+  f: &F,
+  (t0, (a, m0)): (f64, (f64, f64)),
+  (t1, (b, m1)): (f64, (f64, f64)),
+) -> f64
+where
+  F: Fn(f64) -> (f64, f64),
+{
+  let dt = t1 - t0;
+  let (m0, m1) = (m0 * dt, m1 * dt);
+
+  (1..ERROR_CHECKS)
+    .map(|i| {
+      let nt = f64::from(i) / f64::from(ERROR_CHECKS);
+      let nt2 = nt * nt;
+      let nt3 = nt2 * nt;
+      let approximation = (2. * nt3 - 3. * nt2 + 1.) * a
+        + (nt3 - 2. * nt2 + nt) * m0
+        + (-2. * nt3 + 3. * nt2) * b
+        + (nt3 - nt2) * m1;
+
+      (f(t0 + dt * nt).0 - approximation).abs()
+    })
+    .fold(0., f64::max)
+}
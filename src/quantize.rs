@@ -0,0 +1,145 @@
+//! Quantization of sampled values.
+//!
+//! Some outputs only accept discrete values: retro-style stepped animations, drivers of hardware
+//! accepting a fixed set of setpoints, etc. A [`Quantization`] snaps values to such a set, and a
+//! [`Quantized`] sampler applies it to every value sampled from a spline.
+
+use crate::interpolate::{InnerSpace, Interpolate, Scalar};
+use crate::spline::Spline;
+
+/// Rule snapping values to a discrete set of values.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Quantization<'a, V> {
+  /// Snap to `count` regularly spaced levels between `low` and `high`, both included.
+  ///
+  /// Values are projected on the line going from `low` to `high` and snapped to the nearest level;
+  /// values beyond `low` or `high` snap to them. A single level always yields `low`.
+  Levels {
+    /// First level.
+    low: V,
+    /// Last level.
+    high: V,
+    /// Number of levels.
+    count: usize,
+  },
+
+  /// Snap to the nearest value of a palette.
+  ///
+  /// If several values of the palette are the nearest, the first one is used.
+  Palette(&'a [V]),
+}
+
+impl<'a, V> Quantization<'a, V> {
+  /// Snap a value.
+  ///
+  /// # Return
+  ///
+  /// `None` if there is no value to snap to – i.e. there are no levels or the palette is empty.
+  pub fn apply<T>(&self, value: V) -> Option<V>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    match *self {
+      Quantization::Levels { low, high, count } => {
+        if count <= 1 {
+          return if count == 1 { Some(low) } else { None };
+        }
+
+        let range = high - low;
+        let length = range.dot(range);
+
+        if length == T::zero() {
+          return Some(low);
+        }
+
+        let steps = T::from_f64((count - 1) as f64);
+        let projected = (value - low).dot(range) / length * steps;
+        let level = if projected < T::zero() {
+          T::zero()
+        } else if projected > steps {
+          steps
+        } else {
+          (projected + T::from_f64(0.5)).floor()
+        };
+
+        Some(low + range.outer_mul(level / steps))
+      }
+
+      Quantization::Palette(palette) => palette
+        .iter()
+        .map(|&candidate| (candidate, (candidate - value).norm()))
+        .fold(
+          None,
+          |nearest: Option<(V, T)>, (candidate, d)| match nearest {
+            Some((_, nearest_d)) if nearest_d <= d => nearest,
+            _ => Some((candidate, d)),
+          },
+        )
+        .map(|(candidate, _)| candidate),
+    }
+  }
+}
+
+/// A sampler quantizing the values sampled from a [`Spline`].
+#[derive(Clone, Copy, Debug)]
+pub struct Quantized<'a, T, V> {
+  spline: &'a Spline<T, V>,
+  quantization: Quantization<'a, V>,
+}
+
+impl<'a, T, V> Quantized<'a, T, V> {
+  /// Quantize the values sampled from a spline.
+  pub fn new(spline: &'a Spline<T, V>, quantization: Quantization<'a, V>) -> Self {
+    Quantized {
+      spline,
+      quantization,
+    }
+  }
+
+  /// Spline being sampled.
+  pub fn spline(&self) -> &'a Spline<T, V> {
+    self.spline
+  }
+
+  /// Quantization applied to sampled values.
+  pub fn quantization(&self) -> &Quantization<'a, V> {
+    &self.quantization
+  }
+
+  /// Sample the spline at a given time and quantize the value.
+  ///
+  /// See [`Spline::sample`] and [`Quantization::apply`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    self
+      .spline
+      .sample(t)
+      .and_then(|value| self.quantization.apply(value))
+  }
+
+  /// Sample the spline at a given time with clamping and quantize the value.
+  ///
+  /// See [`Spline::clamped_sample`] and [`Quantization::apply`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    self
+      .spline
+      .clamped_sample(t)
+      .and_then(|value| self.quantization.apply(value))
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Create a [`Quantized`] sampler of the spline.
+  pub fn quantized<'a>(&'a self, quantization: Quantization<'a, V>) -> Quantized<'a, T, V> {
+    Quantized::new(self, quantization)
+  }
+}
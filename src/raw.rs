@@ -0,0 +1,111 @@
+//! Standalone segment evaluation.
+//!
+//! The functions of this module evaluate a single segment from plain values and tangents, without
+//! any [`Spline`] or [`Key`]. They let other curve containers reuse the formulas of this crate
+//! while keeping their own storage.
+//!
+//! All functions take a _normalized_ time, going from `0` at the start of the segment to `1` at its
+//! end; see [`Interpolator::normalize`] to compute it from a sampling value.
+//!
+//! ```
+//! use splines::raw::{eval_cubic_bezier, eval_linear};
+//!
+//! assert_eq!(eval_linear(0.25, 0., 10.), 2.5);
+//! assert_eq!(eval_cubic_bezier(0.5, 0., 0., 10., 10.), 5.);
+//! ```
+//!
+//! [`Spline`]: crate::spline::Spline
+//! [`Key`]: crate::key::Key
+
+use crate::interpolate::{Interpolate, Interpolator, Linear, Scalar};
+use crate::segment::Shape;
+
+/// Step segment: `a` until `threshold`, `b` afterwards.
+///
+/// This is the formula of [`Interpolation::Step`](crate::interpolation::Interpolation::Step).
+#[inline]
+pub fn eval_step<T, V>(nt: T, threshold: T, a: V, b: V) -> V
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  V::step(nt, threshold, a, b)
+}
+
+/// Linear segment from `a` to `b`.
+///
+/// This is the formula of [`Interpolation::Linear`](crate::interpolation::Interpolation::Linear).
+#[inline]
+pub fn eval_linear<T, V>(nt: T, a: V, b: V) -> V
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  V::lerp(nt, a, b)
+}
+
+/// Cosine segment from `a` to `b`.
+///
+/// This is the formula of [`Interpolation::Cosine`](crate::interpolation::Interpolation::Cosine).
+#[inline]
+pub fn eval_cosine<T, V>(nt: T, a: V, b: V) -> V
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  V::cosine(nt, a, b)
+}
+
+/// Catmull-Rom segment from `a` to `b`, with `before` and `after` the `(t, value)` pairs of the
+/// neighboring points.
+///
+/// The sampling values of the points are used to scale the tangents, so that non-uniformly spaced
+/// points are supported. This is the formula of
+/// [`Interpolation::CatmullRom`](crate::interpolation::Interpolation::CatmullRom).
+#[inline]
+pub fn eval_catmull_rom<T, V>(nt: T, before: (T, V), a: (T, V), b: (T, V), after: (T, V)) -> V
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  V::cubic_hermite(nt, before, a, b, after)
+}
+
+/// Cubic Hermite segment from `a` to `b`, with tangents `m0` at `a` and `m1` at `b`.
+///
+/// Tangents are expressed with respect to the normalized time; multiply tangents expressed with
+/// respect to the sampling value by the duration of the segment.
+#[inline]
+pub fn eval_hermite<T, V>(nt: T, a: V, m0: V, b: V, m1: V) -> V
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  Shape::hermite(a, m0, b, m1).value(nt)
+}
+
+/// Quadratic Bézier segment from `a` to `b`, with control point `u`.
+///
+/// This is the formula of [`Interpolation::Bezier`](crate::interpolation::Interpolation::Bezier)
+/// when the next key is not a Bézier key.
+#[inline]
+pub fn eval_quadratic_bezier<T, V>(nt: T, a: V, u: V, b: V) -> V
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  V::quadratic_bezier(nt, a, u, b)
+}
+
+/// Cubic Bézier segment from `a` to `b`, with control points `u` and `v`.
+///
+/// This is the formula of
+/// [`Interpolation::StrokeBezier`](crate::interpolation::Interpolation::StrokeBezier).
+#[inline]
+pub fn eval_cubic_bezier<T, V>(nt: T, a: V, u: V, v: V, b: V) -> V
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  V::cubic_bezier(nt, a, u, v, b)
+}
@@ -0,0 +1,58 @@
+//! Parallel sampling with [rayon](https://crates.io/crates/rayon).
+
+use crate::interpolate::{Interpolate, Scalar};
+use crate::spline::{clamped_sample_hinted, sample_hinted, Spline};
+use rayon::prelude::*;
+
+impl<T, V> Spline<T, V> {
+  /// Sample a spline at many times at once, splitting the times across threads.
+  ///
+  /// This behaves the same way as [`Spline::sample_many`], every thread reusing the segments it
+  /// finds for the next times it samples. It is worth it for large numbers of times only.
+  pub fn par_sample_many(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Scalar + Send + Sync,
+    V: Interpolate<T> + Send + Sync,
+  {
+    ts.par_iter()
+      .map_init(
+        || None,
+        |hint, &t| sample_hinted(&self.0, hint, t).or_else(|| self.1.clone()),
+      )
+      .collect()
+  }
+
+  /// Bake the spline into `count` regularly spaced samples from `t0` to `t1`, both included,
+  /// splitting the samples across threads.
+  ///
+  /// The spline is sampled with clamping (see [`Spline::clamped_sample`]). This is typically used to
+  /// bake curves into fixed-rate tracks at asset build time. A single sample is taken at `t0`.
+  pub fn par_bake(&self, t0: T, t1: T, count: usize) -> Vec<Option<V>>
+  where
+    T: Scalar + Send + Sync,
+    V: Interpolate<T> + Send + Sync,
+  {
+    let step = if count > 1 {
+      (t1 - t0) / T::from_f64((count - 1) as f64)
+    } else {
+      T::zero()
+    };
+
+    (0..count)
+      .into_par_iter()
+      .map_init(
+        || None,
+        |hint, i| {
+          // avoid rounding errors at the end
+          let t = if i > 0 && i + 1 == count {
+            t1
+          } else {
+            t0 + step * T::from_f64(i as f64)
+          };
+
+          clamped_sample_hinted(&self.0, hint, t).or_else(|| self.1.clone())
+        },
+      )
+      .collect()
+  }
+}
@@ -0,0 +1,140 @@
+//! Description of the serialization format.
+//!
+//! External tools – web editors, importers written in other languages, etc. – need to know how
+//! splines are serialized to read and write them reliably. [`Spline::schema_json`] generates a
+//! [JSON Schema](https://json-schema.org) (draft 2020-12) describing the format produced and
+//! accepted by the `serde` implementations of this crate.
+//!
+//! The schema of the sampling and carried types is provided by the [`Schema`] trait, which is
+//! implemented for `f32` and `f64`, and for the vector and quaternion types of cgmath, glam and
+//! nalgebra with their respective features – enabling the `serde` feature along with one of them
+//! enables its serialization support too. Implement it for your own types to describe how they are
+//! serialized.
+
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/// Types that can describe their serialization format as a JSON Schema.
+pub trait Schema {
+  /// JSON Schema of the type, as a JSON object.
+  fn schema_json() -> String;
+}
+
+impl Schema for f32 {
+  fn schema_json() -> String {
+    r#"{"type":"number"}"#.into()
+  }
+}
+
+impl Schema for f64 {
+  fn schema_json() -> String {
+    r#"{"type":"number"}"#.into()
+  }
+}
+
+// Implement Schema for a type with the given expression.
+#[cfg(any(
+  feature = "impl-cgmath",
+  feature = "cgmath",
+  feature = "impl-glam",
+  feature = "glam",
+  feature = "impl-nalgebra",
+  feature = "nalgebra"
+))]
+macro_rules! impl_Schema {
+  ($v:ty, $schema:expr) => {
+    impl $crate::schema::Schema for $v {
+      fn schema_json() -> String {
+        $schema
+      }
+    }
+  };
+}
+
+#[cfg(any(
+  feature = "impl-cgmath",
+  feature = "cgmath",
+  feature = "impl-glam",
+  feature = "glam",
+  feature = "impl-nalgebra",
+  feature = "nalgebra"
+))]
+pub(crate) use impl_Schema;
+
+// Schema of values serialized as arrays of len numbers, such as glam and nalgebra vectors.
+#[cfg(any(
+  feature = "impl-glam",
+  feature = "glam",
+  feature = "impl-nalgebra",
+  feature = "nalgebra"
+))]
+pub(crate) fn number_array_schema(len: usize) -> String {
+  format!(
+    r#"{{"type":"array","prefixItems":[{items}],"minItems":{len},"maxItems":{len}}}"#,
+    items = core::iter::repeat_n(f64::schema_json(), len)
+      .collect::<Vec<_>>()
+      .join(","),
+    len = len
+  )
+}
+
+// Schema of values serialized as objects with the given fields, such as cgmath vectors.
+#[cfg(any(feature = "impl-cgmath", feature = "cgmath"))]
+pub(crate) fn object_schema(fields: &[(&str, String)]) -> String {
+  let properties: Vec<_> = fields
+    .iter()
+    .map(|(name, schema)| format!(r#""{}":{}"#, name, schema))
+    .collect();
+  let required: Vec<_> = fields
+    .iter()
+    .map(|(name, _)| format!(r#""{}""#, name))
+    .collect();
+
+  format!(
+    r#"{{"type":"object","properties":{{{}}},"required":[{}]}}"#,
+    properties.join(","),
+    required.join(",")
+  )
+}
+
+impl<T, V> Spline<T, V> {
+  /// JSON Schema describing how a spline is serialized.
+  ///
+  /// A spline is serialized as an array of keys. Unit interpolation modes are serialized as
+  /// strings (e.g. `"linear"`) and the other ones as single-field objects (e.g.
+  /// `{ "step": 0.5 }`). The `in_interpolation` field of keys is optional and omitted when not
+  /// set; the `tangent_constraint` field is optional, defaults to `"free"` and is omitted when
+  /// `"free"`.
+  pub fn schema_json() -> String
+  where
+    T: Schema,
+    V: Schema,
+  {
+    let t = T::schema_json();
+    let v = V::schema_json();
+
+    format!(
+      concat!(
+        r##"{{"$schema":"https://json-schema.org/draft/2020-12/schema","##,
+        r##""title":"Spline","type":"array","items":{{"$ref":"#/$defs/key"}},"$defs":{{"##,
+        r##""key":{{"type":"object","properties":{{"t":{t},"value":{v},"##,
+        r##""interpolation":{{"$ref":"#/$defs/interpolation"}},"##,
+        r##""in_interpolation":{{"$ref":"#/$defs/interpolation"}},"##,
+        r##""tangent_constraint":{{"$ref":"#/$defs/tangent_constraint"}}}},"##,
+        r##""required":["t","value","interpolation"]}},"##,
+        r##""interpolation":{{"oneOf":[{{"enum":["linear","cosine","catmull_rom"]}},"##,
+        r##"{{"type":"object","properties":{{"step":{t}}},"required":["step"],"##,
+        r##""additionalProperties":false}},"##,
+        r##"{{"type":"object","properties":{{"bezier":{v}}},"required":["bezier"],"##,
+        r##""additionalProperties":false}},"##,
+        r##"{{"type":"object","properties":{{"stroke_bezier":{{"type":"array","##,
+        r##""prefixItems":[{v},{v}],"minItems":2,"maxItems":2}}}},"##,
+        r##""required":["stroke_bezier"],"additionalProperties":false}}]}},"##,
+        r##""tangent_constraint":{{"enum":["free","aligned","mirrored"],"default":"free"}}}}}}"##,
+      ),
+      t = t,
+      v = v
+    )
+  }
+}
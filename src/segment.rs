@@ -0,0 +1,208 @@
+//! Segment-level mathematics.
+//!
+//! A segment is the part of a spline defined between two consecutive keys. This module expresses
+//! the shape of a segment in normalized time, which is used to compute derivatives and other
+//! differential quantities that cannot be obtained from [`Interpolate`] alone.
+//!
+//! [`Interpolate`]: crate::interpolate::Interpolate
+
+use crate::interpolate::{Linear, Scalar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+
+/// Shape of a segment, parameterized by normalized time.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Shape<T, V> {
+  /// Hold `a` until `threshold` is reached, then `b`.
+  Step { threshold: T, a: V, b: V },
+
+  /// Cosine interpolation between `a` and `b`.
+  Cosine { a: V, b: V },
+
+  /// Cubic polynomial, with coefficients sorted by ascending degree.
+  Cubic([V; 4]),
+}
+
+impl<T, V> Shape<T, V>
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  /// Shape of the segment starting at key `i`.
+  ///
+  /// Return `None` if `i` is the last key or if the interpolation mode requires keys that are not
+  /// available (e.g. [`Interpolation::CatmullRom`] near the ends of the spline).
+  pub(crate) fn new(keys: &[Key<T, V>], i: usize) -> Option<Self> {
+    let cp0 = keys.get(i)?;
+    let cp1 = keys.get(i + 1)?;
+    let a = cp0.value;
+    let b = cp1.value;
+
+    match cp0.interpolation {
+      Interpolation::Step(threshold) => Some(Shape::Step { threshold, a, b }),
+
+      Interpolation::Linear => Some(Shape::Cubic([a, b - a, zero(a), zero(a)])),
+
+      Interpolation::Cosine => Some(Shape::Cosine { a, b }),
+
+      Interpolation::CatmullRom => {
+        if i == 0 || i >= keys.len() - 2 {
+          return None;
+        }
+
+        let cpm0 = &keys[i - 1];
+        let cpm1 = &keys[i + 2];
+        let dt = cp1.t - cp0.t;
+        let m0 = (b - cpm0.value).outer_div(cp1.t - cpm0.t).outer_mul(dt);
+        let m1 = (cpm1.value - a).outer_div(cpm1.t - cp0.t).outer_mul(dt);
+
+        Some(Self::hermite(a, m0, b, m1))
+      }
+
+      Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => {
+        let shape = match *cp1.incoming() {
+          Interpolation::Bezier(v) => Self::cubic_bezier(a, u, b + b - v, b),
+          Interpolation::StrokeBezier(v, _) => Self::cubic_bezier(a, u, v, b),
+          _ => Self::quadratic_bezier(a, u, b),
+        };
+
+        Some(shape)
+      }
+    }
+  }
+
+  /// Cubic Hermite segment going from `a` to `b` with tangents `m0` and `m1`.
+  pub(crate) fn hermite(a: V, m0: V, b: V, m1: V) -> Self {
+    let c2 = thrice(b - a) - twice(m0) - m1;
+    let c3 = twice(a - b) + m0 + m1;
+
+    Shape::Cubic([a, m0, c2, c3])
+  }
+
+  /// Quadratic Bézier segment going from `a` to `b` with control point `u`.
+  pub(crate) fn quadratic_bezier(a: V, u: V, b: V) -> Self {
+    Shape::Cubic([a, twice(u - a), a - twice(u) + b, zero(a)])
+  }
+
+  /// Cubic Bézier segment going from `a` to `b` with control points `u` and `v`.
+  pub(crate) fn cubic_bezier(a: V, u: V, v: V, b: V) -> Self {
+    Shape::Cubic([
+      a,
+      thrice(u - a),
+      thrice(a - twice(u) + v),
+      b - a + thrice(u - v),
+    ])
+  }
+
+  /// Control points of the segment as a cubic Bézier curve, going from the first to the last one.
+  ///
+  /// Linear, Catmull-Rom and Bézier segments are polynomials of degree three at most, and then have
+  /// an exact cubic Bézier form. Cosine and step segments don’t, and yield `None`.
+  pub(crate) fn bezier(&self) -> Option<[V; 4]> {
+    match *self {
+      Shape::Cubic([c0, c1, c2, c3]) => {
+        let three = T::one() + T::one() + T::one();
+        let u = c0 + c1.outer_div(three);
+        let v = u + (c1 + c2).outer_div(three);
+
+        Some([c0, u, v, c0 + c1 + c2 + c3])
+      }
+
+      _ => None,
+    }
+  }
+
+  /// Value of the segment at normalized time `nt`.
+  pub(crate) fn value(&self, nt: T) -> V {
+    match *self {
+      Shape::Step { threshold, a, b } => {
+        if nt < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      Shape::Cosine { a, b } => {
+        let one = T::one();
+        let half = one / (one + one);
+
+        a + (b - a).outer_mul((one - (T::pi() * nt).cos()) * half)
+      }
+
+      Shape::Cubic([c0, c1, c2, c3]) => {
+        c0 + (c1 + (c2 + c3.outer_mul(nt)).outer_mul(nt)).outer_mul(nt)
+      }
+    }
+  }
+
+  /// Value, first and second derivatives of the segment at normalized time `nt`.
+  ///
+  /// Derivatives are expressed with respect to the normalized time; divide them by the duration of
+  /// the segment (and its square, respectively) to get them with respect to the sampling value.
+  pub(crate) fn derivatives(&self, nt: T) -> [V; 3] {
+    match *self {
+      Shape::Step { threshold, a, b } => {
+        let value = if nt < threshold { a } else { b };
+        [value, zero(a), zero(a)]
+      }
+
+      Shape::Cosine { a, b } => {
+        let one = T::one();
+        let half = one / (one + one);
+        let pi = T::pi();
+        let x = pi * nt;
+        let d = b - a;
+
+        [
+          a + d.outer_mul((one - x.cos()) * half),
+          d.outer_mul(pi * half * x.sin()),
+          d.outer_mul(pi * pi * half * x.cos()),
+        ]
+      }
+
+      Shape::Cubic([c0, c1, c2, c3]) => [
+        c0 + (c1 + (c2 + c3.outer_mul(nt)).outer_mul(nt)).outer_mul(nt),
+        c1 + (twice(c2) + thrice(c3).outer_mul(nt)).outer_mul(nt),
+        twice(c2) + twice(thrice(c3)).outer_mul(nt),
+      ],
+    }
+  }
+}
+
+impl<T, V> Shape<T, V> {
+  /// Apply a linear map to the values of the shape, such as projecting on a coordinate.
+  pub(crate) fn map<W>(self, f: impl Fn(V) -> W) -> Shape<T, W> {
+    match self {
+      Shape::Step { threshold, a, b } => Shape::Step {
+        threshold,
+        a: f(a),
+        b: f(b),
+      },
+      Shape::Cosine { a, b } => Shape::Cosine { a: f(a), b: f(b) },
+      Shape::Cubic([c0, c1, c2, c3]) => Shape::Cubic([f(c0), f(c1), f(c2), f(c3)]),
+    }
+  }
+}
+
+fn zero<T, V>(v: V) -> V
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  v.outer_mul(T::zero())
+}
+
+fn twice<V>(v: V) -> V
+where
+  V: Copy + core::ops::Add<V, Output = V>,
+{
+  v + v
+}
+
+fn thrice<V>(v: V) -> V
+where
+  V: Copy + core::ops::Add<V, Output = V>,
+{
+  v + v + v
+}
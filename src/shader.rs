@@ -0,0 +1,204 @@
+//! Shader code generation.
+//!
+//! Curves authored on the CPU often need to be evaluated on the GPU too, and keeping both in sync
+//! by hand is error-prone. [`Spline::to_shader`] generates a GLSL or WGSL function evaluating a
+//! scalar spline, along with the data that function reads.
+//!
+//! # Data layout
+//!
+//! The data is a flat array of `f32`, meant to be uploaded into a storage buffer (or a large enough
+//! uniform array). It starts with a header of four floats – the number of segments, the value of
+//! the first key, the value of the last key and a padding float – followed by eight floats per
+//! segment:
+//!
+//!   1. The sampling value at the start of the segment.
+//!   2. The sampling value at the end of the segment.
+//!   3. The kind of segment: `0` for a cubic polynomial, `1` for a step and `2` for a cosine.
+//!   4. The threshold of step segments, or `0`.
+//!   5. – 8. The coefficients of cubic polynomials by ascending degree, with respect to the
+//!      normalized time (see [`Polynomial`]). Step and cosine segments store the values of their
+//!      keys in the first two coefficients.
+//!
+//! Segments are then aligned on 16 bytes, as required by most buffer layouts.
+//!
+//! [`Polynomial`]: crate::baked::Polynomial
+
+use crate::interpolate::{Linear, Scalar};
+use crate::segment::Shape;
+use crate::spline::Spline;
+
+/// Number of floats in the header of the data.
+const HEADER_LEN: usize = 4;
+
+/// Number of floats per segment in the data.
+const SEGMENT_LEN: usize = 8;
+
+/// Shading language to generate code for.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ShaderLanguage {
+  /// GLSL, version 4.30 or later (or GLSL ES 3.10 or later).
+  Glsl,
+  /// WGSL.
+  Wgsl,
+}
+
+/// Shader code evaluating a spline, along with the data it reads.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShaderSpline {
+  /// Source code of the evaluation function.
+  pub source: String,
+  /// Data to upload, as described in the [module documentation](self).
+  pub data: Vec<f32>,
+}
+
+const GLSL_SOURCE: &str = "float NAME(float t) {
+  int count = int(NAME_data[0]);
+
+  if (count == 0 || t <= NAME_data[4]) {
+    return NAME_data[1];
+  }
+
+  if (t >= NAME_data[8 * count - 3]) {
+    return NAME_data[2];
+  }
+
+  int lo = 0;
+  int hi = count - 1;
+
+  while (lo < hi) {
+    int mid = (lo + hi + 1) / 2;
+
+    if (NAME_data[4 + 8 * mid] <= t) {
+      lo = mid;
+    } else {
+      hi = mid - 1;
+    }
+  }
+
+  int base = 4 + 8 * lo;
+  float start = NAME_data[base];
+  float kind = NAME_data[base + 2];
+  float c0 = NAME_data[base + 4];
+  float c1 = NAME_data[base + 5];
+  float nt = (t - start) / (NAME_data[base + 1] - start);
+
+  if (kind == 1.0) {
+    return nt < NAME_data[base + 3] ? c0 : c1;
+  }
+
+  if (kind == 2.0) {
+    return c0 + (c1 - c0) * (1.0 - cos(3.14159265358979 * nt)) * 0.5;
+  }
+
+  return c0 + (c1 + (NAME_data[base + 6] + NAME_data[base + 7] * nt) * nt) * nt;
+}
+";
+
+const WGSL_SOURCE: &str = "fn NAME(t: f32) -> f32 {
+  let count = i32(NAME_data[0]);
+
+  if (count == 0 || t <= NAME_data[4]) {
+    return NAME_data[1];
+  }
+
+  if (t >= NAME_data[8 * count - 3]) {
+    return NAME_data[2];
+  }
+
+  var lo = 0;
+  var hi = count - 1;
+
+  while (lo < hi) {
+    let mid = (lo + hi + 1) / 2;
+
+    if (NAME_data[4 + 8 * mid] <= t) {
+      lo = mid;
+    } else {
+      hi = mid - 1;
+    }
+  }
+
+  let base = 4 + 8 * lo;
+  let start = NAME_data[base];
+  let kind = NAME_data[base + 2];
+  let c0 = NAME_data[base + 4];
+  let c1 = NAME_data[base + 5];
+  let nt = (t - start) / (NAME_data[base + 1] - start);
+
+  if (kind == 1.0) {
+    return select(c1, c0, nt < NAME_data[base + 3]);
+  }
+
+  if (kind == 2.0) {
+    return c0 + (c1 - c0) * (1.0 - cos(3.14159265358979 * nt)) * 0.5;
+  }
+
+  return c0 + (c1 + (NAME_data[base + 6] + NAME_data[base + 7] * nt) * nt) * nt;
+}
+";
+
+impl<T> Spline<T, T> {
+  /// Generate a shader function evaluating the spline with clamping.
+  ///
+  /// The function is called `name` and takes the sampling value as a single `f32`. It reads the
+  /// data from an array of `f32` called `{name}_data`, which must be declared by the shader; for
+  /// instance, in WGSL:
+  ///
+  /// ```wgsl
+  /// @group(0) @binding(0) var<storage, read> curve_data: array<f32>;
+  /// ```
+  ///
+  /// Or in GLSL:
+  ///
+  /// ```glsl
+  /// layout(std430, binding = 0) readonly buffer CurveData { float curve_data[]; };
+  /// ```
+  ///
+  /// The function behaves like [`Spline::clamped_sample`], with single-precision floats. Splines of
+  /// vectors can be evaluated component by component, by generating a function per component (see
+  /// [`Spline::map`]).
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has no key or if one of its segments cannot be sampled (see
+  /// [`Spline::sample`]).
+  pub fn to_shader(&self, language: ShaderLanguage, name: &str) -> Option<ShaderSpline>
+  where
+    T: Scalar + Linear<T>,
+  {
+    let keys = self.keys();
+    let (first, last) = (keys.first()?, keys.last()?);
+    let float = |x: T| x.to_f64() as f32;
+    let segment_count = keys.len() - 1;
+    let mut data = Vec::with_capacity(HEADER_LEN + SEGMENT_LEN * segment_count);
+
+    data.extend([
+      segment_count as f32,
+      float(first.value),
+      float(last.value),
+      0.,
+    ]);
+
+    for (i, pair) in keys.windows(2).enumerate() {
+      let (kind, threshold, coefficients) = match Shape::new(keys, i)? {
+        Shape::Cubic(coefficients) => (0., T::zero(), coefficients),
+        Shape::Step { threshold, a, b } => (1., threshold, [a, b, T::zero(), T::zero()]),
+        Shape::Cosine { a, b } => (2., T::zero(), [a, b, T::zero(), T::zero()]),
+      };
+
+      data.extend([float(pair[0].t), float(pair[1].t), kind, float(threshold)]);
+      data.extend(coefficients.map(float));
+    }
+
+    let source = match language {
+      ShaderLanguage::Glsl => GLSL_SOURCE,
+      ShaderLanguage::Wgsl => WGSL_SOURCE,
+    };
+
+    Some(ShaderSpline {
+      source: source.replace("NAME", name),
+      data,
+    })
+  }
+}
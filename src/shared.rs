@@ -0,0 +1,81 @@
+//! Splines shared with real-time threads.
+//!
+//! Parameter automation typically has a thread editing a curve – a UI – while another one samples
+//! it – an audio or render thread – which must never block. A [`SharedSpline`] holds a spline
+//! behind an [`ArcSwap`]: publishing a new spline atomically replaces the current one, and sampling
+//! never locks nor allocates. A sampling thread sees either the previous spline or the new one,
+//! never a mix of both.
+
+use crate::interpolate::{Interpolate, Interpolator};
+use crate::spline::Spline;
+use arc_swap::ArcSwap;
+use std::sync::Arc;
+
+/// A spline that can be replaced while being sampled by other threads.
+#[derive(Debug)]
+pub struct SharedSpline<T, V> {
+  current: ArcSwap<Spline<T, V>>,
+}
+
+impl<T, V> SharedSpline<T, V> {
+  /// Share a spline.
+  pub fn new(spline: Spline<T, V>) -> Self {
+    SharedSpline {
+      current: ArcSwap::from_pointee(spline),
+    }
+  }
+
+  /// Replace the shared spline.
+  ///
+  /// Threads sampling the previous spline while it’s replaced keep on sampling it until they are
+  /// done; it’s dropped by the last of them.
+  pub fn publish(&self, spline: Spline<T, V>) {
+    self.current.store(Arc::new(spline));
+  }
+
+  /// Replace the shared spline by an edited version of it.
+  ///
+  /// `f` might be called several times if other threads publish splines concurrently.
+  pub fn update<F>(&self, mut f: F)
+  where
+    F: FnMut(&Spline<T, V>) -> Spline<T, V>,
+  {
+    self.current.rcu(|spline| f(spline));
+  }
+
+  /// Get the current spline.
+  ///
+  /// The returned spline is not affected by later publications, which is useful to sample a
+  /// consistent spline several times.
+  pub fn load(&self) -> Arc<Spline<T, V>> {
+    self.current.load_full()
+  }
+
+  /// Sample the current spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.current.load().sample(t)
+  }
+
+  /// Sample the current spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.current.load().clamped_sample(t)
+  }
+}
+
+impl<T, V> From<Spline<T, V>> for SharedSpline<T, V> {
+  fn from(spline: Spline<T, V>) -> Self {
+    Self::new(spline)
+  }
+}
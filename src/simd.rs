@@ -0,0 +1,100 @@
+//! SIMD batch sampling of `f32` splines.
+//!
+//! Audio-rate envelopes and curve rasterization sample the same segments of a spline many times
+//! in a row. The functions of this module sample several normalized times of a segment at once with
+//! the SIMD vectors of [`wide`]: [`f32x4`] and [`f32x8`].
+//!
+//! Segments are evaluated from their polynomial forms (see [`Spline::to_polynomials`]); values can
+//! then differ from [`Spline::sample`] by rounding errors.
+
+use crate::segment::Shape;
+use crate::spline::{sample_hinted, Spline};
+use std::f32::consts::PI;
+use wide::{f32x4, f32x8, CmpLt};
+
+macro_rules! impl_simd_sampling {
+  ($lanes:ty, $n:literal, $sample_segment:ident, $sample_into:ident) => {
+    impl Spline<f32, f32> {
+      #[doc = concat!("Sample the segment starting at key `i` at ", stringify!($n), " normalized times at once.")]
+      ///
+      /// See [`Spline::sample_segment`] for further details.
+      pub fn $sample_segment(&self, i: usize, nt: $lanes) -> Option<$lanes> {
+        let value = match Shape::new(self.keys(), i)? {
+          Shape::Step { threshold, a, b } => {
+            nt.cmp_lt(<$lanes>::splat(threshold))
+              .blend(<$lanes>::splat(a), <$lanes>::splat(b))
+          }
+
+          Shape::Cosine { a, b } => {
+            let blend = (<$lanes>::ONE - (nt * PI).cos()) * 0.5;
+            blend.mul_add(<$lanes>::splat(b - a), <$lanes>::splat(a))
+          }
+
+          Shape::Cubic([c0, c1, c2, c3]) => {
+            let c = <$lanes>::splat(c3).mul_add(nt, <$lanes>::splat(c2));
+            let c = c.mul_add(nt, <$lanes>::splat(c1));
+            c.mul_add(nt, <$lanes>::splat(c0))
+          }
+        };
+
+        Some(value)
+      }
+
+      #[doc = concat!("Sample the spline at many times at once, ", stringify!($n), " times at a time.")]
+      ///
+      /// This behaves like [`Spline::sample_into`]: `out[i]` receives the value sampled at `ts[i]`
+      /// and entries that cannot be sampled are left untouched. Every chunk of
+      #[doc = concat!(stringify!($n), " consecutive times")]
+      /// falling in a single segment is sampled at once; the other ones are sampled one by one. This
+      /// is then the fastest for ascending, closely spaced times, such as audio blocks.
+      ///
+      /// # Return
+      ///
+      /// The number of values that were written.
+      ///
+      /// # Panics
+      ///
+      /// If `ts` and `out` don’t have the same length.
+      pub fn $sample_into(&self, ts: &[f32], out: &mut [f32]) -> usize {
+        assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+
+        let keys = self.keys();
+        let mut hint: Option<usize> = None;
+        let mut written = 0;
+
+        for (ts, out) in ts.chunks($n).zip(out.chunks_mut($n)) {
+          // the segment of the previous chunk is likely to contain the whole chunk
+          if let Some(i) = hint.filter(|&i| i + 1 < keys.len()) {
+            let (start, end) = (keys[i].t, keys[i + 1].t);
+
+            if ts.len() == $n && ts.iter().all(|&t| start <= t && t < end) {
+              let mut nt = [0.; $n];
+
+              for (nt, &t) in nt.iter_mut().zip(ts) {
+                *nt = (t - start) / (end - start);
+              }
+
+              if let Some(values) = self.$sample_segment(i, <$lanes>::from(nt)) {
+                out.copy_from_slice(&values.to_array());
+                written += $n;
+                continue;
+              }
+            }
+          }
+
+          for (&t, value) in ts.iter().zip(out) {
+            if let Some(sampled) = sample_hinted(keys, &mut hint, t).or(self.fallback().copied()) {
+              *value = sampled;
+              written += 1;
+            }
+          }
+        }
+
+        written
+      }
+    }
+  };
+}
+
+impl_simd_sampling!(f32x4, 4, sample_segment_x4, sample_into_x4);
+impl_simd_sampling!(f32x8, 8, sample_segment_x8, sample_into_x8);
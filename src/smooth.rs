@@ -0,0 +1,128 @@
+//! Smoothing of key values.
+//!
+//! Splines built from sensor or telemetry data are noisy: their keys jitter around the actual
+//! signal, and interpolation faithfully reproduces that jitter. [`Spline::smooth`] denoises them by
+//! replacing the value of every key with a weighted average of the values of its neighbors, as
+//! described by a [`SmoothingWindow`].
+
+use crate::interpolate::{Linear, Scalar};
+use crate::interpolation::Interpolation;
+use crate::spline::Spline;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Weights given to the neighbors of a key by [`Spline::smooth`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kernel<T> {
+  /// All the keys of the window have the same weight – i.e. a moving average.
+  Box,
+  /// Keys are weighted by a Gaussian of the given standard deviation, expressed in keys.
+  Gaussian(T),
+}
+
+impl<T> Default for Kernel<T> {
+  /// [`Kernel::Box`] is the default.
+  fn default() -> Self {
+    Kernel::Box
+  }
+}
+
+/// Options of [`Spline::smooth`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SmoothingWindow<T> {
+  /// Number of keys on each side of a key that are averaged with it.
+  pub radius: usize,
+  /// Weights of the keys in the window.
+  pub kernel: Kernel<T>,
+  /// Whether the first and last keys keep their values.
+  pub preserve_endpoints: bool,
+}
+
+impl<T> Default for SmoothingWindow<T> {
+  /// A moving average over three keys, preserving the endpoints.
+  fn default() -> Self {
+    SmoothingWindow {
+      radius: 1,
+      kernel: Kernel::Box,
+      preserve_endpoints: true,
+    }
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Smooth the values of the keys.
+  ///
+  /// The value of every key is replaced by the weighted average of the values of the keys within
+  /// `window.radius` keys of it. Near the ends of the spline, the window is truncated and the
+  /// weights of the remaining keys are normalized. Keys are weighted by their index, not by their
+  /// sampling value, which suits regularly sampled data.
+  ///
+  /// Bézier control points move along with their keys, so that tangents keep their shape.
+  pub fn smooth(&self, window: SmoothingWindow<T>) -> Self
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let keys = self.keys();
+    let weight = |d: usize| match window.kernel {
+      Kernel::Box => T::one(),
+      Kernel::Gaussian(sigma) => {
+        let d = T::from_f64(d as f64);
+        (T::zero() - d * d / (T::from_f64(2.) * sigma * sigma)).exp()
+      }
+    };
+    let last = keys.len().saturating_sub(1);
+    let smoothed: Vec<V> = (0..keys.len())
+      .map(|i| {
+        if window.preserve_endpoints && (i == 0 || i == last) {
+          return keys[i].value;
+        }
+
+        let (start, end) = (
+          i.saturating_sub(window.radius),
+          (i + window.radius).min(last),
+        );
+        let (sum, total) = keys[start..=end].iter().zip(start..).fold(
+          (None, T::zero()),
+          |(sum, total): (Option<V>, T), (key, j)| {
+            let w = weight(i.abs_diff(j));
+            let value = key.value.outer_mul(w);
+            (Some(sum.map_or(value, |sum| sum + value)), total + w)
+          },
+        );
+
+        sum.map_or(keys[i].value, |sum| sum.outer_div(total))
+      })
+      .collect();
+
+    let move_interpolation = |interpolation: &mut Interpolation<T, V>, delta: V| match interpolation
+    {
+      Interpolation::Bezier(u) => *u = *u + delta,
+      Interpolation::StrokeBezier(v, u) => {
+        *v = *v + delta;
+        *u = *u + delta;
+      }
+      _ => (),
+    };
+    let keys = keys
+      .iter()
+      .zip(smoothed)
+      .map(|(key, value)| {
+        let mut key = *key;
+        let delta = value - key.value;
+
+        key.value = value;
+        move_interpolation(&mut key.interpolation, delta);
+
+        if let Some(ref mut interpolation) = key.in_interpolation {
+          move_interpolation(interpolation, delta);
+        }
+
+        key
+      })
+      .collect();
+
+    self.with_keys(keys)
+  }
+}
@@ -0,0 +1,338 @@
+//! Inverse evaluation of splines.
+
+use crate::interpolate::{Linear, Planar, Scalar};
+use crate::segment::Shape;
+use crate::spline::Spline;
+use std::f64::consts::PI;
+
+/// Maximum number of iterations of the bisection.
+const MAX_BISECTIONS: u32 = 64;
+
+/// Tolerance used to accept cubic roots slightly outside of `[0; 1]`, due to rounding.
+const ROOT_EPSILON: f64 = 1e-9;
+
+impl<T> Spline<T, T> {
+  /// Find the sampling value at which the spline reaches `value`.
+  ///
+  /// This is the inverse of [`Spline::sample`] for monotonic scalar splines, which is typically
+  /// needed when using splines as response curves. Cubic segments (Catmull-Rom, Bézier, linear)
+  /// are solved analytically, while cosine segments are solved by bisection.
+  ///
+  /// If the spline is not monotonic, the lowest sampling value at which the spline reaches `value`
+  /// is returned. On a step segment, the step is reached only by the values of its keys.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline never reaches `value`. Segments that cannot be sampled (see
+  /// [`Spline::sample`]) are ignored.
+  pub fn solve_t(&self, value: T) -> Option<T>
+  where
+    T: Scalar + Linear<T>,
+  {
+    let keys = self.keys();
+
+    if keys.len() == 1 {
+      return if keys[0].value == value {
+        Some(keys[0].t)
+      } else {
+        None
+      };
+    }
+
+    for i in 0..keys.len().saturating_sub(1) {
+      let nt = match Shape::new(keys, i) {
+        Some(shape) => solve_segment(&shape, value),
+        None => continue,
+      };
+
+      if let Some(nt) = nt {
+        let (t0, t1) = (keys[i].t, keys[i + 1].t);
+        return Some(t0 + (t1 - t0) * nt);
+      }
+    }
+
+    None
+  }
+
+  /// Find every sampling value at which the spline crosses `level`.
+  ///
+  /// This is typically used to detect triggers in automation curves, such as when an envelope
+  /// goes above or below a threshold. Sampling values are returned in ascending order. Cubic
+  /// segments (Catmull-Rom, Bézier, linear) are solved analytically, while cosine segments are
+  /// solved by bisection.
+  ///
+  /// Points where the spline only touches `level` are returned as well. Step segments cross
+  /// `level` at their threshold if `level` lies between the values of their keys, and segments
+  /// constant and equal to `level` only yield their start. Segments that cannot be sampled (see
+  /// [`Spline::sample`]) are ignored.
+  pub fn crossings(&self, level: T) -> Vec<T>
+  where
+    T: Scalar + Linear<T>,
+  {
+    let keys = self.keys();
+    let mut crossings: Vec<T> = Vec::new();
+
+    if keys.len() == 1 && keys[0].value == level {
+      crossings.push(keys[0].t);
+    }
+
+    for i in 0..keys.len().saturating_sub(1) {
+      let roots = match Shape::new(keys, i) {
+        Some(Shape::Step { threshold, a, b }) => {
+          let between = (a < level && level < b) || (b < level && level < a);
+
+          if between {
+            vec![threshold]
+          } else {
+            segment_roots(&Shape::Step { threshold, a, b }, level)
+          }
+        }
+        Some(shape) => segment_roots(&shape, level),
+        None => continue,
+      };
+      let (t0, t1) = (keys[i].t, keys[i + 1].t);
+
+      for nt in roots {
+        let t = if nt == T::one() {
+          t1
+        } else {
+          t0 + (t1 - t0) * nt
+        };
+
+        // roots at the end of a segment are found again at the start of the next one
+        if crossings.last().is_none_or(|&last| last != t) {
+          crossings.push(t);
+        }
+      }
+    }
+
+    crossings
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Evaluate a 2D spline as an easing curve.
+  ///
+  /// The first coordinate of the carried values is treated as the time: the spline is solved for
+  /// the point whose first coordinate equals `x` and the second coordinate of that point is
+  /// returned. This is how CSS’ `cubic-bezier()` timing functions and the easing curves of
+  /// animation software work, and it cannot be expressed by sampling the spline at `x`, since the
+  /// sampling value of the spline is not its first coordinate.
+  ///
+  /// The first coordinate is expected to be monotonic – as is the case with CSS, where the first
+  /// coordinates of the control points are in `[0; 1]`. If it’s not, the point with the lowest
+  /// sampling value is used.
+  ///
+  /// # Return
+  ///
+  /// `None` if no point of the spline has `x` as its first coordinate. Segments that cannot be
+  /// sampled (see [`Spline::sample`]) are ignored.
+  pub fn ease(&self, x: T) -> Option<T>
+  where
+    T: Scalar + Linear<T>,
+    V: Planar<T>,
+  {
+    let keys = self.keys();
+
+    if keys.len() == 1 {
+      let value = keys[0].value;
+      return if value.x() == x {
+        Some(value.y())
+      } else {
+        None
+      };
+    }
+
+    for i in 0..keys.len().saturating_sub(1) {
+      let shape = match Shape::new(keys, i) {
+        Some(shape) => shape,
+        None => continue,
+      };
+
+      if let Some(nt) = solve_segment(&shape.map(V::x), x) {
+        return Some(shape.map(V::y).value(nt));
+      }
+    }
+
+    None
+  }
+}
+
+/// Find the lowest normalized time at which a scalar segment reaches `value`.
+fn solve_segment<T>(shape: &Shape<T, T>, value: T) -> Option<T>
+where
+  T: Scalar + Linear<T>,
+{
+  segment_roots(shape, value).first().copied()
+}
+
+/// Normalized times, in ascending order, at which a scalar segment reaches `value`.
+///
+/// A segment constant and equal to `value` only yields its start.
+pub(crate) fn segment_roots<T>(shape: &Shape<T, T>, value: T) -> Vec<T>
+where
+  T: Scalar + Linear<T>,
+{
+  match *shape {
+    Shape::Step { threshold, a, b } => {
+      let mut roots = Vec::new();
+
+      if a == value {
+        roots.push(T::zero());
+      }
+
+      if b == value && b != a {
+        roots.push(threshold);
+      }
+
+      roots
+    }
+
+    Shape::Cosine { a, b } => bisect(shape, a, b, value).into_iter().collect(),
+
+    Shape::Cubic(coefficients) => cubic_roots_in_unit(coefficients, value),
+  }
+}
+
+/// Find the normalized time at which a segment monotonic between `a` and `b` reaches `value`.
+fn bisect<T>(shape: &Shape<T, T>, a: T, b: T, value: T) -> Option<T>
+where
+  T: Scalar + Linear<T>,
+{
+  let increasing = a <= b;
+  let (low_value, high_value) = if increasing { (a, b) } else { (b, a) };
+
+  if value < low_value || value > high_value {
+    return None;
+  }
+
+  if value == a {
+    return Some(T::zero());
+  }
+
+  if value == b {
+    return Some(T::one());
+  }
+
+  let mut low = T::zero();
+  let mut high = T::one();
+
+  for _ in 0..MAX_BISECTIONS {
+    let middle = (low + high) * T::from_f64(0.5);
+
+    if (shape.value(middle) < value) == increasing {
+      low = middle;
+    } else {
+      high = middle;
+    }
+  }
+
+  Some((low + high) * T::from_f64(0.5))
+}
+
+/// Roots in `[0; 1]`, in ascending order, of `c0 + c1 x + c2 x² + c3 x³ = value`.
+fn cubic_roots_in_unit<T>([c0, c1, c2, c3]: [T; 4], value: T) -> Vec<T>
+where
+  T: Scalar,
+{
+  let (d, c, b, a) = ((c0 - value).to_f64(), c1.to_f64(), c2.to_f64(), c3.to_f64());
+  let scale = d.abs().max(c.abs()).max(b.abs()).max(a.abs());
+
+  if scale == 0. {
+    // the segment is constant and equal to the value
+    return vec![T::zero()];
+  }
+
+  let roots = if a.abs() <= ROOT_EPSILON * scale {
+    quadratic_roots(b, c, d)
+  } else {
+    cubic_roots(a, b, c, d)
+  };
+
+  let mut roots: Vec<f64> = roots
+    .into_iter()
+    .flatten()
+    .filter(|x| (-ROOT_EPSILON..=1. + ROOT_EPSILON).contains(x))
+    .map(|x| polish(a, b, c, d, x.clamp(0., 1.)))
+    .collect();
+
+  roots.sort_by(f64::total_cmp);
+  roots.dedup_by(|x, y| (*x - *y).abs() <= ROOT_EPSILON);
+  roots.into_iter().map(T::from_f64).collect()
+}
+
+/// Real roots of `a x² + b x + c = 0`.
+pub(crate) fn quadratic_roots(a: f64, b: f64, c: f64) -> [Option<f64>; 3] {
+  let scale = a.abs().max(b.abs()).max(c.abs());
+
+  if a.abs() <= ROOT_EPSILON * scale {
+    if b == 0. {
+      return [None; 3];
+    }
+
+    return [Some(-c / b), None, None];
+  }
+
+  let discriminant = b * b - 4. * a * c;
+
+  if discriminant < 0. {
+    return [None; 3];
+  }
+
+  // numerically stable form, avoiding the cancellation of -b ± √Δ
+  let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+  let r0 = q / a;
+  let r1 = if q != 0. { c / q } else { r0 };
+
+  [Some(r0), Some(r1), None]
+}
+
+/// Real roots of `a x³ + b x² + c x + d = 0`, with `a ≠ 0`.
+fn cubic_roots(a: f64, b: f64, c: f64, d: f64) -> [Option<f64>; 3] {
+  // depressed cubic t³ + p t + q = 0, with x = t - b / 3a
+  let (b, c, d) = (b / a, c / a, d / a);
+  let shift = -b / 3.;
+  let p = c - b * b / 3.;
+  let q = 2. * b * b * b / 27. - b * c / 3. + d;
+  let discriminant = q * q / 4. + p * p * p / 27.;
+
+  if p == 0. && q == 0. {
+    [Some(shift), None, None]
+  } else if discriminant > 0. {
+    let sqrt = discriminant.sqrt();
+    let u = (-q / 2. + sqrt).cbrt();
+    let v = (-q / 2. - sqrt).cbrt();
+
+    [Some(u + v + shift), None, None]
+  } else if discriminant == 0. {
+    [Some(3. * q / p + shift), Some(-1.5 * q / p + shift), None]
+  } else {
+    let r = 2. * (-p / 3.).sqrt();
+    let phi = ((3. * q / (2. * p)) * (-3. / p).sqrt())
+      .clamp(-1., 1.)
+      .acos()
+      / 3.;
+
+    [
+      Some(r * phi.cos() + shift),
+      Some(r * (phi - 2. * PI / 3.).cos() + shift),
+      Some(r * (phi - 4. * PI / 3.).cos() + shift),
+    ]
+  }
+}
+
+/// Refine a root of `a x³ + b x² + c x + d = 0` with a few Newton iterations, staying in `[0; 1]`.
+fn polish(a: f64, b: f64, c: f64, d: f64, mut x: f64) -> f64 {
+  for _ in 0..2 {
+    let f = ((a * x + b) * x + c) * x + d;
+    let df = (3. * a * x + 2. * b) * x + c;
+
+    if df == 0. {
+      break;
+    }
+
+    x = (x - f / df).clamp(0., 1.);
+  }
+
+  x
+}
@@ -0,0 +1,123 @@
+//! Checked sorted keys, unlocking fast sampling paths.
+//!
+//! Every call to [`Spline::sample`] checks that the spline has enough keys and that the sampling
+//! value lies inside of it. In hot loops where those invariants are already known to hold, those
+//! checks are wasted. [`SortedKeys`] carries the proof that a slice of keys is non-empty and sorted,
+//! checked once when it’s created, and provides entry points that skip the per-call checks – still
+//! retaining them as debug assertions.
+
+use crate::interpolate::{Interpolate, Interpolator};
+use crate::key::Key;
+use crate::spline::{sample_segment, Spline};
+#[cfg(not(feature = "std"))]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+/// A non-empty slice of keys sorted by sampling value.
+///
+/// The only ways to get one are [`SortedKeys::new`], which checks the invariants, and
+/// [`Spline::sorted_keys`], which relies on the invariants of [`Spline`].
+#[derive(Debug)]
+pub struct SortedKeys<'a, T, V> {
+  keys: &'a [Key<T, V>],
+}
+
+impl<'a, T, V> Clone for SortedKeys<'a, T, V> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<'a, T, V> Copy for SortedKeys<'a, T, V> {}
+
+impl<'a, T, V> SortedKeys<'a, T, V> {
+  /// Check that keys are non-empty and sorted by ascending sampling value.
+  ///
+  /// This is *O(n)*. Return `None` if the keys are empty or not sorted – keys whose sampling values
+  /// cannot be compared, such as NaN, are considered not sorted.
+  pub fn new(keys: &'a [Key<T, V>]) -> Option<Self>
+  where
+    T: PartialOrd,
+  {
+    let sorted = keys.windows(2).all(|w| {
+      matches!(
+        w[0].t.partial_cmp(&w[1].t),
+        Some(Ordering::Less | Ordering::Equal)
+      )
+    });
+
+    if keys.is_empty() || !sorted {
+      return None;
+    }
+
+    Some(SortedKeys { keys })
+  }
+
+  /// Sorted keys.
+  pub fn keys(&self) -> &'a [Key<T, V>] {
+    self.keys
+  }
+
+  /// Number of keys; always at least one.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Always `false`, since sorted keys are never empty.
+  pub fn is_empty(&self) -> bool {
+    false
+  }
+
+  /// Sample the keys at a given time, without checking that `t` lies inside of them.
+  ///
+  /// This is the fast path of [`Spline::sample`]: no check is performed in release builds, and the
+  /// value is returned directly instead of an [`Option`].
+  ///
+  /// `t` must lie between the first and the last key, and the segment containing `t` must be
+  /// sampleable (see [`Spline::sample`]). Those conditions are checked by debug assertions; in
+  /// release builds, breaking them doesn’t cause undefined behavior but yields unspecified values:
+  /// sampling values outside of the keys are sampled on the first or last segment, and segments
+  /// that cannot be sampled yield the value of their lower key.
+  pub fn sample_unchecked(&self, t: T) -> V
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let keys = self.keys;
+    let len = keys.len();
+
+    debug_assert!(
+      keys[0].t <= t && t <= keys[len - 1].t,
+      "sampling value out of the keys"
+    );
+
+    if len == 1 {
+      return keys[0].value.clone();
+    }
+
+    let i = keys
+      .partition_point(|key| key.t <= t)
+      .saturating_sub(1)
+      .min(len - 2);
+    let value = sample_segment(keys, i, t);
+
+    debug_assert!(value.is_some(), "segment {} cannot be sampled", i);
+
+    value.unwrap_or(keys[i].value.clone())
+  }
+}
+
+impl<T, V> Spline<T, V> {
+  /// Sorted keys of the spline, unlocking the fast sampling paths of [`SortedKeys`].
+  ///
+  /// This is *O(1)*, since the keys of a spline are always sorted. Return `None` if the spline has
+  /// no key.
+  pub fn sorted_keys(&self) -> Option<SortedKeys<'_, T, V>> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(SortedKeys { keys: self.keys() })
+    }
+  }
+}
@@ -1,19 +1,63 @@
 //! Spline curves and operations.
 
 #[cfg(feature = "std")]
-use crate::interpolate::{Interpolate, Interpolator};
+use crate::interpolate::{InnerSpace, Interpolate, Interpolator, Linear, Scalar};
 use crate::interpolation::Interpolation;
-use crate::key::Key;
+use crate::iter::Drain;
+use crate::key::{Key, TangentConstraint};
+use crate::segment::Shape;
+use crate::track::TrackKind;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 use core::cmp::Ordering;
 #[cfg(not(feature = "std"))]
-use core::ops::{Div, Mul};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use core::ops::{Bound, Div, Index, Mul, Range, RangeBounds};
 #[cfg(any(feature = "serialization", feature = "serde"))]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
 use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::ops::{Bound, Index, Range, RangeBounds};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// Number of keys stored inline by splines, without any heap allocation, with the `smallvec`
+/// feature.
+#[cfg(feature = "smallvec")]
+pub(crate) const INLINE_KEYS: usize = 8;
+
+// Container of the keys of a spline.
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type Keys<T, V> = Vec<Key<T, V>>;
+#[cfg(feature = "smallvec")]
+pub(crate) type Keys<T, V> = smallvec::SmallVec<[Key<T, V>; INLINE_KEYS]>;
+
+#[cfg(not(feature = "smallvec"))]
+pub(crate) fn keys_from_vec<T, V>(keys: Vec<Key<T, V>>) -> Keys<T, V> {
+  keys
+}
+
+#[cfg(feature = "smallvec")]
+pub(crate) fn keys_from_vec<T, V>(keys: Vec<Key<T, V>>) -> Keys<T, V> {
+  smallvec::SmallVec::from_vec(keys)
+}
+
+#[cfg(not(feature = "smallvec"))]
+pub(crate) fn keys_into_vec<T, V>(keys: Keys<T, V>) -> Vec<Key<T, V>> {
+  keys
+}
+
+#[cfg(feature = "smallvec")]
+pub(crate) fn keys_into_vec<T, V>(keys: Keys<T, V>) -> Vec<Key<T, V>> {
+  keys.into_vec()
+}
 
 /// Spline curve used to provide interpolation between control points (keys).
 ///
@@ -27,16 +71,39 @@ use std::cmp::Ordering;
 ///     for the required interpolation mode, you get `None`.
 ///   - [`Spline::clamped_sample`]: behaves like [`Spline::sample`] but will return either the first
 ///     or last key if out of bound; it will return `None` if not enough key.
-#[derive(Debug, Clone, Default)]
+///
+/// A spline made of a single key is valid: it has a zero duration, [`Spline::clamped_sample`]
+/// always returns the value of its key and [`Spline::sample`] returns it only at the exact time of
+/// the key.
+///
+/// A spline can also hold a _fallback_ value (see [`Spline::set_fallback`]), returned by the
+/// sampling functions whenever they would otherwise return `None`, and a semantic tag (see
+/// [`Spline::set_kind`]).
+///
+/// With the `smallvec` feature, splines of up to eight keys store them inline, without any heap
+/// allocation – which is the case of most UI animations.
+#[derive(Debug, Clone, Default, PartialEq)]
 #[cfg_attr(
   any(feature = "serialization", feature = "serde"),
-  derive(Deserialize, Serialize)
+  derive(Deserialize, Serialize),
+  serde(transparent)
 )]
-pub struct Spline<T, V>(pub(crate) Vec<Key<T, V>>);
+pub struct Spline<T, V>(
+  pub(crate) Keys<T, V>,
+  // a default path rather than serde(skip) alone, so that deserializing doesn't require V: Default
+  #[cfg_attr(
+    any(feature = "serialization", feature = "serde"),
+    serde(skip, default = "Option::default")
+  )]
+  pub(crate) Option<V>,
+  // splines are serialized as bare arrays of keys; see TaggedSpline to serialize the tag
+  #[cfg_attr(any(feature = "serialization", feature = "serde"), serde(skip))]
+  pub(crate)  Option<TrackKind>,
+);
 
 impl<T, V> Spline<T, V> {
   /// Internal sort to ensure invariant of sorting keys is valid.
-  fn internal_sort(&mut self)
+  pub(crate) fn internal_sort(&mut self)
   where
     T: PartialOrd,
   {
@@ -51,11 +118,60 @@ impl<T, V> Spline<T, V> {
   where
     T: PartialOrd,
   {
-    let mut spline = Spline(keys);
+    let mut spline = Spline(keys_from_vec(keys), None, None);
     spline.internal_sort();
     spline
   }
 
+  /// Create a new spline out of keys, checking them.
+  ///
+  /// This behaves like [`Spline::from_vec`], but rejects keys that would make sampling misbehave
+  /// instead of silently accepting them. The indices reported by [`SplineError`] are the indices of
+  /// the keys in `keys`.
+  pub fn try_from_vec(keys: Vec<Key<T, V>>) -> Result<Self, SplineError>
+  where
+    T: PartialOrd,
+  {
+    if keys.len() < 2 {
+      return Err(SplineError::NotEnoughKeys(keys.len()));
+    }
+
+    if let Some(i) = keys
+      .iter()
+      .position(|key| key.t.partial_cmp(&key.t).is_none())
+    {
+      return Err(SplineError::IncomparableKey(i));
+    }
+
+    let mut indexed: Vec<_> = keys.into_iter().enumerate().collect();
+    indexed.sort_by(|(_, k0), (_, k1)| k0.t.partial_cmp(&k1.t).unwrap_or(Ordering::Less));
+
+    if let Some(w) = indexed.windows(2).find(|w| w[0].1.t == w[1].1.t) {
+      return Err(SplineError::DuplicateKey(w[0].0.max(w[1].0)));
+    }
+
+    let keys = indexed.into_iter().map(|(_, key)| key).collect();
+    Ok(Spline(keys_from_vec(keys), None, None))
+  }
+
+  /// Create a new spline out of keys already sorted by ascending sampling value.
+  ///
+  /// This skips the sort performed by [`Spline::from_vec`], which is useful when the keys are
+  /// known to be sorted – for instance when they were saved from a spline. The order is only
+  /// checked by a debug assertion; sampling a spline created out of unsorted keys yields unspecified
+  /// values.
+  pub fn from_sorted_vec(keys: Vec<Key<T, V>>) -> Self
+  where
+    T: PartialOrd,
+  {
+    debug_assert!(
+      keys.windows(2).all(|w| w[0].t <= w[1].t),
+      "keys are not sorted"
+    );
+
+    Spline(keys_from_vec(keys), None, None)
+  }
+
   /// Clear the spline by removing all keys. Keeps the underlying allocated storage, so adding
   /// new keys should be faster than creating a new [`Spline`]
   #[inline]
@@ -63,13 +179,70 @@ impl<T, V> Spline<T, V> {
     self.0.clear()
   }
 
+  /// Fallback value, returned by the sampling functions whenever they would otherwise return
+  /// `None`.
+  pub fn fallback(&self) -> Option<&V> {
+    self.1.as_ref()
+  }
+
+  /// Set the fallback value, or remove it with `None`.
+  ///
+  /// When set, the fallback value is returned by [`Spline::sample`], [`Spline::clamped_sample`],
+  /// their batch versions ([`Spline::sample_many`], [`Spline::sample_into`], etc.) and the sampling
+  /// functions of [`Cursor`](crate::cursor::Cursor) whenever they would otherwise return `None` –
+  /// for instance out of the keys, or near the ends of [`Interpolation::CatmullRom`] splines. It’s
+  /// also yielded by [`Spline::iter_samples`] instead of skipping such sampling values. Functions
+  /// returning keys along with values, such as [`Spline::sample_with_key`] and
+  /// [`Cursor::sample_with_key`](crate::cursor::Cursor::sample_with_key), don’t use it.
+  ///
+  /// The fallback value is not serialized.
+  pub fn set_fallback(&mut self, fallback: Option<V>) {
+    self.1 = fallback;
+  }
+
+  /// Set the fallback value (see [`Spline::set_fallback`]).
+  pub fn with_fallback(mut self, fallback: V) -> Self {
+    self.1 = Some(fallback);
+    self
+  }
+
+  /// Semantic tag of the spline, if any.
+  pub fn kind(&self) -> Option<TrackKind> {
+    self.2
+  }
+
+  /// Set the semantic tag of the spline, or remove it with `None`.
+  ///
+  /// The tag doesn’t change how the spline is sampled; it tells integrations what the values of
+  /// the spline represent (see [`TrackKind`]). It is not serialized with the spline; use a
+  /// [`TaggedSpline`](crate::track::TaggedSpline) to serialize it too.
+  pub fn set_kind(&mut self, kind: Option<TrackKind>) {
+    self.2 = kind;
+  }
+
+  /// Set the semantic tag of the spline (see [`Spline::set_kind`]).
+  pub fn with_kind(mut self, kind: TrackKind) -> Self {
+    self.2 = Some(kind);
+    self
+  }
+
+  /// Create a spline out of sorted keys, keeping the fallback value and the tag of this spline.
+  pub(crate) fn with_keys(&self, keys: Vec<Key<T, V>>) -> Self
+  where
+    V: Clone,
+  {
+    Spline(keys_from_vec(keys), self.1.clone(), self.2)
+  }
+
   /// Create a new spline by consuming an `Iterater<Item = Key<T>>`. They keys don’t have to be
   /// sorted.
   ///
   /// # Note on iterators
   ///
   /// It’s valid to use any iterator that implements `Iterator<Item = Key<T>>`. However, you should
-  /// use [`Spline::from_vec`] if you are passing a [`Vec`].
+  /// use [`Spline::from_vec`] if you are passing a [`Vec`]. Splines can also be collected from
+  /// iterators with [`Iterator::collect`].
+  #[allow(clippy::should_implement_trait)]
   pub fn from_iter<I>(iter: I) -> Self
   where
     I: Iterator<Item = Key<T, V>>,
@@ -83,6 +256,62 @@ impl<T, V> Spline<T, V> {
     &self.0
   }
 
+  /// Look up a sampling value among the keys with a binary search.
+  ///
+  /// # Return
+  ///
+  /// `Ok(index)` if the key at `index` has `t` as sampling value, or `Err(index)` with the index at
+  /// which a key sampled at `t` would be inserted otherwise. In the latter case, `t` lies in the
+  /// segment starting at `index - 1`, if `index` is neither `0` nor the number of keys.
+  pub fn key_index_at(&self, t: T) -> Result<usize, usize>
+  where
+    T: PartialOrd,
+  {
+    let index = self.0.partition_point(|key| key.t < t);
+
+    match self.0.get(index) {
+      Some(key) if key.t == t => Ok(index),
+      _ => Err(index),
+    }
+  }
+
+  /// Retrieve the keys whose sampling value lies in a range.
+  ///
+  /// The bounds of the range are looked up with a binary search, so this is `O(log n)`. If the
+  /// range is empty or reversed, no key is returned.
+  pub fn keys_between<R>(&self, range: R) -> &[Key<T, V>]
+  where
+    R: RangeBounds<T>,
+    T: PartialOrd,
+  {
+    let start = match range.start_bound() {
+      Bound::Included(t0) => self.0.partition_point(|key| key.t < *t0),
+      Bound::Excluded(t0) => self.0.partition_point(|key| key.t <= *t0),
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(t1) => self.0.partition_point(|key| key.t <= *t1),
+      Bound::Excluded(t1) => self.0.partition_point(|key| key.t < *t1),
+      Bound::Unbounded => self.0.len(),
+    };
+
+    &self.0[start..end.max(start)]
+  }
+
+  /// Consume the spline and get its keys back, sorted.
+  ///
+  /// The fallback value and the tag of the spline are dropped.
+  pub fn into_keys(self) -> Vec<Key<T, V>> {
+    keys_into_vec(self.0)
+  }
+
+  /// Borrow the keys of the spline as a [`SplineRef`].
+  ///
+  /// The fallback value of the spline is not part of the view.
+  pub fn view(&self) -> SplineRef<'_, T, V> {
+    SplineRef { keys: &self.0 }
+  }
+
   /// Number of keys.
   #[inline(always)]
   pub fn len(&self) -> usize {
@@ -95,6 +324,20 @@ impl<T, V> Spline<T, V> {
     self.0.is_empty()
   }
 
+  /// Duration of the spline, i.e. the difference between the sampling values of its last and first
+  /// keys.
+  ///
+  /// Splines with less than two keys have a zero duration.
+  pub fn duration(&self) -> T
+  where
+    T: Scalar,
+  {
+    match (self.0.first(), self.0.last()) {
+      (Some(first), Some(last)) => last.t - first.t,
+      _ => T::zero(),
+    }
+  }
+
   /// Sample a spline at a given time, returning the interpolated value along with its associated
   /// key.
   ///
@@ -110,80 +353,157 @@ impl<T, V> Spline<T, V> {
   /// sampling impossible. For instance, [`Interpolation::CatmullRom`] requires *four* keys. If
   /// you’re near the beginning of the spline or its end, ensure you have enough keys around to make
   /// the sampling.
+  ///
+  /// A spline made of a single key can only be sampled at the exact time of that key, yielding its
+  /// value.
   pub fn sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
-    let keys = &self.0;
-    let i = search_lower_cp(keys, t)?;
-    let cp0 = &keys[i];
-
-    let value = match cp0.interpolation {
-      Interpolation::Step(threshold) => {
-        let cp1 = &keys[i + 1];
-        let nt = t.normalize(cp0.t, cp1.t);
-        let value = V::step(nt, threshold, cp0.value, cp1.value);
+    self.view().sample_with_key(t)
+  }
 
-        Some(value)
-      }
+  /// Sample the segment starting at key `i` at the normalized time `nt`.
+  ///
+  /// `nt` is local to the segment: `0` is the time of key `i` and `1` the time of key `i + 1`. No
+  /// search is performed, which is useful when the segment is already known – for instance when
+  /// editing or baking a spline segment by segment.
+  ///
+  /// # Return
+  ///
+  /// `None` if `i` is the last key or is out of the keys, or if the segment cannot be sampled (see
+  /// [`Spline::sample`]).
+  pub fn sample_segment(&self, i: usize, nt: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.view().sample_segment(i, nt)
+  }
 
-      Interpolation::Linear => {
-        let cp1 = &keys[i + 1];
-        let nt = t.normalize(cp0.t, cp1.t);
-        let value = V::lerp(nt, cp0.value, cp1.value);
+  /// Sample a spline at a given time, returning the interpolated value along with details about the
+  /// segment used.
+  ///
+  /// This behaves like [`Spline::sample_with_key`], but also reports the normalized time inside the
+  /// segment and the interpolation mode used, which is typically needed by curve editors to highlight
+  /// the active segment.
+  pub fn sample_detailed(&self, t: T) -> Option<SampledDetailed<T, V>>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    self.view().sample_detailed(t)
+  }
 
-        Some(value)
-      }
+  /// Sample a spline at many times at once.
+  ///
+  /// This behaves the same way as calling [`Spline::sample`] for every `t` in `ts`, but the segment
+  /// found for a `t` is reused for the next one if it still applies (or if the next one is a
+  /// neighbor), avoiding a binary search. This is much faster when consecutive `ts` are close to
+  /// each other, which is typical when sampling the same spline for many particles or to discretize
+  /// it.
+  pub fn sample_many(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self
+      .view()
+      .sample_many(ts)
+      .into_iter()
+      .map(|value| value.or_else(|| self.1.clone()))
+      .collect()
+  }
 
-      Interpolation::Cosine => {
-        let cp1 = &keys[i + 1];
-        let nt = t.normalize(cp0.t, cp1.t);
-        let value = V::cosine(nt, cp0.value, cp1.value);
+  /// Sample a spline at many ascending times at once.
+  ///
+  /// This behaves the same way as calling [`Spline::sample`] for every `t` in `ts`, but assumes `ts`
+  /// are sorted in ascending order: instead of looking up the segment of every `t`, the segment of
+  /// the previous `t` is advanced until it contains the next one. Sampling `n` values from a spline
+  /// of `k` keys is then *O(n + k)*, which is what curve discretizers need.
+  ///
+  /// If `ts` are not sorted, the result is still correct, but a binary search is performed every
+  /// time a `t` is lower than the previous one.
+  pub fn sample_sorted(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self
+      .view()
+      .sample_sorted(ts)
+      .into_iter()
+      .map(|value| value.or_else(|| self.1.clone()))
+      .collect()
+  }
 
-        Some(value)
-      }
+  /// Sample a spline at many times at once, writing the values into a caller-provided buffer.
+  ///
+  /// This behaves like [`Spline::sample_many`] but doesn’t allocate, which makes it suitable for
+  /// real-time code paths, such as filling an audio block. `out[i]` receives the value sampled at
+  /// `ts[i]`; entries for which [`Spline::sample`] would return [`None`] are left untouched.
+  ///
+  /// # Return
+  ///
+  /// The number of values that were written.
+  ///
+  /// # Panics
+  ///
+  /// If `ts` and `out` don’t have the same length.
+  pub fn sample_into(&self, ts: &[T], out: &mut [V]) -> usize
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
 
-      Interpolation::CatmullRom => {
-        // We need at least four points for Catmull Rom; ensure we have them, otherwise, return
-        // None.
-        if i == 0 || i >= keys.len() - 2 {
-          None
-        } else {
-          let cp1 = &keys[i + 1];
-          let cpm0 = &keys[i - 1];
-          let cpm1 = &keys[i + 2];
-          let nt = t.normalize(cp0.t, cp1.t);
-          let value = V::cubic_hermite(
-            nt,
-            (cpm0.t, cpm0.value),
-            (cp0.t, cp0.value),
-            (cp1.t, cp1.value),
-            (cpm1.t, cpm1.value),
-          );
+    let mut hint = None;
+    let mut written = 0;
 
-          Some(value)
-        }
+    for (&t, value) in ts.iter().zip(out) {
+      if let Some(sampled) = sample_hinted(&self.0, &mut hint, t).or_else(|| self.1.clone()) {
+        *value = sampled;
+        written += 1;
       }
+    }
 
-      Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => {
-        // We need to check the next control point to see whether we want quadratic or cubic Bezier.
-        let cp1 = &keys[i + 1];
-        let nt = t.normalize(cp0.t, cp1.t);
-
-        let value = match cp1.interpolation {
-          Interpolation::Bezier(v) => V::cubic_bezier_mirrored(nt, cp0.value, u, v, cp1.value),
+    written
+  }
 
-          Interpolation::StrokeBezier(v, _) => V::cubic_bezier(nt, cp0.value, u, v, cp1.value),
+  /// Sample a spline at many times at once with clamping, writing the values into a
+  /// caller-provided buffer.
+  ///
+  /// This is the clamped version of [`Spline::sample_into`]: `ts` lying before the first key or
+  /// after the last one get the value of the first key or the last one, respectively.
+  ///
+  /// # Return
+  ///
+  /// The number of values that were written, which is lower than `ts.len()` only if the spline has
+  /// no key or if some segments cannot be sampled (see [`Spline::sample`]).
+  ///
+  /// # Panics
+  ///
+  /// If `ts` and `out` don’t have the same length.
+  pub fn clamped_sample_into(&self, ts: &[T], out: &mut [V]) -> usize
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
 
-          _ => V::quadratic_bezier(nt, cp0.value, u, cp1.value),
-        };
+    let mut hint = None;
+    let mut written = 0;
 
-        Some(value)
+    for (&t, value) in ts.iter().zip(out) {
+      if let Some(sampled) = clamped_sample_hinted(&self.0, &mut hint, t).or_else(|| self.1.clone())
+      {
+        *value = sampled;
+        written += 1;
       }
-    };
+    }
 
-    value.map(|value| SampledWithKey { value, key: i })
+    written
   }
 
   /// Sample a spline at a given time.
@@ -193,7 +513,58 @@ impl<T, V> Spline<T, V> {
     T: Interpolator,
     V: Interpolate<T>,
   {
-    self.sample_with_key(t).map(|sampled| sampled.value)
+    self
+      .sample_with_key(t)
+      .map(|sampled| sampled.value)
+      .or_else(|| self.1.clone())
+  }
+
+  /// Sample a spline at a given time, returning `default` if it cannot be sampled.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample_or(&self, t: T, default: V) -> V
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample(t).unwrap_or(default)
+  }
+
+  /// Sample a spline at a given time, returning the interpolated value along with the unit tangent
+  /// of the curve at that point.
+  ///
+  /// The segment is looked up only once for both the value and the tangent, which is faster than
+  /// sampling and differentiating separately.
+  ///
+  /// # Return
+  ///
+  /// `None` in the same situations as [`Spline::sample`]. If the curve is stationary at `t` (for
+  /// instance with [`Interpolation::Step`]), the returned tangent is zero.
+  pub fn sample_with_tangent(&self, t: T) -> Option<SampledWithTangent<V>>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    self.view().sample_with_tangent(t)
+  }
+
+  /// Curvature of the curve at a given time.
+  ///
+  /// The curvature is computed from the first and second derivatives of the curve and is the
+  /// inverse of the radius of the osculating circle. It makes sense for vector-valued splines,
+  /// such as the ones carrying the vector types of the math backends (enable the `cgmath`, `glam`
+  /// or `nalgebra` feature). Scalar splines always have a zero curvature.
+  ///
+  /// # Return
+  ///
+  /// `None` in the same situations as [`Spline::sample`], or if the curve is stationary at `t`, in
+  /// which case the curvature is not defined.
+  pub fn curvature(&self, t: T) -> Option<T>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    self.view().curvature(t)
   }
 
   /// Sample a spline at a given time with clamping, returning the interpolated value along with its
@@ -208,55 +579,116 @@ impl<T, V> Spline<T, V> {
   ///
   /// This function returns [`None`] if you have no key.
   pub fn clamped_sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.view().clamped_sample_with_key(t)
+  }
+
+  /// Sample a spline at a given time with clamping.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self
+      .clamped_sample_with_key(t)
+      .map(|sampled| sampled.value)
+      .or_else(|| self.1.clone())
+  }
+
+  /// Sample a spline at a given time with clamping, returning the default value of `V` if it cannot
+  /// be sampled.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample_or_default(&self, t: T) -> V
+  where
+    T: Interpolator,
+    V: Interpolate<T> + Default,
+  {
+    self.clamped_sample(t).unwrap_or_default()
+  }
+
+  /// Sample a spline at a given time, reporting why it cannot be sampled.
+  ///
+  /// This behaves like [`Spline::sample`], but returns a [`SampleError`] instead of [`None`]. The
+  /// fallback value is not used, so that the error is always reported.
+  pub fn try_sample(&self, t: T) -> Result<V, SampleError>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
     if self.0.is_empty() {
-      return None;
+      return Err(SampleError::Empty);
     }
 
-    self.sample_with_key(t).or_else(move || {
-      let first = self.0.first().unwrap();
-
-      if t <= first.t {
-        let sampled = SampledWithKey {
-          value: first.value,
-          key: 0,
-        };
-        Some(sampled)
-      } else {
-        let last = self.0.last().unwrap();
-
-        if t >= last.t {
-          let sampled = SampledWithKey {
-            value: last.value,
-            key: self.0.len() - 1,
-          };
-          Some(sampled)
-        } else {
-          None
-        }
-      }
-    })
+    let i = search_lower_cp(&self.0, t).ok_or(SampleError::OutOfDomain)?;
+    sample_segment(&self.0, i, t).ok_or(SampleError::NotEnoughKeys(i))
   }
 
-  /// Sample a spline at a given time with clamping.
-  pub fn clamped_sample(&self, t: T) -> Option<V>
+  /// Sample a spline at a given time with clamping, reporting why it cannot be sampled.
+  ///
+  /// This behaves like [`Spline::clamped_sample`], but returns a [`SampleError`] instead of
+  /// [`None`]. The fallback value is not used, so that the error is always reported.
+  pub fn try_clamped_sample(&self, t: T) -> Result<V, SampleError>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
-    self.clamped_sample_with_key(t).map(|sampled| sampled.value)
+    let (first, last) = match (self.0.first(), self.0.last()) {
+      (Some(first), Some(last)) => (first, last),
+      _ => return Err(SampleError::Empty),
+    };
+
+    if t <= first.t {
+      Ok(first.value.clone())
+    } else if t >= last.t {
+      Ok(last.value.clone())
+    } else {
+      self.try_sample(t)
+    }
   }
 
   /// Add a key into the spline.
+  ///
+  /// The key is inserted after the keys having the same sampling value. This is *O(log n)* to find
+  /// where to insert the key, plus the cost of moving the keys after it.
   pub fn add(&mut self, key: Key<T, V>)
   where
     T: PartialOrd,
   {
-    self.0.push(key);
-    self.internal_sort();
+    let index = self.0.partition_point(|k| k.t <= key.t);
+    self.0.insert(index, key);
+  }
+
+  /// Add many keys into the spline at once.
+  ///
+  /// The keys don’t have to be sorted. They are sorted and then merged with the keys of the spline
+  /// in a single pass, which is much faster than adding them one by one. Keys having the same
+  /// sampling value as a key of the spline are inserted after it.
+  pub fn add_many<I>(&mut self, keys: I)
+  where
+    I: IntoIterator<Item = Key<T, V>>,
+    T: PartialOrd,
+  {
+    let mut added: Vec<_> = keys.into_iter().collect();
+    added.sort_by(|k0, k1| k0.t.partial_cmp(&k1.t).unwrap_or(Ordering::Less));
+
+    let keys = core::mem::take(&mut self.0);
+    let mut merged = Vec::with_capacity(keys.len() + added.len());
+    let mut added = added.into_iter().peekable();
+
+    for key in keys {
+      while let Some(k) = added.next_if(|k| k.t < key.t) {
+        merged.push(k);
+      }
+
+      merged.push(key);
+    }
+
+    merged.extend(added);
+    self.0 = keys_from_vec(merged);
   }
 
   /// Remove a key from the spline.
@@ -268,9 +700,33 @@ impl<T, V> Spline<T, V> {
     }
   }
 
-  /// Update a key and return the key already present.
-  ///
-  /// The key is updated — if present — with the provided function.
+  /// Retain only the keys for which a predicate returns `true`, keeping them sorted.
+  pub fn retain<F>(&mut self, mut f: F)
+  where
+    F: FnMut(&Key<T, V>) -> bool,
+  {
+    self.0.retain(|key| f(key));
+  }
+
+  /// Remove the keys in a range of indices, returning them in an iterator.
+  ///
+  /// As with [`Vec::drain`], the keys are removed even if the iterator is not fully consumed.
+  ///
+  /// # Panics
+  ///
+  /// If the range is out of bounds or if its start is greater than its end.
+  pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, V>
+  where
+    R: RangeBounds<usize>,
+  {
+    Drain {
+      keys: self.0.drain(range),
+    }
+  }
+
+  /// Update a key and return the key already present.
+  ///
+  /// The key is updated — if present — with the provided function.
   ///
   /// # Notes
   ///
@@ -293,14 +749,133 @@ impl<T, V> Spline<T, V> {
   }
 
   /// Mutably get a key at a given index.
-  pub fn get_mut(&mut self, index: usize) -> Option<KeyMut<T, V>> {
-    self.0.get_mut(index).map(|key| KeyMut {
-      value: &mut key.value,
-      interpolation: &mut key.interpolation,
-    })
+  pub fn get_mut(&mut self, index: usize) -> Option<KeyMut<'_, T, V>> {
+    self.0.get_mut(index).map(KeyMut::new)
+  }
+
+  /// Mutably get a key at a given index.
+  ///
+  /// This is the mutable counterpart of indexing a spline (`spline[index]`), which cannot be
+  /// provided with [`IndexMut`](core::ops::IndexMut) as [`KeyMut`] doesn’t allow to move keys.
+  ///
+  /// # Panics
+  ///
+  /// If `index` is out of bounds.
+  pub fn key_mut(&mut self, index: usize) -> KeyMut<'_, T, V> {
+    let len = self.len();
+
+    match self.get_mut(index) {
+      Some(key) => key,
+      None => panic!("key index {} out of bounds ({} keys)", index, len),
+    }
+  }
+}
+
+impl<T, V> Index<usize> for Spline<T, V> {
+  type Output = Key<T, V>;
+
+  fn index(&self, index: usize) -> &Self::Output {
+    &self.0[index]
+  }
+}
+
+impl<T, V> FromIterator<Key<T, V>> for Spline<T, V>
+where
+  T: PartialOrd,
+{
+  fn from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = Key<T, V>>,
+  {
+    Self::from_vec(iter.into_iter().collect())
+  }
+}
+
+impl<T, V> Extend<Key<T, V>> for Spline<T, V>
+where
+  T: PartialOrd,
+{
+  /// Add keys to the spline, sorting them once all added (see [`Spline::add_many`]).
+  fn extend<I>(&mut self, iter: I)
+  where
+    I: IntoIterator<Item = Key<T, V>>,
+  {
+    self.add_many(iter);
+  }
+}
+
+/// Reasons why keys cannot make a spline.
+///
+/// See [`Spline::try_from_vec`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SplineError {
+  /// There are less than two keys – this many.
+  NotEnoughKeys(usize),
+
+  /// The sampling value of the key at this index cannot be compared, such as NaN.
+  IncomparableKey(usize),
+
+  /// The key at this index has the same sampling value as a previous key.
+  DuplicateKey(usize),
+}
+
+impl fmt::Display for SplineError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      SplineError::NotEnoughKeys(count) => {
+        write!(f, "a spline needs at least two keys, got {}", count)
+      }
+
+      SplineError::IncomparableKey(i) => {
+        write!(f, "the sampling value of key {} cannot be compared", i)
+      }
+
+      SplineError::DuplicateKey(i) => {
+        write!(f, "the sampling value of key {} is used by another key", i)
+      }
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SplineError {}
+
+/// Reasons why a spline cannot be sampled.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SampleError {
+  /// The spline has no key.
+  Empty,
+
+  /// The sampling value lies before the first key or at or after the last key – or, for a spline
+  /// made of a single key, anywhere but on that key.
+  OutOfDomain,
+
+  /// The segment starting at the key at this index uses [`Interpolation::CatmullRom`], but lacks a
+  /// key before it or a key after its end.
+  NotEnoughKeys(usize),
+}
+
+impl fmt::Display for SampleError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      SampleError::Empty => f.write_str("the spline has no key"),
+
+      SampleError::OutOfDomain => f.write_str("the sampling value is out of the spline"),
+
+      SampleError::NotEnoughKeys(i) => write!(
+        f,
+        "the Catmull-Rom segment starting at key {} lacks surrounding keys",
+        i
+      ),
+    }
   }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for SampleError {}
+
 /// A sampled value along with its key index.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct SampledWithKey<V> {
@@ -311,6 +886,37 @@ pub struct SampledWithKey<V> {
   pub key: usize,
 }
 
+/// A sampled value along with details about the segment it was sampled from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SampledDetailed<T, V> {
+  /// Sampled value.
+  pub value: V,
+
+  /// Key index, i.e. index of the key starting the segment.
+  pub key: usize,
+
+  /// Normalized time inside the segment, between `0` and `1`.
+  ///
+  /// Always `0` for splines made of a single key.
+  pub nt: T,
+
+  /// Interpolation mode used to sample the segment.
+  pub interpolation: Interpolation<T, V>,
+}
+
+/// A sampled value along with the unit tangent of the curve and its key index.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SampledWithTangent<V> {
+  /// Sampled value.
+  pub value: V,
+
+  /// Unit tangent of the curve at the sampled point.
+  pub tangent: V,
+
+  /// Key index.
+  pub key: usize,
+}
+
 /// A mutable [`Key`].
 ///
 /// Mutable keys allow to edit the carried values and the interpolation mode but not the actual
@@ -322,23 +928,1572 @@ pub struct KeyMut<'a, T, V> {
   pub value: &'a mut V,
   /// Interpolation mode to use for that key.
   pub interpolation: &'a mut Interpolation<T, V>,
+  /// Interpolation mode with which the key is reached, if different from the interpolation mode.
+  pub in_interpolation: &'a mut Option<Interpolation<T, V>>,
+  /// Constraint applied to the tangents of the key when editing them.
+  pub tangent_constraint: &'a mut TangentConstraint,
 }
 
-// Find the lower control point corresponding to a given time.
-// It has the property to have a timestamp smaller or equal to t
-fn search_lower_cp<T, V>(cps: &[Key<T, V>], t: T) -> Option<usize>
-where
-  T: PartialOrd,
-{
-  let len = cps.len();
-  if len < 2 {
-    return None;
+impl<'a, T, V> KeyMut<'a, T, V> {
+  // Borrow the editable parts of a key.
+  pub(crate) fn new(key: &'a mut Key<T, V>) -> Self {
+    KeyMut {
+      value: &mut key.value,
+      interpolation: &mut key.interpolation,
+      in_interpolation: &mut key.in_interpolation,
+      tangent_constraint: &mut key.tangent_constraint,
+    }
   }
-  match cps.binary_search_by(|key| key.t.partial_cmp(&t).unwrap()) {
-    Err(i) if i >= len => None,
-    Err(i) if i == 0 => None,
-    Err(i) => Some(i - 1),
-    Ok(i) if i == len - 1 => None,
-    Ok(i) => Some(i),
+
+  /// Get the input and output tangents (Bézier handles) of the key, in that order.
+  ///
+  /// The input tangent is taken from the incoming interpolation mode (see [`Key::incoming`]) and
+  /// the output tangent from the interpolation mode. For [`Interpolation::Bezier`], the input
+  /// tangent is the mirror of the output one. For any non Bézier interpolation mode, the handle is
+  /// located on the key’s value.
+  pub fn tangents(&self) -> (V, V)
+  where
+    T: Scalar,
+    V: Linear<T>,
+  {
+    let value = *self.value;
+    let incoming = self.in_interpolation.as_ref().unwrap_or(self.interpolation);
+
+    let input = match *incoming {
+      Interpolation::StrokeBezier(input, _) => input,
+      Interpolation::Bezier(output) => value + value - output,
+      _ => value,
+    };
+
+    let output = match *self.interpolation {
+      Interpolation::StrokeBezier(_, output) | Interpolation::Bezier(output) => output,
+      _ => value,
+    };
+
+    (input, output)
+  }
+
+  /// Set the input tangent (Bézier handle) of the key.
+  ///
+  /// The output tangent is updated according to the [`TangentConstraint`] of the key, and the
+  /// interpolation mode is set to [`Interpolation::StrokeBezier`]. If the key has an incoming
+  /// interpolation mode, it is set instead, and the interpolation mode is updated only if it’s
+  /// already a Bézier one – so that a key left stepped stays stepped.
+  pub fn set_in_tangent(&mut self, handle: V)
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let (_, output) = self.tangents();
+    let output = constrained_handle(*self.tangent_constraint, *self.value, handle, output);
+    let tangents = Interpolation::StrokeBezier(handle, output);
+
+    match self.in_interpolation {
+      Some(incoming) => {
+        *incoming = tangents;
+
+        if let Interpolation::Bezier(_) | Interpolation::StrokeBezier(..) = self.interpolation {
+          *self.interpolation = tangents;
+        }
+      }
+
+      None => *self.interpolation = tangents,
+    }
+  }
+
+  /// Set the output tangent (Bézier handle) of the key.
+  ///
+  /// The input tangent is updated according to the [`TangentConstraint`] of the key, and the
+  /// interpolation mode is set to [`Interpolation::StrokeBezier`]. If the key has an incoming
+  /// interpolation mode, it is updated as well.
+  pub fn set_out_tangent(&mut self, handle: V)
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let (input, _) = self.tangents();
+    let input = constrained_handle(*self.tangent_constraint, *self.value, handle, input);
+    let tangents = Interpolation::StrokeBezier(input, handle);
+
+    if let Some(incoming) = self.in_interpolation {
+      *incoming = tangents;
+    }
+
+    *self.interpolation = tangents;
+  }
+}
+
+/// A [`Spline`] storing its keys as a struct of arrays.
+///
+/// The sampling values of the keys are stored in their own array, separately from the carried
+/// values and interpolation modes. Searching for the segment to sample then only walks through the
+/// sampling values, which keeps that search cache-friendly when the carried values are large –
+/// matrices, long arrays, etc.
+///
+/// It provides the same sampling and editing API as [`Spline`], following the same rules – fallback
+/// value included – except for the functions borrowing keys, since keys are not stored as such:
+/// [`SoaSpline::get`] returns a copy of a key, and [`SoaSpline::keys_between`] returns a range of
+/// indices. Both convert into each other with [`From`], keeping the fallback value and the tag.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SoaSpline<T, V> {
+  ts: Vec<T>,
+  values: Vec<V>,
+  modes: Vec<KeyModes<T, V>>,
+  fallback: Option<V>,
+  kind: Option<TrackKind>,
+}
+
+impl<T, V> Default for SoaSpline<T, V> {
+  fn default() -> Self {
+    SoaSpline {
+      ts: Vec::new(),
+      values: Vec::new(),
+      modes: Vec::new(),
+      fallback: None,
+      kind: None,
+    }
+  }
+}
+
+// Everything but the sampling value and the carried value of a key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct KeyModes<T, V> {
+  interpolation: Interpolation<T, V>,
+  in_interpolation: Option<Interpolation<T, V>>,
+  tangent_constraint: TangentConstraint,
+}
+
+impl<T, V> KeyModes<T, V> {
+  // Split a key into its sampling value, carried value and modes.
+  fn split(key: Key<T, V>) -> (T, V, Self) {
+    let modes = KeyModes {
+      interpolation: key.interpolation,
+      in_interpolation: key.in_interpolation,
+      tangent_constraint: key.tangent_constraint,
+    };
+
+    (key.t, key.value, modes)
+  }
+
+  // Join the modes with a sampling value and a carried value back into a key.
+  fn join(self, t: T, value: V) -> Key<T, V> {
+    Key {
+      t,
+      value,
+      interpolation: self.interpolation,
+      in_interpolation: self.in_interpolation,
+      tangent_constraint: self.tangent_constraint,
+    }
+  }
+}
+
+impl<T, V> SoaSpline<T, V> {
+  /// Create a new spline out of keys. The keys don’t have to be sorted.
+  pub fn from_vec(keys: Vec<Key<T, V>>) -> Self
+  where
+    T: PartialOrd,
+  {
+    Spline::from_vec(keys).into()
+  }
+
+  /// Clear the spline by removing all keys. Keeps the underlying allocated storage, so adding new
+  /// keys should be faster than creating a new [`SoaSpline`].
+  pub fn clear(&mut self) {
+    self.ts.clear();
+    self.values.clear();
+    self.modes.clear();
+  }
+
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.ts.len()
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.ts.is_empty()
+  }
+
+  /// Sampling values of the keys, sorted.
+  pub fn ts(&self) -> &[T] {
+    &self.ts
+  }
+
+  /// Carried values of the keys, in the order of [`SoaSpline::ts`].
+  pub fn values(&self) -> &[V] {
+    &self.values
+  }
+
+  /// Mutably borrow the carried values of the keys, in the order of [`SoaSpline::ts`].
+  pub fn values_mut(&mut self) -> &mut [V] {
+    &mut self.values
+  }
+
+  /// Fallback value of the spline, if any.
+  ///
+  /// See [`Spline::set_fallback`] for further details.
+  pub fn fallback(&self) -> Option<&V> {
+    self.fallback.as_ref()
+  }
+
+  /// Set the fallback value of the spline, or remove it with `None`.
+  ///
+  /// See [`Spline::set_fallback`] for further details.
+  pub fn set_fallback(&mut self, fallback: Option<V>) {
+    self.fallback = fallback;
+  }
+
+  /// Set the fallback value (see [`SoaSpline::set_fallback`]).
+  pub fn with_fallback(mut self, fallback: V) -> Self {
+    self.fallback = Some(fallback);
+    self
+  }
+
+  /// Semantic tag of the spline, if any.
+  pub fn kind(&self) -> Option<TrackKind> {
+    self.kind
+  }
+
+  /// Set the semantic tag of the spline, or remove it with `None`.
+  ///
+  /// See [`Spline::set_kind`] for further details.
+  pub fn set_kind(&mut self, kind: Option<TrackKind>) {
+    self.kind = kind;
+  }
+
+  /// Set the semantic tag of the spline (see [`SoaSpline::set_kind`]).
+  pub fn with_kind(mut self, kind: TrackKind) -> Self {
+    self.kind = Some(kind);
+    self
+  }
+
+  /// Retrieve the key at a given index.
+  pub fn get(&self, index: usize) -> Option<Key<T, V>>
+  where
+    T: Copy,
+    V: Clone,
+  {
+    let modes = self.modes.get(index)?;
+
+    Some(
+      modes
+        .clone()
+        .join(self.ts[index], self.values[index].clone()),
+    )
+  }
+
+  /// Look up a sampling value among the keys with a binary search.
+  ///
+  /// See [`Spline::key_index_at`] for further details.
+  pub fn key_index_at(&self, t: T) -> Result<usize, usize>
+  where
+    T: PartialOrd,
+  {
+    let index = self.ts.partition_point(|key_t| *key_t < t);
+
+    match self.ts.get(index) {
+      Some(key_t) if *key_t == t => Ok(index),
+      _ => Err(index),
+    }
+  }
+
+  /// Indices of the keys whose sampling value lies in a range.
+  ///
+  /// The bounds of the range are looked up with a binary search, so this is `O(log n)`. If the
+  /// range is empty or reversed, the returned range is empty.
+  pub fn keys_between<R>(&self, range: R) -> Range<usize>
+  where
+    R: RangeBounds<T>,
+    T: PartialOrd,
+  {
+    let start = match range.start_bound() {
+      Bound::Included(t0) => self.ts.partition_point(|t| t < t0),
+      Bound::Excluded(t0) => self.ts.partition_point(|t| t <= t0),
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(t1) => self.ts.partition_point(|t| t <= t1),
+      Bound::Excluded(t1) => self.ts.partition_point(|t| t < t1),
+      Bound::Unbounded => self.ts.len(),
+    };
+
+    start..end.max(start)
+  }
+
+  /// Duration of the spline.
+  ///
+  /// See [`Spline::duration`] for further details.
+  pub fn duration(&self) -> T
+  where
+    T: Scalar,
+  {
+    match (self.ts.first(), self.ts.last()) {
+      (Some(&first), Some(&last)) => last - first,
+      _ => T::zero(),
+    }
+  }
+
+  /// Sample the spline at a given time, returning the interpolated value along with its associated
+  /// key.
+  ///
+  /// See [`Spline::sample_with_key`] for further details.
+  pub fn sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let i = match self.search_lower_cp(t) {
+      Some(i) => i,
+      None => {
+        #[cfg(feature = "stats")]
+        crate::stats::record(|stats| stats.out_of_range += 1);
+
+        return None;
+      }
+    };
+    let value = sample_indexed_segment(self, i, t)?;
+
+    Some(SampledWithKey { value, key: i })
+  }
+
+  /// Sample the segment starting at key `i` at the normalized time `nt`.
+  ///
+  /// See [`Spline::sample_segment`] for further details.
+  pub fn sample_segment(&self, i: usize, nt: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    if i + 1 >= self.len() {
+      return None;
+    }
+
+    sample_indexed_segment_normalized(self, i, nt)
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self
+      .sample_with_key(t)
+      .map(|sampled| sampled.value)
+      .or_else(|| self.fallback.clone())
   }
+
+  /// Sample the spline at a given time, returning `default` if it cannot be sampled.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample_or(&self, t: T, default: V) -> V
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample(t).unwrap_or(default)
+  }
+
+  /// Sample the spline at a given time with clamping, returning the interpolated value along with
+  /// its associated key.
+  ///
+  /// See [`Spline::clamped_sample_with_key`] for further details.
+  pub fn clamped_sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let (first, last) = (*self.ts.first()?, *self.ts.last()?);
+
+    self.sample_with_key(t).or_else(|| {
+      let key = if t <= first {
+        0
+      } else if t >= last {
+        self.len() - 1
+      } else {
+        return None;
+      };
+
+      Some(SampledWithKey {
+        value: self.values[key].clone(),
+        key,
+      })
+    })
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self
+      .clamped_sample_with_key(t)
+      .map(|sampled| sampled.value)
+      .or_else(|| self.fallback.clone())
+  }
+
+  /// Sample the spline at a given time with clamping, returning the default value of `V` if it
+  /// cannot be sampled.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample_or_default(&self, t: T) -> V
+  where
+    T: Interpolator,
+    V: Interpolate<T> + Default,
+  {
+    self.clamped_sample(t).unwrap_or_default()
+  }
+
+  /// Sample the spline at a given time, reporting why it cannot be sampled.
+  ///
+  /// See [`Spline::try_sample`] for further details.
+  pub fn try_sample(&self, t: T) -> Result<V, SampleError>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    if self.is_empty() {
+      return Err(SampleError::Empty);
+    }
+
+    let i = self.search_lower_cp(t).ok_or(SampleError::OutOfDomain)?;
+    sample_indexed_segment(self, i, t).ok_or(SampleError::NotEnoughKeys(i))
+  }
+
+  /// Sample the spline at a given time with clamping, reporting why it cannot be sampled.
+  ///
+  /// See [`Spline::try_clamped_sample`] for further details.
+  pub fn try_clamped_sample(&self, t: T) -> Result<V, SampleError>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let (first, last) = match (self.ts.first(), self.ts.last()) {
+      (Some(&first), Some(&last)) => (first, last),
+      _ => return Err(SampleError::Empty),
+    };
+
+    if t <= first {
+      Ok(self.values[0].clone())
+    } else if t >= last {
+      Ok(self.values[self.len() - 1].clone())
+    } else {
+      self.try_sample(t)
+    }
+  }
+
+  /// Sample the spline at many times at once.
+  ///
+  /// See [`Spline::sample_many`] for further details.
+  pub fn sample_many(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let mut hint = None;
+
+    ts.iter()
+      .map(|&t| {
+        self
+          .sample_hinted(&mut hint, t)
+          .or_else(|| self.fallback.clone())
+      })
+      .collect()
+  }
+
+  /// Sample the spline at many ascending times at once.
+  ///
+  /// See [`Spline::sample_sorted`] for further details.
+  pub fn sample_sorted(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let mut segment = 0;
+
+    ts.iter()
+      .map(|&t| {
+        let value = match self.advance_lower_cp(segment, t) {
+          Some(i) => {
+            segment = i;
+            sample_indexed_segment(self, i, t)
+          }
+
+          None => {
+            #[cfg(feature = "stats")]
+            crate::stats::record(|stats| stats.out_of_range += 1);
+
+            None
+          }
+        };
+
+        value.or_else(|| self.fallback.clone())
+      })
+      .collect()
+  }
+
+  /// Sample the spline at many times at once, writing the values into a caller-provided buffer.
+  ///
+  /// See [`Spline::sample_into`] for further details.
+  ///
+  /// # Panics
+  ///
+  /// If `ts` and `out` don’t have the same length.
+  pub fn sample_into(&self, ts: &[T], out: &mut [V]) -> usize
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+
+    let mut hint = None;
+    let mut written = 0;
+
+    for (&t, value) in ts.iter().zip(out) {
+      if let Some(sampled) = self
+        .sample_hinted(&mut hint, t)
+        .or_else(|| self.fallback.clone())
+      {
+        *value = sampled;
+        written += 1;
+      }
+    }
+
+    written
+  }
+
+  /// Sample the spline at many times at once with clamping, writing the values into a
+  /// caller-provided buffer.
+  ///
+  /// See [`Spline::clamped_sample_into`] for further details.
+  ///
+  /// # Panics
+  ///
+  /// If `ts` and `out` don’t have the same length.
+  pub fn clamped_sample_into(&self, ts: &[T], out: &mut [V]) -> usize
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+
+    let mut hint = None;
+    let mut written = 0;
+
+    for (&t, value) in ts.iter().zip(out) {
+      if let Some(sampled) = self
+        .clamped_sample_hinted(&mut hint, t)
+        .or_else(|| self.fallback.clone())
+      {
+        *value = sampled;
+        written += 1;
+      }
+    }
+
+    written
+  }
+
+  /// Add a key into the spline.
+  ///
+  /// See [`Spline::add`] for further details.
+  pub fn add(&mut self, key: Key<T, V>)
+  where
+    T: PartialOrd,
+  {
+    let index = self.ts.partition_point(|t| *t <= key.t);
+    let (t, value, modes) = KeyModes::split(key);
+
+    self.ts.insert(index, t);
+    self.values.insert(index, value);
+    self.modes.insert(index, modes);
+  }
+
+  /// Add many keys into the spline at once.
+  ///
+  /// See [`Spline::add_many`] for further details.
+  pub fn add_many<I>(&mut self, keys: I)
+  where
+    I: IntoIterator<Item = Key<T, V>>,
+    T: PartialOrd,
+  {
+    let mut spline = Spline::from(core::mem::take(self));
+
+    spline.add_many(keys);
+    *self = spline.into();
+  }
+
+  /// Remove a key from the spline.
+  pub fn remove(&mut self, index: usize) -> Option<Key<T, V>> {
+    if index >= self.len() {
+      return None;
+    }
+
+    let t = self.ts.remove(index);
+    let value = self.values.remove(index);
+    Some(self.modes.remove(index).join(t, value))
+  }
+
+  /// Retain only the keys for which a predicate returns `true`, keeping them sorted.
+  ///
+  /// The predicate is given the index of every key, which can be used with [`SoaSpline::ts`] and
+  /// [`SoaSpline::values`].
+  pub fn retain<F>(&mut self, mut f: F)
+  where
+    F: FnMut(usize) -> bool,
+  {
+    let kept: Vec<_> = (0..self.len()).map(&mut f).collect();
+
+    retain_flagged(&mut self.ts, &kept);
+    retain_flagged(&mut self.values, &kept);
+    retain_flagged(&mut self.modes, &kept);
+  }
+
+  // Same as search_lower_cp, on the sampling values of the keys.
+  fn search_lower_cp(&self, t: T) -> Option<usize>
+  where
+    T: PartialOrd,
+  {
+    let len = self.ts.len();
+
+    if len == 1 && self.ts[0] == t {
+      return Some(0);
+    }
+
+    // index of the first key after t
+    let next = self.ts.partition_point(|key_t| *key_t <= t);
+
+    if next == 0 || next >= len {
+      None
+    } else {
+      Some(next - 1)
+    }
+  }
+
+  // Same as search_lower_cp_near, on the sampling values of the keys.
+  fn search_lower_cp_near(&self, i: usize, t: T) -> Option<usize>
+  where
+    T: PartialOrd,
+  {
+    let ts = &self.ts;
+
+    [Some(i), i.checked_add(1), i.checked_sub(1)]
+      .into_iter()
+      .flatten()
+      .find(|&j| j + 1 < ts.len() && ts[j] <= t && t < ts[j + 1])
+  }
+
+  // Same as advance_lower_cp, on the sampling values of the keys.
+  fn advance_lower_cp(&self, mut i: usize, t: T) -> Option<usize>
+  where
+    T: PartialOrd,
+  {
+    let ts = &self.ts;
+    let len = ts.len();
+
+    if len < 2 || i + 1 >= len || t < ts[i] {
+      return self.search_lower_cp(t);
+    }
+
+    while i + 1 < len && ts[i + 1] <= t {
+      i += 1;
+    }
+
+    if i + 1 < len {
+      Some(i)
+    } else {
+      None
+    }
+  }
+
+  // Same as sample_hinted, on the arrays of the spline.
+  fn sample_hinted(&self, hint: &mut Option<usize>, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let near = hint.and_then(|i| self.search_lower_cp_near(i, t));
+    let i = match near.or_else(|| self.search_lower_cp(t)) {
+      Some(i) => i,
+      None => {
+        #[cfg(feature = "stats")]
+        crate::stats::record(|stats| stats.out_of_range += 1);
+
+        return None;
+      }
+    };
+
+    *hint = Some(i);
+    sample_indexed_segment(self, i, t)
+  }
+
+  // Same as clamped_sample_hinted, on the arrays of the spline.
+  fn clamped_sample_hinted(&self, hint: &mut Option<usize>, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let (first, last) = (*self.ts.first()?, *self.ts.last()?);
+
+    if t <= first {
+      self.values.first().cloned()
+    } else if t >= last {
+      self.values.last().cloned()
+    } else {
+      self.sample_hinted(hint, t)
+    }
+  }
+}
+
+// Retain the items of a vector whose flag is set.
+fn retain_flagged<X>(items: &mut Vec<X>, kept: &[bool]) {
+  let mut kept = kept.iter();
+  items.retain(|_| kept.next().copied().unwrap_or(true));
+}
+
+impl<T, V> IndexedKeys<T, V> for SoaSpline<T, V>
+where
+  T: Copy,
+  V: Clone,
+{
+  fn len(&self) -> usize {
+    self.ts.len()
+  }
+
+  fn t(&self, i: usize) -> T {
+    self.ts[i]
+  }
+
+  fn value(&self, i: usize) -> V {
+    self.values[i].clone()
+  }
+
+  fn with_interpolation<R>(
+    &self,
+    i: usize,
+    incoming: bool,
+    f: impl FnOnce(&Interpolation<T, V>) -> R,
+  ) -> R {
+    let modes = &self.modes[i];
+
+    match modes.in_interpolation {
+      Some(ref interpolation) if incoming => f(interpolation),
+      _ => f(&modes.interpolation),
+    }
+  }
+}
+
+impl<T, V> From<Spline<T, V>> for SoaSpline<T, V> {
+  fn from(spline: Spline<T, V>) -> Self {
+    let len = spline.0.len();
+    let mut soa = SoaSpline {
+      ts: Vec::with_capacity(len),
+      values: Vec::with_capacity(len),
+      modes: Vec::with_capacity(len),
+      fallback: spline.1,
+      kind: spline.2,
+    };
+
+    for key in spline.0 {
+      let (t, value, modes) = KeyModes::split(key);
+
+      soa.ts.push(t);
+      soa.values.push(value);
+      soa.modes.push(modes);
+    }
+
+    soa
+  }
+}
+
+impl<T, V> From<SoaSpline<T, V>> for Spline<T, V> {
+  fn from(soa: SoaSpline<T, V>) -> Self {
+    let keys = soa
+      .ts
+      .into_iter()
+      .zip(soa.values)
+      .zip(soa.modes)
+      .map(|((t, value), modes)| modes.join(t, value))
+      .collect();
+
+    // the keys are already sorted
+    Spline(keys, soa.fallback, soa.kind)
+  }
+}
+
+/// A borrowed view over sorted keys, providing the sampling API of [`Spline`].
+///
+/// The keys are not owned, so they can live in memory-mapped files, arenas, etc. and be sampled
+/// without being copied into a [`Spline`]. They are assumed to be sorted by sampling value, which
+/// is only checked by a debug assertion; sampling unsorted keys yields unspecified values.
+///
+/// Sampling follows the same rules as [`Spline`], but a view has no fallback value.
+#[derive(Debug)]
+pub struct SplineRef<'a, T, V> {
+  keys: &'a [Key<T, V>],
+}
+
+impl<'a, T, V> Clone for SplineRef<'a, T, V> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<'a, T, V> Copy for SplineRef<'a, T, V> {}
+
+impl<'a, T, V> SplineRef<'a, T, V> {
+  /// Create a view over keys sorted by sampling value.
+  pub fn new(keys: &'a [Key<T, V>]) -> Self
+  where
+    T: PartialOrd,
+  {
+    debug_assert!(
+      keys.windows(2).all(|w| w[0].t <= w[1].t),
+      "keys are not sorted"
+    );
+
+    SplineRef { keys }
+  }
+
+  /// Retrieve the keys of the view.
+  pub fn keys(&self) -> &'a [Key<T, V>] {
+    self.keys
+  }
+
+  /// Number of keys.
+  #[inline(always)]
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Check whether the view has no key.
+  #[inline(always)]
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Get a key at a given index.
+  pub fn get(&self, index: usize) -> Option<&'a Key<T, V>> {
+    self.keys.get(index)
+  }
+
+  /// Duration of the keys.
+  ///
+  /// See [`Spline::duration`] for further details.
+  pub fn duration(&self) -> T
+  where
+    T: Scalar,
+  {
+    match (self.keys.first(), self.keys.last()) {
+      (Some(first), Some(last)) => last.t - first.t,
+      _ => T::zero(),
+    }
+  }
+
+  /// Sample the keys at a given time, returning the interpolated value along with its associated
+  /// key.
+  ///
+  /// See [`Spline::sample_with_key`] for further details.
+  pub fn sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let keys = self.keys;
+    let i = match search_lower_cp(keys, t) {
+      Some(i) => i,
+      None => {
+        #[cfg(feature = "stats")]
+        crate::stats::record(|stats| stats.out_of_range += 1);
+
+        return None;
+      }
+    };
+    let value = sample_segment(keys, i, t)?;
+
+    Some(SampledWithKey { value, key: i })
+  }
+
+  /// Sample the segment starting at key `i` at the normalized time `nt`.
+  ///
+  /// See [`Spline::sample_segment`] for further details.
+  pub fn sample_segment(&self, i: usize, nt: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    if i + 1 >= self.keys.len() {
+      return None;
+    }
+
+    sample_segment_normalized(self.keys, i, nt)
+  }
+
+  /// Sample the keys at a given time, returning the interpolated value along with details about
+  /// the segment used.
+  ///
+  /// See [`Spline::sample_detailed`] for further details.
+  pub fn sample_detailed(&self, t: T) -> Option<SampledDetailed<T, V>>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    let keys = self.keys;
+    let i = search_lower_cp(keys, t)?;
+    let value = sample_segment(keys, i, t)?;
+    let cp0 = &keys[i];
+    let nt = keys
+      .get(i + 1)
+      .map_or(T::zero(), |cp1| t.normalize(cp0.t, cp1.t));
+
+    Some(SampledDetailed {
+      value,
+      key: i,
+      nt,
+      interpolation: cp0.interpolation.clone(),
+    })
+  }
+
+  /// Sample the keys at many times at once.
+  ///
+  /// See [`Spline::sample_many`] for further details.
+  pub fn sample_many(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let mut hint = None;
+
+    ts.iter()
+      .map(|&t| sample_hinted(self.keys, &mut hint, t))
+      .collect()
+  }
+
+  /// Sample the keys at many ascending times at once.
+  ///
+  /// See [`Spline::sample_sorted`] for further details.
+  pub fn sample_sorted(&self, ts: &[T]) -> Vec<Option<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let keys = self.keys;
+    let mut segment = 0;
+
+    ts.iter()
+      .map(|&t| {
+        let i = match advance_lower_cp(keys, segment, t) {
+          Some(i) => i,
+          None => {
+            #[cfg(feature = "stats")]
+            crate::stats::record(|stats| stats.out_of_range += 1);
+
+            return None;
+          }
+        };
+
+        segment = i;
+        sample_segment(keys, i, t)
+      })
+      .collect()
+  }
+
+  /// Sample the keys at many times at once, writing the values into a caller-provided buffer.
+  ///
+  /// See [`Spline::sample_into`] for further details.
+  ///
+  /// # Panics
+  ///
+  /// If `ts` and `out` don’t have the same length.
+  pub fn sample_into(&self, ts: &[T], out: &mut [V]) -> usize
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+
+    let mut hint = None;
+    let mut written = 0;
+
+    for (&t, value) in ts.iter().zip(out) {
+      if let Some(sampled) = sample_hinted(self.keys, &mut hint, t) {
+        *value = sampled;
+        written += 1;
+      }
+    }
+
+    written
+  }
+
+  /// Sample the keys at many times at once with clamping, writing the values into a
+  /// caller-provided buffer.
+  ///
+  /// See [`Spline::clamped_sample_into`] for further details.
+  ///
+  /// # Panics
+  ///
+  /// If `ts` and `out` don’t have the same length.
+  pub fn clamped_sample_into(&self, ts: &[T], out: &mut [V]) -> usize
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(ts.len(), out.len(), "ts and out must have the same length");
+
+    let mut hint = None;
+    let mut written = 0;
+
+    for (&t, value) in ts.iter().zip(out) {
+      if let Some(sampled) = clamped_sample_hinted(self.keys, &mut hint, t) {
+        *value = sampled;
+        written += 1;
+      }
+    }
+
+    written
+  }
+
+  /// Sample the keys at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample_with_key(t).map(|sampled| sampled.value)
+  }
+
+  /// Sample the keys at a given time, returning `default` if they cannot be sampled.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample_or(&self, t: T, default: V) -> V
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample(t).unwrap_or(default)
+  }
+
+  /// Sample the keys at a given time, returning the interpolated value along with the unit
+  /// tangent of the curve at that point.
+  ///
+  /// See [`Spline::sample_with_tangent`] for further details.
+  pub fn sample_with_tangent(&self, t: T) -> Option<SampledWithTangent<V>>
+  where
+    T: Scalar,
+    V: Interpolate<T> + InnerSpace<T>,
+  {
+    let keys = self.keys;
+    let (i, [_, derivative, _]) = derivatives_at(keys, t)?;
+    let value = sample_segment(keys, i, t)?;
+    let norm = derivative.norm();
+    let tangent = if norm > T::zero() {
+      derivative.outer_div(norm)
+    } else {
+      derivative
+    };
+
+    Some(SampledWithTangent {
+      value,
+      tangent,
+      key: i,
+    })
+  }
+
+  /// Curvature of the curve at a given time.
+  ///
+  /// See [`Spline::curvature`] for further details.
+  pub fn curvature(&self, t: T) -> Option<T>
+  where
+    T: Scalar,
+    V: InnerSpace<T>,
+  {
+    let (_, [_, d1, d2]) = derivatives_at(self.keys, t)?;
+    let d1_2 = d1.dot(d1);
+
+    if d1_2 <= T::zero() {
+      return None;
+    }
+
+    let d1_d2 = d1.dot(d2);
+    let area_2 = d1_2 * d2.dot(d2) - d1_d2 * d1_d2;
+    let area_2 = if area_2 > T::zero() {
+      area_2
+    } else {
+      T::zero()
+    };
+
+    Some(area_2.sqrt() / (d1_2 * d1_2.sqrt()))
+  }
+
+  /// Sample the keys at a given time with clamping, returning the interpolated value along with
+  /// its associated key.
+  ///
+  /// See [`Spline::clamped_sample_with_key`] for further details.
+  pub fn clamped_sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let first = self.keys.first()?;
+    let last = self.keys.last()?;
+
+    self.sample_with_key(t).or_else(move || {
+      if t <= first.t {
+        Some(SampledWithKey {
+          value: first.value.clone(),
+          key: 0,
+        })
+      } else if t >= last.t {
+        Some(SampledWithKey {
+          value: last.value.clone(),
+          key: self.keys.len() - 1,
+        })
+      } else {
+        None
+      }
+    })
+  }
+
+  /// Sample the keys at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.clamped_sample_with_key(t).map(|sampled| sampled.value)
+  }
+
+  /// Copy the keys into a new [`Spline`].
+  pub fn to_spline(&self) -> Spline<T, V>
+  where
+    T: Copy,
+    V: Clone,
+  {
+    Spline(keys_from_vec(self.keys.to_vec()), None, None)
+  }
+}
+
+impl<'a, T, V> From<&'a Spline<T, V>> for SplineRef<'a, T, V> {
+  fn from(spline: &'a Spline<T, V>) -> Self {
+    spline.view()
+  }
+}
+
+/// A spline sharing its keys behind an [`Arc`].
+///
+/// Cloning an [`ArcSpline`] only increments a reference counter instead of copying the keys, so
+/// that a curve authored once can be given to many entities – or threads. The keys cannot be
+/// edited; convert back into a [`Spline`] to do so. Sampling follows the same rules as [`Spline`];
+/// see [`ArcSpline::view`] for the complete sampling API.
+///
+/// The fallback value and the tag of a [`Spline`] are not kept.
+#[derive(Debug)]
+pub struct ArcSpline<T, V> {
+  keys: Arc<[Key<T, V>]>,
+}
+
+impl<T, V> Clone for ArcSpline<T, V> {
+  fn clone(&self) -> Self {
+    ArcSpline {
+      keys: Arc::clone(&self.keys),
+    }
+  }
+}
+
+impl<T, V> ArcSpline<T, V> {
+  /// Create a new spline out of keys. The keys don’t have to be sorted.
+  pub fn from_vec(keys: Vec<Key<T, V>>) -> Self
+  where
+    T: PartialOrd,
+  {
+    Spline::from_vec(keys).into()
+  }
+
+  /// Retrieve the keys of the spline.
+  pub fn keys(&self) -> &[Key<T, V>] {
+    &self.keys
+  }
+
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Check whether two splines share the same keys.
+  pub fn ptr_eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.keys, &other.keys)
+  }
+
+  /// Borrow the keys of the spline as a [`SplineRef`].
+  pub fn view(&self) -> SplineRef<'_, T, V> {
+    SplineRef { keys: &self.keys }
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.view().sample(t)
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.view().clamped_sample(t)
+  }
+}
+
+impl<T, V> PartialEq for ArcSpline<T, V>
+where
+  T: PartialEq,
+  V: PartialEq,
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.keys == other.keys
+  }
+}
+
+impl<T, V> From<Spline<T, V>> for ArcSpline<T, V> {
+  fn from(spline: Spline<T, V>) -> Self {
+    ArcSpline {
+      keys: spline.0.into_iter().collect(),
+    }
+  }
+}
+
+impl<T, V> From<ArcSpline<T, V>> for Spline<T, V>
+where
+  T: Copy,
+  V: Clone,
+{
+  fn from(spline: ArcSpline<T, V>) -> Self {
+    // the keys are already sorted
+    Spline(keys_from_vec(spline.keys.to_vec()), None, None)
+  }
+}
+
+impl<'a, T, V> From<&'a ArcSpline<T, V>> for SplineRef<'a, T, V> {
+  fn from(spline: &'a ArcSpline<T, V>) -> Self {
+    spline.view()
+  }
+}
+
+// Compute the handle opposite to an edited one, around a key value, according to a constraint.
+fn constrained_handle<T, V>(constraint: TangentConstraint, value: V, edited: V, opposite: V) -> V
+where
+  T: Scalar,
+  V: InnerSpace<T>,
+{
+  match constraint {
+    TangentConstraint::Free => opposite,
+
+    TangentConstraint::Mirrored => value + value - edited,
+
+    TangentConstraint::Aligned => {
+      let dir = value - edited;
+      let len = dir.norm();
+
+      if len > T::zero() {
+        value + dir.outer_mul((opposite - value).norm() / len)
+      } else {
+        opposite
+      }
+    }
+  }
+}
+
+// Find the lower control point corresponding to a given time.
+// It has the property to have a timestamp smaller or equal to t. Single-key splines only have a
+// lower control point at the exact time of their key.
+pub(crate) fn search_lower_cp<T, V>(cps: &[Key<T, V>], t: T) -> Option<usize>
+where
+  T: PartialOrd,
+{
+  let len = cps.len();
+  if len == 1 && cps[0].t == t {
+    return Some(0);
+  }
+  if len < 2 {
+    return None;
+  }
+  match cps.binary_search_by(|key| key.t.partial_cmp(&t).unwrap()) {
+    Err(i) if i >= len => None,
+    Err(0) => None,
+    Err(i) => Some(i - 1),
+    Ok(i) if i == len - 1 => None,
+    Ok(i) => Some(i),
+  }
+}
+
+// Find the lower control point of t among the segment starting at the key at index i and its
+// neighbors. This is O(1) and is meant to be tried before search_lower_cp when t is known to be close
+// to a previous lookup.
+pub(crate) fn search_lower_cp_near<T, V>(cps: &[Key<T, V>], i: usize, t: T) -> Option<usize>
+where
+  T: PartialOrd,
+{
+  [Some(i), i.checked_add(1), i.checked_sub(1)]
+    .into_iter()
+    .flatten()
+    .find(|&j| j + 1 < cps.len() && cps[j].t <= t && t < cps[j + 1].t)
+}
+
+// Find the lower control point of t by moving forward from the segment starting at the key at index
+// i. This is amortized O(1) when t only increases; it falls back to search_lower_cp otherwise.
+fn advance_lower_cp<T, V>(cps: &[Key<T, V>], mut i: usize, t: T) -> Option<usize>
+where
+  T: PartialOrd,
+{
+  let len = cps.len();
+
+  if len < 2 || i + 1 >= len || t < cps[i].t {
+    return search_lower_cp(cps, t);
+  }
+
+  while i + 1 < len && cps[i + 1].t <= t {
+    i += 1;
+  }
+
+  if i + 1 < len {
+    Some(i)
+  } else {
+    None
+  }
+}
+
+// Sample at t, first trying the segment of the previous sample (if any) and its neighbors before
+// falling back to a binary search.
+pub(crate) fn sample_hinted<T, V>(keys: &[Key<T, V>], hint: &mut Option<usize>, t: T) -> Option<V>
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  let near = hint.and_then(|i| search_lower_cp_near(keys, i, t));
+  let i = match near.or_else(|| search_lower_cp(keys, t)) {
+    Some(i) => i,
+    None => {
+      #[cfg(feature = "stats")]
+      crate::stats::record(|stats| stats.out_of_range += 1);
+
+      return None;
+    }
+  };
+
+  *hint = Some(i);
+  sample_segment(keys, i, t)
+}
+
+// Clamped version of sample_hinted.
+pub(crate) fn clamped_sample_hinted<T, V>(
+  keys: &[Key<T, V>],
+  hint: &mut Option<usize>,
+  t: T,
+) -> Option<V>
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  let first = keys.first()?;
+  let last = keys.last()?;
+
+  if t <= first.t {
+    Some(first.value.clone())
+  } else if t >= last.t {
+    Some(last.value.clone())
+  } else {
+    sample_hinted(keys, hint, t)
+  }
+}
+
+// Find the segment of t and compute the value, first and second derivatives of the curve at t, with
+// respect to the sampling value.
+pub(crate) fn derivatives_at<T, V>(keys: &[Key<T, V>], t: T) -> Option<(usize, [V; 3])>
+where
+  T: Scalar,
+  V: Linear<T>,
+{
+  let i = search_lower_cp(keys, t)?;
+  let cp0 = &keys[i];
+  let cp1 = keys.get(i + 1)?;
+  let dt = cp1.t - cp0.t;
+  let nt = t.normalize(cp0.t, cp1.t);
+  let [value, d1, d2] = Shape::new(keys, i)?.derivatives(nt);
+
+  Some((i, [value, d1.outer_div(dt), d2.outer_div(dt * dt)]))
+}
+
+// Keys addressed by index, which segments can be sampled from without materializing [`Key`]s –
+// keys stored in separate arrays, in a ring buffer or compressed, for instance.
+pub(crate) trait IndexedKeys<T, V> {
+  // Number of keys.
+  fn len(&self) -> usize;
+
+  // Sampling value of the key at index i.
+  fn t(&self, i: usize) -> T;
+
+  // Carried value of the key at index i.
+  fn value(&self, i: usize) -> V;
+
+  // Call f with the interpolation mode of the key at index i – or the one with which it is reached,
+  // if incoming is true (see Key::incoming).
+  fn with_interpolation<R>(
+    &self,
+    i: usize,
+    incoming: bool,
+    f: impl FnOnce(&Interpolation<T, V>) -> R,
+  ) -> R;
+}
+
+impl<T, V> IndexedKeys<T, V> for [Key<T, V>]
+where
+  T: Copy,
+  V: Clone,
+{
+  fn len(&self) -> usize {
+    <[_]>::len(self)
+  }
+
+  fn t(&self, i: usize) -> T {
+    self[i].t
+  }
+
+  fn value(&self, i: usize) -> V {
+    self[i].value.clone()
+  }
+
+  fn with_interpolation<R>(
+    &self,
+    i: usize,
+    incoming: bool,
+    f: impl FnOnce(&Interpolation<T, V>) -> R,
+  ) -> R {
+    if incoming {
+      f(self[i].incoming())
+    } else {
+      f(&self[i].interpolation)
+    }
+  }
+}
+
+// Sample the segment starting at the key at index i, i.e. the lower control point of t. If that key
+// is the only key of the spline, its value is returned.
+pub(crate) fn sample_segment<T, V>(keys: &[Key<T, V>], i: usize, t: T) -> Option<V>
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  sample_indexed_segment(keys, i, t)
+}
+
+// Sample the segment starting at the key at index i at the normalized time nt. That key must not be
+// the last one.
+pub(crate) fn sample_segment_normalized<T, V>(keys: &[Key<T, V>], i: usize, nt: T) -> Option<V>
+where
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  sample_indexed_segment_normalized(keys, i, nt)
+}
+
+// Same as sample_segment, for any IndexedKeys.
+pub(crate) fn sample_indexed_segment<K, T, V>(keys: &K, i: usize, t: T) -> Option<V>
+where
+  K: IndexedKeys<T, V> + ?Sized,
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  if keys.len() == 1 {
+    return Some(keys.value(i));
+  }
+
+  let nt = t.normalize(keys.t(i), keys.t(i + 1));
+  sample_indexed_segment_normalized(keys, i, nt)
+}
+
+// Same as sample_segment_normalized, for any IndexedKeys. Only the values of the keys the segment
+// depends on are cloned.
+pub(crate) fn sample_indexed_segment_normalized<K, T, V>(keys: &K, i: usize, nt: T) -> Option<V>
+where
+  K: IndexedKeys<T, V> + ?Sized,
+  T: Interpolator,
+  V: Interpolate<T>,
+{
+  keys.with_interpolation(i, false, |interpolation| {
+    #[cfg(feature = "stats")]
+    crate::stats::record_sample(interpolation);
+
+    match interpolation {
+      Interpolation::Step(threshold) => {
+        let value = V::step(nt, *threshold, keys.value(i), keys.value(i + 1));
+
+        Some(value)
+      }
+
+      Interpolation::Linear => {
+        let value = V::lerp(nt, keys.value(i), keys.value(i + 1));
+
+        Some(value)
+      }
+
+      Interpolation::Cosine => {
+        let value = V::cosine(nt, keys.value(i), keys.value(i + 1));
+
+        Some(value)
+      }
+
+      Interpolation::CatmullRom => {
+        // We need at least four points for Catmull Rom; ensure we have them, otherwise, return
+        // None.
+        if i == 0 || i >= keys.len() - 2 {
+          None
+        } else {
+          let value = V::cubic_hermite(
+            nt,
+            (keys.t(i - 1), keys.value(i - 1)),
+            (keys.t(i), keys.value(i)),
+            (keys.t(i + 1), keys.value(i + 1)),
+            (keys.t(i + 2), keys.value(i + 2)),
+          );
+
+          Some(value)
+        }
+      }
+
+      Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => {
+        // We need to check the next control point to see whether we want quadratic or cubic Bezier.
+        let (a, u, b) = (keys.value(i), u.clone(), keys.value(i + 1));
+        let value = keys.with_interpolation(i + 1, true, |incoming| match incoming {
+          Interpolation::Bezier(v) => V::cubic_bezier_mirrored(nt, a, u, v.clone(), b),
+
+          Interpolation::StrokeBezier(v, _) => V::cubic_bezier(nt, a, u, v.clone(), b),
+
+          _ => V::quadratic_bezier(nt, a, u, b),
+        });
+
+        Some(value)
+      }
+    }
+  })
 }
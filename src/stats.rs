@@ -0,0 +1,114 @@
+//! Sampling statistics.
+//!
+//! When the `"stats"` feature is enabled, the crate counts – per thread – how sampling is used:
+//! how many samples were taken per interpolation mode, how often [`Cursor`]s could reuse their
+//! cached segment and how many queries fell outside of splines. This is meant for profiling, to
+//! decide whether switching to cursors or baking curves would pay off.
+//!
+//! Collection can be switched on and off at runtime with [`set_enabled`]; it is enabled by
+//! default.
+//!
+//! ```
+//! # use splines::{Interpolation, Key, Spline};
+//! let spline = Spline::from_vec(vec![
+//!   Key::new(0., 0., Interpolation::Linear),
+//!   Key::new(1., 10., Interpolation::default()),
+//! ]);
+//!
+//! splines::stats::reset();
+//! spline.sample(0.5);
+//! spline.sample(2.);
+//!
+//! let stats = splines::stats::snapshot();
+//! assert_eq!(stats.linear_samples, 1);
+//! assert_eq!(stats.out_of_range, 1);
+//! ```
+//!
+//! [`Cursor`]: crate::cursor::Cursor
+
+use crate::interpolation::Interpolation;
+use std::cell::Cell;
+
+/// Statistics collected on the current thread.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Stats {
+  /// Number of samples taken on [`Interpolation::Step`] segments.
+  pub step_samples: u64,
+  /// Number of samples taken on [`Interpolation::Linear`] segments.
+  pub linear_samples: u64,
+  /// Number of samples taken on [`Interpolation::Cosine`] segments.
+  pub cosine_samples: u64,
+  /// Number of samples taken on [`Interpolation::CatmullRom`] segments.
+  pub catmull_rom_samples: u64,
+  /// Number of samples taken on [`Interpolation::Bezier`] segments.
+  pub bezier_samples: u64,
+  /// Number of samples taken on [`Interpolation::StrokeBezier`] segments.
+  pub stroke_bezier_samples: u64,
+  /// Number of times a cursor moved and could reuse its cached segment.
+  pub cursor_hits: u64,
+  /// Number of times a cursor moved and had to look its segment up.
+  pub cursor_misses: u64,
+  /// Number of sampling queries outside of a spline.
+  pub out_of_range: u64,
+}
+
+impl Stats {
+  /// Total number of samples taken, all interpolation modes included.
+  pub fn total_samples(&self) -> u64 {
+    self.step_samples
+      + self.linear_samples
+      + self.cosine_samples
+      + self.catmull_rom_samples
+      + self.bezier_samples
+      + self.stroke_bezier_samples
+  }
+}
+
+thread_local! {
+  static ENABLED: Cell<bool> = const { Cell::new(true) };
+  static STATS: Cell<Stats> = Cell::new(Stats::default());
+}
+
+/// Get the statistics collected so far on the current thread.
+pub fn snapshot() -> Stats {
+  STATS.with(Cell::get)
+}
+
+/// Reset the statistics of the current thread.
+pub fn reset() {
+  STATS.with(|stats| stats.set(Stats::default()));
+}
+
+/// Enable or disable the collection of statistics on the current thread.
+pub fn set_enabled(enabled: bool) {
+  ENABLED.with(|cell| cell.set(enabled));
+}
+
+/// Check whether statistics are collected on the current thread.
+pub fn is_enabled() -> bool {
+  ENABLED.with(Cell::get)
+}
+
+/// Update the statistics of the current thread, if enabled.
+pub(crate) fn record(f: impl FnOnce(&mut Stats)) {
+  if is_enabled() {
+    STATS.with(|cell| {
+      let mut stats = cell.get();
+      f(&mut stats);
+      cell.set(stats);
+    });
+  }
+}
+
+/// Count a sample taken on a segment with the given interpolation mode.
+pub(crate) fn record_sample<T, V>(interpolation: &Interpolation<T, V>) {
+  record(|stats| match interpolation {
+    Interpolation::Step(_) => stats.step_samples += 1,
+    Interpolation::Linear => stats.linear_samples += 1,
+    Interpolation::Cosine => stats.cosine_samples += 1,
+    Interpolation::CatmullRom => stats.catmull_rom_samples += 1,
+    Interpolation::Bezier(_) => stats.bezier_samples += 1,
+    Interpolation::StrokeBezier(..) => stats.stroke_bezier_samples += 1,
+  });
+}
@@ -0,0 +1,185 @@
+//! Splines fed with a stream of keys.
+//!
+//! Live data, such as telemetry, comes as a stream of keys of ever increasing sampling values, of
+//! which only the most recent ones matter. A [`StreamingSpline`] stores its keys in a ring buffer:
+//! new keys are pushed at the end, and the oldest ones are evicted once there are too many of them
+//! or once they get too old, so that the spline never has to be rebuilt.
+
+use crate::interpolate::{Interpolate, Interpolator, Scalar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::{sample_indexed_segment, IndexedKeys};
+#[cfg(not(feature = "std"))]
+use alloc::collections::vec_deque::{Iter, VecDeque};
+#[cfg(feature = "std")]
+use std::collections::vec_deque::{Iter, VecDeque};
+
+/// A spline keeping a bounded, sliding window of keys.
+///
+/// Sampling follows the same rules as [`Spline`](crate::spline::Spline) on the keys currently held.
+#[derive(Clone, Debug)]
+pub struct StreamingSpline<T, V> {
+  keys: VecDeque<Key<T, V>>,
+  max_len: usize,
+  window: Option<T>,
+}
+
+impl<T, V> StreamingSpline<T, V> {
+  /// Create an empty spline keeping at most `max_len` keys.
+  ///
+  /// # Panics
+  ///
+  /// If `max_len` is zero.
+  pub fn new(max_len: usize) -> Self {
+    assert!(max_len > 0, "a streaming spline must keep at least one key");
+
+    StreamingSpline {
+      keys: VecDeque::with_capacity(max_len),
+      max_len,
+      window: None,
+    }
+  }
+
+  /// Create an empty spline keeping at most `max_len` keys, and only the keys lying in the last
+  /// `window` of sampling values – the most recent key being always kept.
+  ///
+  /// # Panics
+  ///
+  /// If `max_len` is zero.
+  pub fn with_window(max_len: usize, window: T) -> Self {
+    StreamingSpline {
+      window: Some(window),
+      ..Self::new(max_len)
+    }
+  }
+
+  /// Maximum number of keys kept.
+  pub fn max_len(&self) -> usize {
+    self.max_len
+  }
+
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Iterate over the keys, from the oldest to the most recent.
+  pub fn iter(&self) -> Iter<'_, Key<T, V>> {
+    self.keys.iter()
+  }
+
+  /// Remove all keys.
+  pub fn clear(&mut self) {
+    self.keys.clear();
+  }
+
+  /// Push a key, evicting the keys that don’t fit anymore.
+  ///
+  /// Keys are expected to be pushed by ascending sampling value. A key older than the most recent
+  /// one is still inserted where it belongs, which is slower.
+  pub fn push(&mut self, key: Key<T, V>)
+  where
+    T: Scalar,
+  {
+    match self.keys.back() {
+      Some(last) if key.t < last.t => {
+        let index = self.keys.partition_point(|k| k.t <= key.t);
+        self.keys.insert(index, key);
+      }
+
+      _ => self.keys.push_back(key),
+    }
+
+    while self.keys.len() > self.max_len {
+      self.keys.pop_front();
+    }
+
+    if let (Some(window), Some(last)) = (self.window, self.keys.back()) {
+      let oldest = last.t - window;
+
+      while self.keys.front().is_some_and(|key| key.t < oldest) {
+        self.keys.pop_front();
+      }
+    }
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`](crate::spline::Spline::sample) for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let len = self.keys.len();
+
+    if len == 1 && self.keys[0].t == t {
+      return Some(self.keys[0].value.clone());
+    }
+
+    // index of the first key after t
+    let next = self.keys.partition_point(|key| key.t <= t);
+
+    if next == 0 || next >= len {
+      return None;
+    }
+
+    // the keys might not be contiguous in the ring buffer, so they are addressed by index
+    sample_indexed_segment(&self.keys, next - 1, t)
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`](crate::spline::Spline::clamped_sample) for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let (first, last) = (self.keys.front()?, self.keys.back()?);
+
+    if t <= first.t {
+      Some(first.value.clone())
+    } else if t >= last.t {
+      Some(last.value.clone())
+    } else {
+      self.sample(t)
+    }
+  }
+}
+
+impl<T, V> IndexedKeys<T, V> for VecDeque<Key<T, V>>
+where
+  T: Copy,
+  V: Clone,
+{
+  fn len(&self) -> usize {
+    VecDeque::len(self)
+  }
+
+  fn t(&self, i: usize) -> T {
+    self[i].t
+  }
+
+  fn value(&self, i: usize) -> V {
+    self[i].value.clone()
+  }
+
+  fn with_interpolation<R>(
+    &self,
+    i: usize,
+    incoming: bool,
+    f: impl FnOnce(&Interpolation<T, V>) -> R,
+  ) -> R {
+    if incoming {
+      f(self[i].incoming())
+    } else {
+      f(&self[i].interpolation)
+    }
+  }
+}
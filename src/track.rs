@@ -0,0 +1,118 @@
+//! Semantic tags of splines.
+//!
+//! A spline is just a curve of values; nothing tells whether those values are positions, rotations
+//! or colors. When assembling clips out of imported data, such information is needed to handle
+//! every track properly – for instance, to pick the right interpolation or to route the sampled
+//! values to the right property. A [`TrackKind`] can be attached to a spline to carry it (see
+//! [`Spline::set_kind`]), and [`SplineBuilder::kind`] uses it to pick the default interpolation
+//! mode of keys (see [`TrackKind::default_interpolation`]).
+//!
+//! Splines are serialized as bare arrays of keys, so their tag is not serialized. With the `serde`
+//! feature, a [`TaggedSpline`] serializes a spline along with its tag instead.
+//!
+//! [`Spline::set_kind`]: crate::spline::Spline::set_kind
+//! [`SplineBuilder::kind`]: crate::builder::SplineBuilder::kind
+
+use crate::interpolation::Interpolation;
+#[cfg(any(feature = "serialization", feature = "serde"))]
+use crate::spline::Spline;
+#[cfg(any(feature = "serialization", feature = "serde"))]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// What the values of a spline represent.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+  any(feature = "serialization", feature = "serde"),
+  derive(Deserialize, Serialize),
+  serde(rename_all = "snake_case")
+)]
+pub enum TrackKind {
+  /// Translations, or positions.
+  Translation,
+  /// Rotations, such as quaternions or angles.
+  Rotation,
+  /// Scales.
+  Scale,
+  /// Any scalar property, such as weights or intensities.
+  Scalar,
+  /// Colors.
+  Color,
+  /// Application-defined kind.
+  Custom(u32),
+}
+
+impl TrackKind {
+  /// Interpolation mode to use by default for keys of splines of this kind.
+  ///
+  /// - Translations use [`Interpolation::Cosine`], easing in and out of every position.
+  /// - Rotations use [`Interpolation::Linear`], which doesn’t overshoot – overshooting quaternions
+  ///   would rotate past the keys. Normalize sampled quaternions to get a normalized linear
+  ///   interpolation.
+  /// - Colors use [`Interpolation::Linear`], which stays in the gamut spanned by the keys.
+  /// - Scales, scalars and custom kinds use [`Interpolation::default`].
+  pub fn default_interpolation<T, V>(self) -> Interpolation<T, V> {
+    match self {
+      TrackKind::Translation => Interpolation::Cosine,
+      TrackKind::Rotation | TrackKind::Color => Interpolation::Linear,
+      _ => Interpolation::default(),
+    }
+  }
+}
+
+/// A spline serialized along with its semantic tag.
+///
+/// It (de)serializes as an object with a `kind` field – omitted when the spline has no tag – and a
+/// `keys` field holding the spline, serialized the same way as [`Spline`]:
+///
+/// ```json
+/// { "kind": "rotation", "keys": [{ "t": 0, "value": 1, "interpolation": "linear" }] }
+/// ```
+#[cfg(any(feature = "serialization", feature = "serde"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaggedSpline<T, V>(pub Spline<T, V>);
+
+// Serialized form of TaggedSpline.
+#[cfg(any(feature = "serialization", feature = "serde"))]
+#[derive(Deserialize, Serialize)]
+struct TaggedRepr<S> {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  kind: Option<TrackKind>,
+  keys: S,
+}
+
+#[cfg(any(feature = "serialization", feature = "serde"))]
+impl<T, V> Serialize for TaggedSpline<T, V>
+where
+  T: Serialize,
+  V: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    TaggedRepr {
+      kind: self.0.kind(),
+      keys: &self.0,
+    }
+    .serialize(serializer)
+  }
+}
+
+#[cfg(any(feature = "serialization", feature = "serde"))]
+impl<'de, T, V> Deserialize<'de> for TaggedSpline<T, V>
+where
+  T: Deserialize<'de>,
+  V: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let TaggedRepr { kind, keys } = TaggedRepr::<Spline<T, V>>::deserialize(deserializer)?;
+    let mut spline = keys;
+    spline.set_kind(kind);
+
+    Ok(TaggedSpline(spline))
+  }
+}
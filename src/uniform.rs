@@ -0,0 +1,138 @@
+//! Splines with regularly spaced keys.
+//!
+//! Baked animation tracks and sampled signals have their keys at a constant interval. A
+//! [`UniformSpline`] only stores the time of its first key and that interval, so that the segment
+//! to sample is computed arithmetically, in *O(1)*, instead of being searched for.
+
+use crate::interpolate::{Interpolate, Scalar};
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::{sample_segment_normalized, Spline};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A spline whose keys are regularly spaced in time.
+///
+/// The key at index `i` is at the sampling value `start + i * dt`. Sampling follows the same rules
+/// as [`Spline`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniformSpline<T, V> {
+  start: T,
+  dt: T,
+  keys: Vec<Key<T, V>>,
+}
+
+impl<T, V> UniformSpline<T, V> {
+  /// Create a uniform spline out of values, spaced by `dt` from `start`, all using `interpolation`.
+  ///
+  /// `dt` is expected to be positive.
+  pub fn new(start: T, dt: T, values: Vec<V>, interpolation: Interpolation<T, V>) -> Self
+  where
+    T: Scalar,
+    V: Clone,
+  {
+    let keys = values
+      .into_iter()
+      .enumerate()
+      .map(|(i, value)| {
+        Key::new(
+          start + dt * T::from_f64(i as f64),
+          value,
+          interpolation.clone(),
+        )
+      })
+      .collect();
+
+    UniformSpline { start, dt, keys }
+  }
+
+  /// Sampling value of the first key.
+  pub fn start(&self) -> T
+  where
+    T: Copy,
+  {
+    self.start
+  }
+
+  /// Interval between two consecutive keys.
+  pub fn dt(&self) -> T
+  where
+    T: Copy,
+  {
+    self.dt
+  }
+
+  /// Number of keys.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Check whether the spline has no key.
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Retrieve the keys of the spline.
+  pub fn keys(&self) -> &[Key<T, V>] {
+    &self.keys
+  }
+
+  /// Sample the spline at a given time.
+  ///
+  /// See [`Spline::sample`] for further details.
+  pub fn sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    if let [ref key] = self.keys[..] {
+      return if key.t == t {
+        Some(key.value.clone())
+      } else {
+        None
+      };
+    }
+
+    if t < self.start {
+      return None;
+    }
+
+    let x = (t - self.start) / self.dt;
+    let i = x.floor();
+    let index = i.to_f64() as usize;
+
+    if index + 1 >= self.keys.len() {
+      return None;
+    }
+
+    sample_segment_normalized(&self.keys, index, x - i)
+  }
+
+  /// Sample the spline at a given time with clamping.
+  ///
+  /// See [`Spline::clamped_sample`] for further details.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Scalar,
+    V: Interpolate<T>,
+  {
+    let (first, last) = (self.keys.first()?, self.keys.last()?);
+
+    if t <= first.t {
+      Some(first.value.clone())
+    } else if t >= last.t {
+      Some(last.value.clone())
+    } else {
+      self.sample(t)
+    }
+  }
+}
+
+impl<T, V> From<UniformSpline<T, V>> for Spline<T, V>
+where
+  T: PartialOrd,
+{
+  fn from(spline: UniformSpline<T, V>) -> Self {
+    Spline::from_vec(spline.keys)
+  }
+}
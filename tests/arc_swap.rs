@@ -0,0 +1,60 @@
+#![cfg(feature = "arc-swap")]
+
+use splines::shared::SharedSpline;
+use splines::{Interpolation, Key, Spline};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+fn constant(value: f64) -> Spline<f64, f64> {
+  Spline::from_vec(vec![
+    Key::new(0., value, Interpolation::Linear),
+    Key::new(1., value, Interpolation::Linear),
+  ])
+}
+
+#[test]
+fn publish() {
+  let shared = SharedSpline::new(constant(1.));
+  let before = shared.load();
+
+  assert_eq!(shared.sample(0.5), Some(1.));
+
+  shared.publish(constant(2.));
+  assert_eq!(shared.sample(0.5), Some(2.));
+  assert_eq!(shared.clamped_sample(3.), Some(2.));
+  assert_eq!(before.sample(0.5), Some(1.));
+
+  shared.update(|spline| {
+    let mut spline = spline.clone();
+    spline.add(Key::new(2., 4., Interpolation::Linear));
+    spline
+  });
+  assert_eq!(shared.sample(1.5), Some(3.));
+}
+
+#[test]
+fn concurrent_sampling() {
+  let shared = SharedSpline::from(constant(0.));
+  let done = AtomicBool::new(false);
+
+  thread::scope(|scope| {
+    scope.spawn(|| {
+      let mut last = 0.;
+
+      while !done.load(Ordering::Acquire) {
+        // a spline is never seen partially published, and publications are seen in order
+        let value = shared.sample(0.5).unwrap();
+        assert!(value >= last);
+        last = value;
+      }
+    });
+
+    for i in 1..=100 {
+      shared.publish(constant(i as f64));
+    }
+
+    done.store(true, Ordering::Release);
+  });
+
+  assert_eq!(shared.sample(0.5), Some(100.));
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "bytemuck")]
+
+use splines::pod::{PodModes, BEZIER, NONE, STEP, STROKE_BEZIER};
+use splines::{Interpolation, Key, Spline, TangentConstraint};
+
+#[test]
+fn round_trip() {
+  let mut bezier = Key::new(2., 4., Interpolation::Bezier(5.));
+  bezier.tangent_constraint = TangentConstraint::Mirrored;
+  let mut stroke = Key::new(3., 2., Interpolation::StrokeBezier(1., 3.));
+  stroke.in_interpolation = Some(Interpolation::Step(0.25));
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    bezier,
+    stroke,
+    Key::new(4., 3.0f32, Interpolation::Cosine),
+  ]);
+  let pod = spline.to_pod();
+
+  assert_eq!(pod.len(), 5);
+  assert_eq!(pod.ts, vec![0., 1., 2., 3., 4.]);
+  assert_eq!(
+    pod.modes[0],
+    PodModes {
+      interpolation: STEP,
+      in_interpolation: NONE,
+      tangent_constraint: 0
+    }
+  );
+  assert_eq!(pod.modes[2].interpolation, BEZIER);
+  assert_eq!(pod.modes[2].tangent_constraint, 2);
+  assert_eq!(pod.modes[3].interpolation, STROKE_BEZIER);
+  assert_eq!(pod.modes[3].in_interpolation, STEP);
+  assert_eq!(pod.thresholds[3], [0., 0.25]);
+  assert_eq!(pod.tangents[2], [5., 0., 0., 0.]);
+  assert_eq!(pod.tangents[3], [1., 3., 0., 0.]);
+
+  // 3 u32 per key
+  assert_eq!(bytemuck::cast_slice::<_, u8>(&pod.modes).len(), 5 * 12);
+
+  assert_eq!(pod.to_spline(), Some(spline));
+}
+
+#[test]
+fn invalid() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1.0f32, Interpolation::Linear),
+  ]);
+
+  let mut pod = spline.to_pod();
+  pod.modes[1].interpolation = 42;
+  assert_eq!(pod.to_spline(), None);
+
+  let mut pod = spline.to_pod();
+  pod.values.pop();
+  assert_eq!(pod.to_spline(), None);
+}
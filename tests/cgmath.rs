@@ -1,6 +1,7 @@
 #![cfg(feature = "cgmath")]
 
 use cgmath as cg;
+use cgmath::InnerSpace as _;
 use splines::{Interpolation, Key, Spline};
 
 #[test]
@@ -41,3 +42,333 @@ fn stroke_bezier_straight() {
   assert!(approx_eq!(f32, spline.clamped_sample(4.0).unwrap().y, 1.));
   assert!(approx_eq!(f32, spline.clamped_sample(5.0).unwrap().y, 1.));
 }
+
+#[test]
+fn sample_with_tangent_bezier() {
+  use float_cmp::approx_eq;
+
+  let keys = vec![
+    Key::new(
+      0.0,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(
+      1.0,
+      cg::Vector2::new(2., 0.),
+      Interpolation::Bezier(cg::Vector2::new(3., -1.)),
+    ),
+  ];
+  let spline = Spline::from_vec(keys);
+
+  // symmetric curve: the tangent is horizontal at the middle
+  let sampled = spline.sample_with_tangent(0.5).unwrap();
+  assert!(approx_eq!(f32, sampled.value.x, 1.));
+  assert!(approx_eq!(f32, sampled.tangent.x, 1.));
+  assert!(approx_eq!(f32, sampled.tangent.y, 0.));
+}
+
+#[test]
+fn curvature() {
+  use float_cmp::approx_eq;
+
+  let keys = vec![
+    Key::new(
+      0.0,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(1.0, cg::Vector2::new(2., 0.), Interpolation::Linear),
+    Key::new(2.0, cg::Vector2::new(4., 0.), Interpolation::Linear),
+  ];
+  let spline = Spline::from_vec(keys);
+
+  assert!(approx_eq!(f32, spline.curvature(0.5).unwrap(), 1.));
+  assert!(approx_eq!(f32, spline.curvature(1.5).unwrap(), 0.));
+  assert_eq!(spline.curvature(2.), None);
+}
+
+#[test]
+fn arc_length_quadratic_bezier() {
+  let keys = vec![
+    Key::new(
+      0.0,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(1.0, cg::Vector2::new(2., 0.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+  let expected = 0.5 * (8f64.sqrt() + 2. * (1. + 2f64.sqrt()).ln());
+
+  assert!((spline.arc_length(0., 1.).unwrap() - expected).abs() < 1e-6);
+}
+
+#[test]
+fn sample_at_distance() {
+  let keys = vec![
+    Key::new(0.0, cg::Vector2::new(0., 0.), Interpolation::Linear),
+    Key::new(1.0, cg::Vector2::new(1., 0.), Interpolation::Linear),
+    Key::new(2.0, cg::Vector2::new(1., 3.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+
+  let p = spline.sample_at_distance(2.5).unwrap();
+  assert!((p.x - 1.).abs() < 1e-6);
+  assert!((p.y - 1.5).abs() < 1e-6);
+
+  let end = spline.sample_at_distance(4.).unwrap();
+  assert!((end.y - 3.).abs() < 1e-6);
+  assert_eq!(spline.sample_at_distance(4.5), None);
+}
+
+#[test]
+fn sample_at_distance_constant_speed() {
+  // a cubic Bézier with uneven control points has a non-uniform speed
+  let keys = vec![
+    Key::new(
+      0.0,
+      cg::Vector2::new(0., 0.),
+      Interpolation::StrokeBezier(cg::Vector2::new(0., 0.), cg::Vector2::new(0.1, 0.)),
+    ),
+    Key::new(
+      1.0,
+      cg::Vector2::new(4., 0.),
+      Interpolation::StrokeBezier(cg::Vector2::new(3.9, 0.), cg::Vector2::new(4., 0.)),
+    ),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+
+  assert!((spline.sample_at_distance(1.).unwrap().x - 1.).abs() < 1e-5);
+  assert!((spline.sample_at_distance(3.).unwrap().x - 3.).abs() < 1e-5);
+}
+
+#[test]
+fn arc_length_table() {
+  use splines::arc_length::ArcLengthTable;
+
+  let keys = vec![
+    Key::new(0.0, cg::Vector2::new(0., 0.), Interpolation::Linear),
+    Key::new(1.0, cg::Vector2::new(1., 0.), Interpolation::Linear),
+    Key::new(3.0, cg::Vector2::new(1., 3.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+  let table = ArcLengthTable::new(&spline, 8).unwrap();
+
+  assert!((table.total_length() - 4.).abs() < 1e-9);
+  assert!((table.length_at_t(2.).unwrap() - 2.5).abs() < 1e-9);
+  assert!((table.t_at_length(2.5).unwrap() - 2.).abs() < 1e-9);
+  assert!((table.t_at_length(0.5).unwrap() - 0.5).abs() < 1e-9);
+  assert_eq!(table.t_at_length(5.), None);
+  assert_eq!(table.length_at_t(-1.), None);
+}
+
+#[test]
+fn flatten() {
+  use cgmath::InnerSpace;
+
+  let keys = vec![
+    Key::new(0.0, cg::Vector2::new(0., 0.), Interpolation::Linear),
+    Key::new(
+      1.0,
+      cg::Vector2::new(1., 0.),
+      Interpolation::Bezier(cg::Vector2::new(2., 1.)),
+    ),
+    Key::new(2.0, cg::Vector2::new(3., 0.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+
+  for &tolerance in &[1e-1, 1e-2, 1e-3] {
+    let points = spline.flatten(tolerance);
+
+    assert_eq!(points[0], cg::Vector2::new(0., 0.));
+    assert_eq!(points[points.len() - 1], cg::Vector2::new(3., 0.));
+
+    // every sample of the curve lies close to the polyline
+    for i in 0..=200 {
+      let p = spline
+        .sample(1. + i as f64 / 200.)
+        .unwrap_or(cg::Vector2::new(3., 0.));
+      let distance = points
+        .windows(2)
+        .map(|w| {
+          let d = w[1] - w[0];
+          let u = ((p - w[0]).dot(d) / d.magnitude2()).clamp(0., 1.);
+          (w[0] + d * u - p).magnitude()
+        })
+        .fold(f64::INFINITY, f64::min);
+
+      assert!(distance <= tolerance * 1.01, "{distance} > {tolerance}");
+    }
+  }
+
+  assert!(spline.flatten(1e-3).len() > spline.flatten(1e-1).len());
+}
+
+#[test]
+fn css_ease() {
+  // CSS’ ease timing function: cubic-bezier(0.25, 0.1, 0.25, 1)
+  let keys = vec![
+    Key::new(
+      0.,
+      cg::Vector2::new(0., 0.),
+      Interpolation::StrokeBezier(cg::Vector2::new(0., 0.), cg::Vector2::new(0.25, 0.1)),
+    ),
+    Key::new(
+      1.,
+      cg::Vector2::new(1., 1.),
+      Interpolation::StrokeBezier(cg::Vector2::new(0.25, 1.), cg::Vector2::new(1., 1.)),
+    ),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+
+  assert!(spline.ease(0.).unwrap().abs() < 1e-9);
+  assert!((spline.ease(1.).unwrap() - 1.).abs() < 1e-9);
+  assert!((spline.ease(0.5).unwrap() - 0.802_403_387_7).abs() < 1e-6);
+  assert_eq!(spline.ease(1.5), None);
+}
+
+#[test]
+fn extrapolated_sample() {
+  let keys = vec![
+    Key::new(
+      0.,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(2., cg::Vector2::new(2., 0.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+
+  // quadratic Bézier: the tangents at the ends point towards the control point
+  let before = spline.extrapolated_sample(-1.).unwrap();
+  let after = spline.extrapolated_sample(3.).unwrap();
+  assert!((before - cg::Vector2::new(-1., -1.)).x.abs() < 1e-9);
+  assert!((before - cg::Vector2::new(-1., -1.)).y.abs() < 1e-9);
+  assert!((after - cg::Vector2::new(3., -1.)).x.abs() < 1e-9);
+  assert!((after - cg::Vector2::new(3., -1.)).y.abs() < 1e-9);
+  assert_eq!(spline.extrapolated_sample(1.), spline.sample(1.));
+  assert_eq!(
+    spline.extrapolated_sample(2.),
+    Some(cg::Vector2::new(2., 0.))
+  );
+}
+
+#[test]
+fn segment_aabb() {
+  let keys = vec![
+    Key::new(
+      0.,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(1., cg::Vector2::new(2., 0.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+  let aabb = spline.segment_aabb(0).unwrap();
+
+  assert!((aabb.min[0]).abs() < 1e-9 && (aabb.max[0] - 2.).abs() < 1e-9);
+  assert!((aabb.min[1]).abs() < 1e-9 && (aabb.max[1] - 0.5).abs() < 1e-9);
+  assert!(aabb.contains(1., 0.25));
+  assert!(!aabb.contains(1., 0.75));
+  assert_eq!(spline.segment_aabb(1), None);
+}
+
+#[test]
+fn frenet_and_transported_frames() {
+  // quarter of a circle-like arc in the XY plane
+  let keys = vec![
+    Key::new(
+      0.,
+      cg::Vector3::new(0., 0., 0.),
+      Interpolation::Bezier(cg::Vector3::new(1., 0., 0.)),
+    ),
+    Key::new(1., cg::Vector3::new(1., 1., 0.), Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+  let frame = spline.frenet_frame(0.).unwrap();
+
+  assert!((frame.tangent - cg::Vector3::new(1., 0., 0.)).magnitude() < 1e-9);
+  assert!((frame.normal - cg::Vector3::new(0., 1., 0.)).magnitude() < 1e-9);
+  assert!((frame.binormal - cg::Vector3::new(0., 0., 1.)).magnitude() < 1e-9);
+
+  let mut transported = frame;
+  for i in 1..=9 {
+    transported = spline
+      .transported_frame(&transported, i as f64 / 10.)
+      .unwrap();
+  }
+
+  let expected = spline.frenet_frame(0.9).unwrap();
+  assert!((transported.tangent - expected.tangent).magnitude() < 1e-9);
+  assert!((transported.binormal - expected.binormal).magnitude() < 1e-9);
+
+  // straight segments have no Frenet frame
+  let line = Spline::<f64, _>::from_vec(vec![
+    Key::new(0., cg::Vector3::new(0., 0., 0.), Interpolation::Linear),
+    Key::new(1., cg::Vector3::new(1., 0., 0.), Interpolation::Linear),
+  ]);
+  assert_eq!(line.frenet_frame(0.5), None);
+  assert!(line.transported_frame(&frame, 0.5).is_some());
+}
+
+#[test]
+fn intersect_segment() {
+  // parabola-like arch from (0, 0) to (2, 0), peaking at (1, 0.5)
+  let keys = vec![
+    Key::new(
+      0.,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(1., cg::Vector2::new(2., 0.), Interpolation::Linear),
+    Key::new(2., cg::Vector2::new(3., 0.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _>::from_vec(keys);
+
+  // horizontal line crossing the arch twice
+  let hits = spline.intersect_segment(cg::Vector2::new(-1., 0.375), cg::Vector2::new(3., 0.375));
+  assert_eq!(hits.len(), 2);
+  assert!((hits[0].t - 0.25).abs() < 1e-9 && (hits[1].t - 0.75).abs() < 1e-9);
+  assert!((hits[0].point - cg::Vector2::new(0.5, 0.375)).magnitude() < 1e-9);
+  assert!((hits[0].u - 0.375).abs() < 1e-9);
+
+  // the line segment stops before the second crossing
+  let hits = spline.intersect_segment(cg::Vector2::new(-1., 0.375), cg::Vector2::new(1., 0.375));
+  assert_eq!(hits.len(), 1);
+
+  // vertical line going through the shared key, found only once
+  let hits = spline.intersect_segment(cg::Vector2::new(2., -1.), cg::Vector2::new(2., 1.));
+  assert_eq!(hits.len(), 1);
+  assert!((hits[0].t - 1.).abs() < 1e-9);
+}
+
+#[test]
+fn intersect_spline() {
+  // arch from (0, 0) to (2, 0), peaking at (1, 0.5), crossed by a straight line at y = 0.375
+  let arch = Spline::<f64, _>::from_vec(vec![
+    Key::new(
+      0.,
+      cg::Vector2::new(0., 0.),
+      Interpolation::Bezier(cg::Vector2::new(1., 1.)),
+    ),
+    Key::new(1., cg::Vector2::new(2., 0.), Interpolation::default()),
+  ]);
+  let line = Spline::<f64, _>::from_vec(vec![
+    Key::new(0., cg::Vector2::new(-1., 0.375), Interpolation::Linear),
+    Key::new(4., cg::Vector2::new(3., 0.375), Interpolation::Linear),
+    Key::new(5., cg::Vector2::new(3., 10.), Interpolation::default()),
+  ]);
+  let hits = arch.intersect_spline(&line, 1e-9);
+
+  assert_eq!(hits.len(), 2);
+  assert!((hits[0].t - 0.25).abs() < 1e-6 && (hits[1].t - 0.75).abs() < 1e-6);
+  assert!((hits[0].u - 1.5).abs() < 1e-6 && (hits[1].u - 2.5).abs() < 1e-6);
+  assert!((hits[1].point - cg::Vector2::new(1.5, 0.375)).magnitude() < 1e-6);
+
+  let far = Spline::<f64, _>::from_vec(vec![
+    Key::new(0., cg::Vector2::new(0., 1.), Interpolation::Linear),
+    Key::new(1., cg::Vector2::new(2., 1.), Interpolation::default()),
+  ]);
+  assert!(arch.intersect_spline(&far, 1e-9).is_empty());
+}
@@ -0,0 +1,171 @@
+use splines::cursor::{Accumulator, Cursor, Direction, PlaybackEvent, SplineClock};
+use splines::{Interpolation, Key, Spline};
+
+fn spline() -> Spline<f32, f32> {
+  Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::Linear),
+    Key::new(3., 30., Interpolation::default()),
+  ])
+}
+
+#[test]
+fn cursor_forward_events() {
+  let spline = spline();
+  let mut cursor = Cursor::new(&spline, 0.);
+
+  let events: Vec<_> = cursor.advance(2.5).collect();
+  assert_eq!(
+    events,
+    vec![
+      PlaybackEvent::KeyCrossed {
+        key: 1,
+        direction: Direction::Forward
+      },
+      PlaybackEvent::KeyCrossed {
+        key: 2,
+        direction: Direction::Forward
+      },
+    ]
+  );
+  assert_eq!(cursor.t(), 2.5);
+  assert_eq!(cursor.sample(), Some(25.));
+}
+
+#[test]
+fn cursor_backward_events() {
+  let spline = spline();
+  let mut cursor = Cursor::new(&spline, 3.);
+  cursor.reverse();
+  cursor.set_speed(2.);
+
+  let events: Vec<_> = cursor.advance(1.).collect();
+  assert_eq!(
+    events,
+    vec![
+      PlaybackEvent::KeyCrossed {
+        key: 2,
+        direction: Direction::Backward
+      },
+      PlaybackEvent::KeyCrossed {
+        key: 1,
+        direction: Direction::Backward
+      },
+    ]
+  );
+  assert_eq!(cursor.t(), 1.);
+}
+
+#[test]
+fn cursor_pause() {
+  let spline = spline();
+  let mut cursor = Cursor::new(&spline, 0.5);
+  cursor.pause();
+
+  assert_eq!(cursor.advance(10.).count(), 0);
+  assert_eq!(cursor.t(), 0.5);
+
+  cursor.resume();
+  assert_eq!(cursor.advance(1.).count(), 1);
+  assert_eq!(cursor.t(), 1.5);
+}
+
+#[test]
+fn cursor_sample_cached() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 0., Interpolation::Bezier(5.)),
+    Key::new(3., 20., Interpolation::Cosine),
+    Key::new(4., 0., Interpolation::Linear),
+    Key::new(5., 5., Interpolation::default()),
+  ]);
+  let mut cursor = Cursor::<f64, f64>::new(&spline, 1.);
+
+  while cursor.t() < 4.5 {
+    let expected = cursor.sample().unwrap();
+    let cached = cursor.sample_cached().unwrap();
+    assert!((expected - cached).abs() < 1e-9, "{expected} != {cached}");
+
+    cursor.advance(0.125);
+  }
+
+  cursor.seek(0.5);
+  assert_eq!(cursor.sample_cached(), None);
+}
+
+#[test]
+fn accumulator_does_not_drift() {
+  let dt = 1. / 60.;
+  let mut accumulator = Accumulator::new(0f32);
+  let mut naive = 0f32;
+
+  // an hour at 60 frames per second
+  for _ in 0..60 * 60 * 60 {
+    accumulator.advance(dt);
+    naive += dt;
+  }
+
+  assert!((accumulator.t() - 3600.).abs() < 1e-3);
+  assert!((naive - 3600.).abs() > 1.);
+}
+
+#[test]
+fn playback_transform() {
+  let spline = spline();
+  let fast = spline.playback(0.5, 2.);
+
+  assert_eq!(fast.sample(0.), Some(5.));
+  assert_eq!(fast.sample(1.), Some(25.));
+  assert_eq!(fast.sample(2.), None);
+  assert_eq!(fast.clamped_sample(2.), Some(30.));
+  assert_eq!(spline.sample_transformed(1., 0.5, 2.), Some(25.));
+}
+
+#[test]
+fn spline_clock() {
+  let a = spline();
+  let b = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., -10., Interpolation::Linear),
+  ]);
+  let mut clock = SplineClock::new(0.);
+
+  assert_eq!(clock.subscribe(&a), 0);
+  assert_eq!(clock.subscribe(&b), 1);
+  assert_eq!(clock.len(), 2);
+
+  assert_eq!(clock.advance(0.5), &[Some(5.), Some(-5.)]);
+  assert_eq!(clock.advance(1.), &[a.sample(1.5), None]);
+  assert_eq!(clock.advance_clamped(0.), &[a.sample(1.5), Some(-10.)]);
+
+  clock.seek(0.25);
+  assert_eq!(clock.t(), 0.25);
+  assert_eq!(clock.sample(), &[a.sample(0.25), b.sample(0.25)]);
+}
+
+#[test]
+fn cursor_fallback() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 20., Interpolation::CatmullRom),
+    Key::new(3., 30., Interpolation::CatmullRom),
+  ])
+  .with_fallback(-1.);
+  let mut cursor = Cursor::new(&spline, 0.5);
+
+  assert_eq!(cursor.sample(), Some(-1.));
+  assert_eq!(cursor.sample_cached(), Some(-1.));
+  assert_eq!(cursor.clamped_sample(), Some(-1.));
+  assert_eq!(cursor.sample_with_key(), None);
+
+  cursor.seek(1.5);
+  assert_eq!(cursor.sample(), spline.sample(1.5));
+  assert_ne!(cursor.sample(), Some(-1.));
+
+  cursor.seek(4.);
+  assert_eq!(cursor.sample(), Some(-1.));
+  assert_eq!(cursor.clamped_sample(), Some(30.));
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "flo_curves")]
+
+use flo_curves::bezier::{BezierCurve, Curve};
+use flo_curves::Coord2;
+use splines::{convert::ConversionError, Interpolation, Key, Spline};
+
+#[test]
+fn curves_round_trip() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., Coord2(0., 0.), Interpolation::Linear),
+    Key::new(1., Coord2(3., 0.), Interpolation::CatmullRom),
+    Key::new(2., Coord2(3., 3.), Interpolation::Bezier(Coord2(4., 4.))),
+    Key::new(3., Coord2(0., 3.), Interpolation::Linear),
+    Key::new(4., Coord2(0., 0.), Interpolation::Linear),
+  ]);
+  let curves = Vec::<Curve<Coord2>>::try_from(&spline).unwrap();
+
+  assert_eq!(curves.len(), 4);
+  assert_eq!(curves[0].control_points(), (Coord2(1., 0.), Coord2(2., 0.)));
+
+  for (i, curve) in curves.iter().enumerate() {
+    for &nt in &[0., 0.3, 0.7] {
+      let a = curve.point_at_pos(nt);
+      let b = spline.sample(i as f64 + nt).unwrap();
+      assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9);
+    }
+  }
+
+  let back = Spline::<f64, Coord2>::try_from(curves.as_slice()).unwrap();
+  assert_eq!(back.len(), 5);
+
+  for &t in &[0.5, 1.5, 2.5, 3.5] {
+    let a = back.sample(t).unwrap();
+    let b = spline.sample(t).unwrap();
+    assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9);
+  }
+
+  let disconnected = [curves[0], curves[2]];
+  assert_eq!(
+    Spline::<f64, Coord2>::try_from(&disconnected[..]),
+    Err(ConversionError::Disconnected)
+  );
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "glam")]
+
+use glam::{DVec3, Vec3};
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn f64_time_with_f32_values() {
+  // times too large to be precisely represented by f32
+  let start = 1e9;
+  let spline = Spline::<f64, Vec3>::from_vec(vec![
+    Key::new(start, Vec3::ZERO, Interpolation::Linear),
+    Key::new(start + 1., Vec3::new(10., 0., -10.), Interpolation::Cosine),
+    Key::new(start + 2., Vec3::new(20., 0., 0.), Interpolation::default()),
+  ]);
+
+  assert_eq!(spline.sample(start + 0.25), Some(Vec3::new(2.5, 0., -2.5)));
+  assert!((spline.sample(start + 1.5).unwrap() - Vec3::new(15., 0., -5.)).length() < 1e-5);
+  assert!((spline.arc_length(start, start + 1.).unwrap() - 200f64.sqrt()).abs() < 1e-5);
+}
+
+#[test]
+fn f32_time_with_f64_values() {
+  let spline = Spline::<f32, DVec3>::from_vec(vec![
+    Key::new(0., DVec3::ZERO, Interpolation::Linear),
+    Key::new(1., DVec3::new(1e10, 0., 0.), Interpolation::default()),
+  ]);
+
+  assert_eq!(spline.sample(0.5), Some(DVec3::new(5e9, 0., 0.)));
+  assert_eq!(spline.curvature(0.5), Some(0.));
+}
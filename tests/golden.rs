@@ -0,0 +1,113 @@
+//! Golden tests of the interpolation math.
+//!
+//! Every interpolation mode is sampled on a fixed spline and compared, with a tolerance, to sampled
+//! values committed in `tests/golden`. Any change of curve shapes makes those tests fail. If such a
+//! change is intended, regenerate the fixtures with:
+//!
+//! ```text
+//! SPLINES_BLESS=1 cargo test --test golden
+//! ```
+
+use splines::{Interpolation, Key, Spline};
+use std::{env, fs, path::PathBuf};
+
+const SAMPLES: usize = 33;
+const TOLERANCE: f64 = 1e-9;
+
+fn spline(interpolation: Interpolation<f64, f64>) -> Spline<f64, f64> {
+  let values = [0., 2., -1., 3., 1.];
+
+  Spline::from_vec(
+    values
+      .iter()
+      .enumerate()
+      .map(|(i, &value)| Key::new(i as f64, value, interpolation))
+      .collect(),
+  )
+}
+
+fn render(spline: &Spline<f64, f64>) -> String {
+  let end = spline.keys().last().unwrap().t;
+
+  (0..SAMPLES)
+    .map(|i| {
+      let t = end * i as f64 / (SAMPLES - 1) as f64;
+
+      match spline.sample(t) {
+        Some(value) => format!("{} {:.15e}\n", t, value),
+        None => format!("{} none\n", t),
+      }
+    })
+    .collect()
+}
+
+fn check(name: &str, interpolation: Interpolation<f64, f64>) {
+  let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/golden")
+    .join(format!("{}.txt", name));
+  let rendered = render(&spline(interpolation));
+
+  if env::var_os("SPLINES_BLESS").is_some() {
+    fs::write(&path, rendered).unwrap();
+    return;
+  }
+
+  let expected = fs::read_to_string(&path).unwrap();
+  let mut expected_lines = expected.lines();
+
+  for line in rendered.lines() {
+    let expected_line = expected_lines.next().expect("missing golden samples");
+    let (t, value) = line.split_once(' ').unwrap();
+    let (expected_t, expected_value) = expected_line.split_once(' ').unwrap();
+
+    assert_eq!(t, expected_t, "{}: sampling values differ", name);
+
+    match (value.parse::<f64>(), expected_value.parse::<f64>()) {
+      (Ok(a), Ok(b)) => assert!(
+        (a - b).abs() <= TOLERANCE * b.abs().max(1.),
+        "{}: at t = {}, sampled {} but expected {}",
+        name,
+        t,
+        a,
+        b
+      ),
+      _ => assert_eq!(value, expected_value, "{}: at t = {}", name, t),
+    }
+  }
+
+  assert!(
+    expected_lines.next().is_none(),
+    "{}: extra golden samples",
+    name
+  );
+}
+
+#[test]
+fn golden_step() {
+  check("step", Interpolation::Step(0.5));
+}
+
+#[test]
+fn golden_linear() {
+  check("linear", Interpolation::Linear);
+}
+
+#[test]
+fn golden_cosine() {
+  check("cosine", Interpolation::Cosine);
+}
+
+#[test]
+fn golden_catmull_rom() {
+  check("catmull_rom", Interpolation::CatmullRom);
+}
+
+#[test]
+fn golden_bezier() {
+  check("bezier", Interpolation::Bezier(1.));
+}
+
+#[test]
+fn golden_stroke_bezier() {
+  check("stroke_bezier", Interpolation::StrokeBezier(1., -1.));
+}
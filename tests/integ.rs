@@ -1,4 +1,4 @@
-use splines::{spline::SampledWithKey, Interpolation, Key, Spline};
+use splines::{spline::SampledWithKey, Interpolation, Key, Spline, TangentConstraint};
 
 #[test]
 fn step_interpolation_f32() {
@@ -158,6 +158,61 @@ fn several_interpolations_several_keys() {
   assert_eq!(spline.clamped_sample(11.), Some(4.));
 }
 
+#[test]
+fn from_sorted_vec() {
+  let keys = vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::Cosine),
+    Key::new(1., 2., Interpolation::Linear),
+    Key::new(3., 1., Interpolation::Linear),
+  ];
+
+  assert_eq!(
+    Spline::from_sorted_vec(keys.clone()),
+    Spline::from_vec(keys)
+  );
+}
+
+#[test]
+fn try_from_vec() {
+  use splines::spline::SplineError;
+
+  let k0 = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 5., Interpolation::Cosine);
+  let k2 = Key::new(2., 2., Interpolation::Linear);
+
+  assert_eq!(
+    Spline::try_from_vec(vec![k2, k0, k1]),
+    Ok(Spline::from_vec(vec![k0, k1, k2]))
+  );
+  assert_eq!(
+    Spline::<f64, f64>::try_from_vec(vec![]),
+    Err(SplineError::NotEnoughKeys(0))
+  );
+  assert_eq!(
+    Spline::try_from_vec(vec![k0]),
+    Err(SplineError::NotEnoughKeys(1))
+  );
+  assert_eq!(
+    Spline::try_from_vec(vec![k0, Key::new(f64::NAN, 1., Interpolation::Linear), k1]),
+    Err(SplineError::IncomparableKey(1))
+  );
+  assert_eq!(
+    Spline::try_from_vec(vec![k1, k2, k0, Key::new(2., 0., Interpolation::Linear)]),
+    Err(SplineError::DuplicateKey(3))
+  );
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "keys are not sorted")]
+fn from_sorted_vec_unsorted() {
+  Spline::from_sorted_vec(vec![
+    Key::new(1., 0., Interpolation::Linear),
+    Key::new(0., 5., Interpolation::Linear),
+  ]);
+}
+
 #[test]
 fn add_key_empty() {
   let mut spline: Spline<f32, f32> = Spline::from_vec(vec![]);
@@ -175,13 +230,36 @@ fn add_key() {
   let k4 = Key::new(10., 2., Interpolation::Linear);
   let end = Key::new(11., 4., Interpolation::default());
   let new = Key::new(2.4, 40., Interpolation::Linear);
-  let mut spline = Spline::from_vec(vec![start, k1, k2.clone(), k3, k4, end]);
+  let mut spline = Spline::from_vec(vec![start, k1, k2, k3, k4, end]);
 
   assert_eq!(spline.keys(), &[start, k1, k2, k3, k4, end]);
   spline.add(new);
   assert_eq!(spline.keys(), &[start, k1, k2, new, k3, k4, end]);
 }
 
+#[test]
+fn add_many_keys() {
+  let k0 = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 5., Interpolation::Linear);
+  let k2 = Key::new(2., 0., Interpolation::Step(0.1));
+  let k3 = Key::new(3., 1., Interpolation::Linear);
+  let new0 = Key::new(-1., 3., Interpolation::Linear);
+  let new1 = Key::new(1., 2., Interpolation::Cosine);
+  let new2 = Key::new(2.5, 4., Interpolation::Linear);
+  let new3 = Key::new(4., 6., Interpolation::Linear);
+  let mut spline = Spline::from_vec(vec![k0, k1, k2, k3]);
+
+  spline.add_many(vec![new3, new1, new0, new2]);
+  assert_eq!(spline.keys(), &[new0, k0, k1, new1, k2, new2, k3, new3]);
+
+  spline.add_many(None);
+  assert_eq!(spline.len(), 8);
+
+  let mut empty = Spline::from_vec(vec![]);
+  empty.add_many(vec![k1, k0]);
+  assert_eq!(empty.keys(), &[k0, k1]);
+}
+
 #[test]
 fn remove_element_empty() {
   let mut spline: Spline<f32, f32> = Spline::from_vec(vec![]);
@@ -199,9 +277,2229 @@ fn remove_element() {
   let k3 = Key::new(3., 1., Interpolation::Linear);
   let k4 = Key::new(10., 2., Interpolation::Linear);
   let end = Key::new(11., 4., Interpolation::default());
-  let mut spline = Spline::from_vec(vec![start, k1, k2.clone(), k3, k4, end]);
+  let mut spline = Spline::from_vec(vec![start, k1, k2, k3, k4, end]);
   let removed = spline.remove(2);
 
   assert_eq!(removed, Some(k2));
   assert_eq!(spline.len(), 5);
 }
+
+#[test]
+fn sample_with_tangent_linear() {
+  let start = Key::new(0., 10., Interpolation::Linear);
+  let end = Key::new(2., 0., Interpolation::default());
+  let spline = Spline::<f32, f32>::from_vec(vec![start, end]);
+
+  let sampled = spline.sample_with_tangent(0.5).unwrap();
+  assert_eq!(sampled.value, 7.5);
+  assert_eq!(sampled.tangent, -1.);
+  assert_eq!(sampled.key, 0);
+  assert_eq!(spline.sample_with_tangent(2.), None);
+}
+
+#[test]
+fn sample_with_tangent_step() {
+  let start = Key::new(0., 0., Interpolation::Step(0.5));
+  let end = Key::new(1., 10., Interpolation::default());
+  let spline = Spline::<f32, f32>::from_vec(vec![start, end]);
+
+  let sampled = spline.sample_with_tangent(0.75).unwrap();
+  assert_eq!(sampled.value, 10.);
+  assert_eq!(sampled.tangent, 0.);
+}
+
+#[test]
+fn tangent_constraint_mirrored() {
+  let mut key = Key::new(0., 1., Interpolation::Bezier(2.));
+  key.tangent_constraint = TangentConstraint::Mirrored;
+  let mut spline = Spline::<f32, f32>::from_vec(vec![key, Key::new(1., 0., Interpolation::Linear)]);
+
+  let mut key = spline.get_mut(0).unwrap();
+  assert_eq!(key.tangents(), (0., 2.));
+  key.set_out_tangent(4.);
+  assert_eq!(*key.interpolation, Interpolation::StrokeBezier(-2., 4.));
+  key.set_in_tangent(0.5);
+  assert_eq!(*key.interpolation, Interpolation::StrokeBezier(0.5, 1.5));
+}
+
+#[test]
+fn tangent_constraint_aligned_and_free() {
+  let mut key = Key::new(0., 1., Interpolation::StrokeBezier(0., 3.));
+  key.tangent_constraint = TangentConstraint::Aligned;
+  let mut spline = Spline::<f32, f32>::from_vec(vec![key, Key::new(1., 0., Interpolation::Linear)]);
+
+  let mut key = spline.get_mut(0).unwrap();
+  key.set_out_tangent(0.);
+  assert_eq!(*key.interpolation, Interpolation::StrokeBezier(2., 0.));
+
+  *key.tangent_constraint = TangentConstraint::Free;
+  key.set_in_tangent(5.);
+  assert_eq!(*key.interpolation, Interpolation::StrokeBezier(5., 0.));
+}
+
+#[test]
+fn arc_length_linear() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 10., Interpolation::Linear);
+  let end = Key::new(3., 4., Interpolation::default());
+  let spline = Spline::<f64, f64>::from_vec(vec![start, k1, end]);
+
+  assert!((spline.arc_length(0., 3.).unwrap() - 16.).abs() < 1e-9);
+  assert!((spline.arc_length(2., 0.5).unwrap() - 8.).abs() < 1e-9);
+  assert_eq!(spline.arc_length(-1., 2.), None);
+}
+
+#[test]
+fn sample_many() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 10., Interpolation::Linear);
+  let end = Key::new(2., 0., Interpolation::default());
+  let spline = Spline::<f32, f32>::from_vec(vec![start, k1, end]);
+  let ts = [0., 0.5, 1.5, 0.25, 3., 1.75, -1.];
+
+  let expected: Vec<_> = ts.iter().map(|&t| spline.sample(t)).collect();
+  assert_eq!(spline.sample_many(&ts), expected);
+  assert_eq!(
+    spline.sample_many(&ts),
+    vec![
+      Some(0.),
+      Some(5.),
+      Some(5.),
+      Some(2.5),
+      None,
+      Some(2.5),
+      None
+    ]
+  );
+}
+
+#[test]
+fn sample_sorted() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 10., Interpolation::Linear);
+  let k2 = Key::new(1.5, 0., Interpolation::Linear);
+  let end = Key::new(2., 10., Interpolation::default());
+  let spline = Spline::<f32, f32>::from_vec(vec![start, k1, k2, end]);
+
+  let ts = [-1., 0., 0.5, 1., 1.75, 1.75, 2., 3.];
+  let expected: Vec<_> = ts.iter().map(|&t| spline.sample(t)).collect();
+  assert_eq!(spline.sample_sorted(&ts), expected);
+
+  // unsorted input is still sampled correctly
+  let ts = [1.75, 0.5, 1.25, 0.];
+  let expected: Vec<_> = ts.iter().map(|&t| spline.sample(t)).collect();
+  assert_eq!(spline.sample_sorted(&ts), expected);
+}
+
+#[test]
+fn sample_into() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 10., Interpolation::Linear);
+  let end = Key::new(2., 0., Interpolation::default());
+  let spline = Spline::<f32, f32>::from_vec(vec![start, k1, end]);
+  let ts = [0., 0.5, 1.5, 3., -1.];
+
+  let mut out = [-1.; 5];
+  assert_eq!(spline.sample_into(&ts, &mut out), 3);
+  assert_eq!(out, [0., 5., 5., -1., -1.]);
+
+  assert_eq!(spline.clamped_sample_into(&ts, &mut out), 5);
+  assert_eq!(out, [0., 5., 5., 0., 0.]);
+}
+
+#[test]
+fn single_key_spline() {
+  let spline = Spline::<f32, f32>::from_vec(vec![Key::new(1., 5., Interpolation::Linear)]);
+
+  assert_eq!(spline.sample(1.), Some(5.));
+  assert_eq!(spline.sample(0.5), None);
+  assert_eq!(spline.clamped_sample(-3.), Some(5.));
+  assert_eq!(spline.clamped_sample(1.), Some(5.));
+  assert_eq!(spline.clamped_sample(8.), Some(5.));
+  assert_eq!(spline.sample_many(&[1., 2.]), vec![Some(5.), None]);
+  assert_eq!(spline.sample_with_tangent(1.), None);
+  assert_eq!(spline.duration(), 0.);
+}
+
+#[test]
+fn iter_samples() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let end = Key::new(1., 10., Interpolation::default());
+  let spline = Spline::<f32, f32>::from_vec(vec![start, end]);
+
+  let samples: Vec<_> = spline.iter_samples(0., 0.25).collect();
+  assert_eq!(
+    samples,
+    vec![(0., 0.), (0.25, 2.5), (0.5, 5.), (0.75, 7.5), (1., 10.)]
+  );
+
+  let samples: Vec<_> = spline.iter_samples(-0.5, 0.5).until(0.5).collect();
+  assert_eq!(samples, vec![(-0.5, 0.), (0., 0.), (0.5, 5.)]);
+
+  // many small steps don’t accumulate rounding errors and still reach the last key
+  assert_eq!(spline.iter_samples(0., 0.1).count(), 11);
+  assert_eq!(spline.iter_samples(0., 0.).count(), 0);
+
+  // sampling values that cannot be sampled yield the fallback value, or are skipped without it
+  let mut spline = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 20., Interpolation::CatmullRom),
+    Key::new(3., 30., Interpolation::CatmullRom),
+  ]);
+  let ts = |spline: &Spline<f32, f32>| {
+    spline
+      .iter_samples(0., 0.5)
+      .map(|(t, _)| t)
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(ts(&spline), vec![0., 1., 1.5, 3.]);
+
+  spline.set_fallback(Some(-1.));
+  let samples: Vec<_> = spline.iter_samples(0., 0.5).collect();
+  assert_eq!(samples.len(), 7);
+  assert_eq!(samples[1], (0.5, -1.));
+  assert_eq!(samples[3], (1.5, spline.sample(1.5).unwrap()));
+  assert_eq!(samples[5], (2.5, -1.));
+  assert_eq!(samples[6], (3., 30.));
+}
+
+#[test]
+fn flatten_straight_segments() {
+  let spline = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(2., 20., Interpolation::Step(0.5)),
+    Key::new(3., 5., Interpolation::default()),
+  ]);
+
+  assert_eq!(spline.flatten(1e-3), vec![0., 10., 20., 5.]);
+  assert!(Spline::<f32, f32>::from_vec(vec![])
+    .flatten(1e-3)
+    .is_empty());
+}
+
+#[test]
+fn broken_interpolation() {
+  // reached with a cubic Bézier curve, left stepped
+  let mut k1 = Key::new(1., 10., Interpolation::Step(1.));
+  k1.in_interpolation = Some(Interpolation::StrokeBezier(12., 12.));
+  let broken = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(5.)),
+    k1,
+    Key::new(2., 20., Interpolation::default()),
+  ]);
+  let smooth = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(5.)),
+    Key::new(1., 10., Interpolation::StrokeBezier(12., 12.)),
+    Key::new(2., 20., Interpolation::default()),
+  ]);
+
+  assert_eq!(broken.sample(0.5), smooth.sample(0.5));
+  assert_eq!(broken.sample(1.5), Some(10.));
+}
+
+#[test]
+fn broken_interpolation_tangents() {
+  let mut k1 = Key::new(1., 10., Interpolation::Step(1.));
+  k1.in_interpolation = Some(Interpolation::StrokeBezier(8., 8.));
+  k1.tangent_constraint = TangentConstraint::Mirrored;
+  let mut spline = Spline::<f32, f32>::from_vec(vec![k1]);
+  let mut key = spline.get_mut(0).unwrap();
+
+  assert_eq!(key.tangents(), (8., 10.));
+
+  // editing the input tangent leaves the key stepped
+  key.set_in_tangent(7.);
+  assert_eq!(*key.interpolation, Interpolation::Step(1.));
+  assert_eq!(key.tangents(), (7., 10.));
+
+  key.set_out_tangent(11.);
+  assert_eq!(*key.interpolation, Interpolation::StrokeBezier(9., 11.));
+  assert_eq!(key.tangents(), (9., 11.));
+}
+
+#[test]
+fn solve_t() {
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Bezier(12.)),
+    Key::new(2., 20., Interpolation::Cosine),
+    Key::new(3., 40., Interpolation::Step(0.5)),
+    Key::new(4., 50., Interpolation::default()),
+  ]);
+
+  for &t in &[0., 0.25, 1., 1.3, 1.9, 2., 2.5, 2.99] {
+    let value = spline.sample(t).unwrap();
+    let solved = spline.solve_t(value).unwrap();
+    assert!((solved - t).abs() < 1e-6, "{solved} != {t}");
+  }
+
+  assert_eq!(spline.solve_t(50.), Some(3.5));
+  assert_eq!(spline.solve_t(45.), None);
+  assert_eq!(spline.solve_t(-1.), None);
+}
+
+#[test]
+fn extended_sample() {
+  use splines::extrapolation::Extrapolation;
+
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 30., Interpolation::default()),
+  ]);
+
+  let (hold, tangent) = (Extrapolation::Hold, Extrapolation::Tangent);
+  assert_eq!(spline.extended_sample(0.5, hold, hold), Some(5.));
+  assert_eq!(spline.extended_sample(-1., hold, hold), Some(0.));
+  assert_eq!(spline.extended_sample(-1., tangent, hold), Some(-10.));
+  assert_eq!(spline.extended_sample(3., hold, tangent), Some(50.));
+
+  let decay = Extrapolation::Decay {
+    target: 20.,
+    time_constant: 1.,
+  };
+  let decayed = spline.extended_sample(3., hold, decay).unwrap();
+  assert!((decayed - (20. + 10. / std::f64::consts::E)).abs() < 1e-9);
+
+  let oscillate = Extrapolation::Oscillate {
+    amplitude: 2.,
+    period: 4.,
+  };
+  assert!((spline.extended_sample(3., hold, oscillate).unwrap() - 32.).abs() < 1e-9);
+  assert!((spline.extended_sample(-1., oscillate, hold).unwrap() + 2.).abs() < 1e-9);
+}
+
+#[test]
+fn fit_report() {
+  use splines::fit::FitReport;
+
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 2., Interpolation::default()),
+  ]);
+  let samples = [(0., 0.), (0.5, 0.5), (1., 1.5), (2., 1.75)];
+
+  let report = FitReport::measure(&spline, &samples).unwrap();
+  assert_eq!(report.max_error, 0.5);
+  assert_eq!(report.worst_t, 1.);
+  assert_eq!(report.mean_error, 0.1875);
+  assert!(report.is_within(0.5));
+  assert!(!report.is_within(0.25));
+
+  assert_eq!(FitReport::measure(&spline, &[]), None);
+}
+
+#[test]
+fn sorted_keys() {
+  use splines::sorted::SortedKeys;
+
+  let keys = vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = Spline::<f32, f32>::from_vec(keys.clone());
+  let sorted = spline.sorted_keys().unwrap();
+
+  for &t in &[0., 0.5, 1., 1.5] {
+    assert_eq!(Some(sorted.sample_unchecked(t)), spline.sample(t));
+  }
+  assert_eq!(sorted.sample_unchecked(2.), 0.);
+
+  assert!(SortedKeys::new(&keys).is_some());
+  assert!(SortedKeys::new(&[keys[1], keys[0]]).is_none());
+  assert!(SortedKeys::<f32, f32>::new(&[]).is_none());
+  assert!(Spline::<f32, f32>::from_vec(vec![]).sorted_keys().is_none());
+}
+
+#[test]
+fn clusters() {
+  let line = |end: f64| {
+    Spline::from_vec(vec![
+      Key::new(0., 0., Interpolation::Linear),
+      Key::new(1., end, Interpolation::Linear),
+    ])
+  };
+  let splines = [
+    line(10.),
+    line(20.),
+    line(10.01),
+    Spline::from_vec(vec![]),
+    line(19.99),
+  ];
+
+  assert_eq!(splines[0].distance(&splines[1]), Some(10.));
+  assert_eq!(splines[0].distance(&splines[3]), None);
+
+  let clusters = splines::cluster::Clusters::new(&splines, 0.1);
+
+  assert_eq!(clusters.canonical(), &[0, 1, 3]);
+  assert_eq!(clusters.remap(), &[0, 1, 0, 2, 1]);
+  assert_eq!(clusters.canonical_of(4), 1);
+}
+
+#[test]
+fn quantized_sampling() {
+  use splines::quantize::Quantization;
+
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+  ]);
+  let levels = spline.quantized(Quantization::Levels {
+    low: 0.,
+    high: 10.,
+    count: 3,
+  });
+
+  assert_eq!(levels.sample(0.2), Some(0.));
+  assert_eq!(levels.sample(0.3), Some(5.));
+  assert_eq!(levels.sample(0.8), Some(10.));
+  assert_eq!(levels.sample(2.), None);
+  assert_eq!(levels.clamped_sample(2.), Some(10.));
+
+  let palette = [1., 4., 9.];
+  let palette = spline.quantized(Quantization::Palette(&palette));
+
+  assert_eq!(palette.sample(0.), Some(1.));
+  assert_eq!(palette.sample(0.6), Some(4.));
+  assert_eq!(palette.sample(0.7), Some(9.));
+  assert_eq!(Quantization::Palette(&[]).apply::<f64>(1.), None);
+}
+
+#[test]
+fn sample_with_policy() {
+  use splines::extrapolation::OutOfBounds;
+
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+  ]);
+
+  assert_eq!(spline.sample_with_policy(0.5, OutOfBounds::None), Some(5.));
+  assert_eq!(spline.sample_with_policy(1.5, OutOfBounds::None), None);
+  assert_eq!(
+    spline.sample_with_policy(1.5, OutOfBounds::Clamp),
+    Some(10.)
+  );
+  assert_eq!(
+    spline.sample_with_policy(1.25, OutOfBounds::Wrap),
+    Some(2.5)
+  );
+  assert_eq!(
+    spline.sample_with_policy(-0.25, OutOfBounds::Wrap),
+    Some(7.5)
+  );
+  assert_eq!(
+    spline.sample_with_policy(1.25, OutOfBounds::Mirror),
+    Some(7.5)
+  );
+  assert_eq!(
+    spline.sample_with_policy(1., OutOfBounds::Mirror),
+    Some(10.)
+  );
+  assert_eq!(
+    spline.sample_with_policy(-0.25, OutOfBounds::Mirror),
+    Some(2.5)
+  );
+  assert_eq!(
+    spline.sample_with_policy(1.5, OutOfBounds::Extrapolate),
+    Some(15.)
+  );
+}
+
+#[test]
+fn extrema() {
+  let spline: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Bezier(2.)),
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(3., -1., Interpolation::Linear),
+  ]);
+  let extrema = spline.extrema().unwrap();
+
+  // the Bézier segment is the quadratic 1 + 2 nt - 3 nt², peaking at 4/3 for nt = 1/3
+  assert!((extrema.max - 4. / 3.).abs() < 1e-9);
+  assert!((extrema.max_t - 4. / 3.).abs() < 1e-9);
+  assert_eq!((extrema.min, extrema.min_t), (-1., 3.));
+  assert_eq!(Spline::<f64, f64>::from_vec(vec![]).extrema(), None);
+}
+
+#[test]
+fn bounds_over() {
+  let spline: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Bezier(2.)),
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(3., -1., Interpolation::Linear),
+  ]);
+
+  let bounds = spline.bounds_over(0.5..1.2).unwrap();
+  assert_eq!((bounds.min, bounds.min_t), (0.5, 0.5));
+  assert!((bounds.max - 1.28).abs() < 1e-9);
+  assert!((bounds.max_t - 1.2).abs() < 1e-9);
+
+  let bounds = spline.bounds_over(1.5..2.5).unwrap();
+  assert!((bounds.max - 1.25).abs() < 1e-9);
+  assert_eq!((bounds.min, bounds.min_t), (-0.5, 2.5));
+
+  let bounds = spline.bounds_over(-1. ..10.).unwrap();
+  assert_eq!(Some(bounds), spline.extrema());
+
+  assert_eq!(spline.bounds_over(5. ..6.), None);
+}
+
+#[test]
+fn f64_time_with_f32_values() {
+  let spline = Spline::<f64, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 20., Interpolation::CatmullRom),
+    Key::new(3., 30., Interpolation::default()),
+  ]);
+
+  assert_eq!(spline.sample(0.5), Some(5.));
+  assert_eq!(spline.sample(1.5), Some(15.));
+}
+
+#[test]
+fn fixed_point_sampling() {
+  use splines::fixed::Fixed;
+
+  let f = Fixed::from_f64;
+  let spline = Spline::from_vec(vec![
+    Key::new(f(0.), f(0.), Interpolation::Linear),
+    Key::new(f(1.), f(10.), Interpolation::Cosine),
+    Key::new(f(2.), f(20.), Interpolation::CatmullRom),
+    Key::new(f(3.), f(30.), Interpolation::Bezier(f(35.))),
+    Key::new(f(4.), f(40.), Interpolation::default()),
+  ]);
+  let float = Spline::from_vec(
+    spline
+      .keys()
+      .iter()
+      .map(|key| {
+        let interpolation = match key.interpolation {
+          Interpolation::Bezier(u) => Interpolation::Bezier(u.to_f64()),
+          Interpolation::Linear => Interpolation::Linear,
+          Interpolation::Cosine => Interpolation::Cosine,
+          _ => Interpolation::CatmullRom,
+        };
+        Key::new(key.t.to_f64(), key.value.to_f64(), interpolation)
+      })
+      .collect(),
+  );
+
+  assert_eq!(spline.sample(f(0.5)), Some(f(5.)));
+
+  for i in 0..40 {
+    let t = i as f64 / 10.;
+    let fixed = spline.sample(f(t)).map(Fixed::to_f64);
+    let expected = float.sample(t);
+
+    match (fixed, expected) {
+      (Some(a), Some(b)) => assert!((a - b).abs() < 1e-6, "{} != {} at {}", a, b, t),
+      (a, b) => assert_eq!(a, b),
+    }
+  }
+
+  // bit-exact results
+  assert_eq!(spline.sample(f(1.3)).unwrap().to_bits(), 51801917270);
+}
+
+#[test]
+fn fixed_point_saturation() {
+  use splines::fixed::Fixed;
+
+  let max = Fixed::from_bits(i64::MAX);
+  let min = Fixed::from_bits(i64::MIN);
+
+  assert_eq!(Fixed::from_f64(1e12), max);
+  assert_eq!(Fixed::from_f64(-1e12), min);
+  assert_eq!(Fixed::from_f64(f64::INFINITY), max);
+  assert_eq!(Fixed::from_f64(f64::NEG_INFINITY), min);
+  assert_eq!(Fixed::from_f64(f64::NAN), Fixed::ZERO);
+  assert_eq!(
+    Fixed::from_f64(-2.5),
+    Fixed::from(-2) - Fixed::ONE / Fixed::from(2)
+  );
+}
+
+#[test]
+fn raw_evaluation_matches_splines() {
+  use splines::raw;
+
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::CatmullRom),
+    Key::new(2., 10., Interpolation::Cosine),
+    Key::new(4., 0., Interpolation::StrokeBezier(-1., 2.)),
+    Key::new(5., 3., Interpolation::default()),
+  ]);
+
+  assert_eq!(spline.sample(0.5), Some(raw::eval_linear(0.5, 0., 5.)));
+  assert_eq!(
+    spline.sample(1.5),
+    Some(raw::eval_catmull_rom(
+      0.5,
+      (0., 0.),
+      (1., 5.),
+      (2., 10.),
+      (4., 0.)
+    ))
+  );
+  assert_eq!(spline.sample(3.), Some(raw::eval_cosine(0.5, 10., 0.)));
+  assert_eq!(
+    spline.sample(4.5),
+    Some(raw::eval_quadratic_bezier(0.5, 0., 2., 3.))
+  );
+  assert_eq!(raw::eval_step(0.5, 0.5, 1., 2.), 2.);
+  assert!((raw::eval_hermite(0.5, 0., 1., 1., 1.) - 0.5f64).abs() < 1e-12);
+  assert_eq!(raw::eval_cubic_bezier(0.5, 0., 1., 3., 4.), 2.);
+}
+
+#[test]
+fn crossings() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Cosine),
+    Key::new(2., 0., Interpolation::Step(0.5)),
+    Key::new(3., 1., Interpolation::Linear),
+    Key::new(4., 0.5, Interpolation::default()),
+  ]);
+
+  assert_eq!(spline.crossings(0.5), vec![0.5, 1.5, 2.5, 4.]);
+  assert_eq!(spline.crossings(1.), vec![1., 2.5, 3.]);
+  assert!(spline.crossings(2.).is_empty());
+}
+
+#[test]
+fn analyze_continuity() {
+  use splines::continuity::Continuity;
+
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Linear),
+    Key::new(2., 2., Interpolation::Linear),
+    Key::new(3., 4., Interpolation::Step(0.)),
+    Key::new(4., 0., Interpolation::Step(0.5)),
+    Key::new(5., 1., Interpolation::Cosine),
+    Key::new(6., 0., Interpolation::Linear),
+  ]);
+  let report = spline.analyze_continuity(1e-6);
+  let continuities: Vec<_> = report.iter().map(|k| k.continuity).collect();
+
+  assert_eq!(
+    continuities,
+    [
+      None,
+      Some(Continuity::C2),
+      Some(Continuity::C0),
+      Some(Continuity::Discontinuous),
+      Some(Continuity::C2),
+      Some(Continuity::C1),
+      None,
+    ]
+  );
+  assert_eq!(
+    report.iter().map(|k| k.segment_jumps).collect::<Vec<_>>(),
+    [false, false, false, false, true, false, false]
+  );
+  assert_eq!((report[3].key, report[3].t), (3, 3.));
+
+  let bezier = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(1.)),
+    Key::new(1., 2., Interpolation::Bezier(3.)),
+    Key::new(2., 0., Interpolation::Bezier(0.)),
+  ]);
+
+  assert_eq!(
+    bezier.analyze_continuity(1e-6)[1].continuity,
+    Some(Continuity::C1)
+  );
+}
+
+#[test]
+fn fallback_value() {
+  let mut spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 20., Interpolation::CatmullRom),
+    Key::new(3., 30., Interpolation::Linear),
+  ]);
+
+  assert_eq!(spline.sample(0.5), None);
+  assert_eq!(spline.sample_or(0.5, -1.), -1.);
+  assert_eq!(spline.sample_or(1.5, -1.), 15.);
+  assert_eq!(spline.clamped_sample_or_default(0.5), 0.);
+
+  spline.set_fallback(Some(-1.));
+  assert_eq!(spline.fallback(), Some(&-1.));
+  assert_eq!(spline.sample(0.5), Some(-1.));
+  assert_eq!(spline.sample(4.), Some(-1.));
+  assert_eq!(spline.clamped_sample(4.), Some(30.));
+  assert_eq!(spline.sample_many(&[0.5, 1.5]), vec![Some(-1.), Some(15.)]);
+
+  let empty = Spline::<f64, f64>::from_vec(vec![]).with_fallback(3.);
+  assert_eq!(empty.clamped_sample(1.), Some(3.));
+}
+
+#[test]
+fn sample_segment_by_index() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(3., 30., Interpolation::CatmullRom),
+    Key::new(4., 40., Interpolation::Linear),
+  ]);
+
+  assert_eq!(spline.sample_segment(0, 0.5), Some(5.));
+  assert_eq!(spline.sample_segment(1, 0.5), spline.sample(2.));
+  assert_eq!(spline.sample_segment(1, 0.), Some(10.));
+  assert_eq!(spline.sample_segment(2, 0.5), None);
+  assert_eq!(spline.sample_segment(3, 0.5), None);
+  assert_eq!(spline.sample_segment(10, 0.5), None);
+}
+
+#[test]
+fn sample_detailed() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 10., Interpolation::Cosine),
+    Key::new(3., 30., Interpolation::Linear),
+  ]);
+
+  let sampled = spline.sample_detailed(2.5).unwrap();
+  assert_eq!(sampled.value, 20.);
+  assert_eq!(sampled.key, 1);
+  assert_eq!(sampled.nt, 0.5);
+  assert_eq!(sampled.interpolation, Interpolation::Cosine);
+
+  let sampled = spline.sample_detailed(0.5).unwrap();
+  assert_eq!((sampled.key, sampled.nt), (0, 0.25));
+  assert_eq!(sampled.interpolation, Interpolation::Linear);
+
+  assert!(spline.sample_detailed(4.).is_none());
+}
+
+#[test]
+fn dead_zone_with_hysteresis() {
+  let spline = Spline::from_vec(vec![
+    Key::new(-1., -10., Interpolation::Linear),
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+  ]);
+  let mut sampler = spline.with_dead_zone(1., 0.5);
+
+  assert_eq!(sampler.sample(0.05), Some(0.));
+  assert_eq!(sampler.sample(0.125), Some(0.));
+  assert!(sampler.is_in_dead_zone());
+  assert_eq!(sampler.sample(-0.25), Some(-2.5));
+  assert!(!sampler.is_in_dead_zone());
+
+  // outputs hovering around the edge don’t make the sampler enter the dead zone again
+  assert_eq!(sampler.sample(0.125), Some(1.25));
+  assert_eq!(sampler.sample(0.09375), Some(0.));
+  assert!(sampler.is_in_dead_zone());
+
+  // the state is kept when the spline cannot be sampled
+  assert_eq!(sampler.sample(2.), None);
+  assert!(sampler.is_in_dead_zone());
+
+  sampler.sample(0.5);
+  sampler.reset();
+  assert!(sampler.is_in_dead_zone());
+  assert_eq!(sampler.clamped_sample(2.), Some(10.));
+
+  // the dead zone is on the output: this response is zero at 0.5, not at 0
+  let offset = Spline::from_vec(vec![
+    Key::new(0., -1., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Linear),
+  ]);
+  let mut sampler = offset.with_dead_zone(0.1, 0.05);
+
+  assert_eq!(sampler.sample(0.), Some(-1.));
+  assert_eq!(sampler.sample(0.5), Some(0.));
+
+  // an output chattering across the dead zone radius stays at zero…
+  let chatter = [0.54, 0.56, 0.53, 0.57, 0.54];
+  assert!(chatter.iter().all(|&t| sampler.sample(t) == Some(0.)));
+
+  // … and, once out of the dead zone, stays out
+  assert!(sampler.sample(0.6).unwrap() > 0.);
+  let chatter = [0.56, 0.57, 0.56, 0.57];
+  assert!(chatter
+    .iter()
+    .all(|&t| sampler.sample(t) == offset.sample(t)));
+}
+
+#[test]
+fn monotonicity() {
+  let ease: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Cosine),
+    Key::new(1., 1., Interpolation::StrokeBezier(1., 1.5)),
+    Key::new(2., 2., Interpolation::Step(0.5)),
+    Key::new(3., 3., Interpolation::Linear),
+  ]);
+
+  assert!(ease.is_increasing());
+  assert!(!ease.is_decreasing());
+  assert!(ease.is_monotonic());
+
+  // the control point 3 makes the curve overshoot 2 before coming back
+  let overshoot: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(3.)),
+    Key::new(1., 2., Interpolation::Linear),
+  ]);
+
+  assert!(!overshoot.is_increasing());
+  assert!(!overshoot.is_monotonic());
+
+  let falling: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 1., Interpolation::Linear),
+    Key::new(1., 0., Interpolation::Linear),
+  ]);
+
+  assert!(falling.is_decreasing());
+  assert!(Spline::<f64, f64>::from_vec(vec![]).is_monotonic());
+}
+
+#[test]
+fn split_at() {
+  let spline: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(3.)),
+    Key::new(1., 2., Interpolation::CatmullRom),
+    Key::new(2., -1., Interpolation::CatmullRom),
+    Key::new(3., 4., Interpolation::StrokeBezier(2., 6.)),
+    Key::new(4., 1., Interpolation::Step(0.5)),
+    Key::new(5., 3., Interpolation::Linear),
+    Key::new(6., 0., Interpolation::Cosine),
+    Key::new(7., 1., Interpolation::default()),
+  ]);
+  let samples = |from: f64, to: f64| (0..=20).map(move |i| from + (to - from) * i as f64 / 20.);
+
+  for &t in &[0.25, 1., 1.5, 2.75, 3.4, 4.25, 4.75, 5.5] {
+    let (left, right) = spline.split_at(t).unwrap();
+
+    assert_eq!(left.keys().last().unwrap().t, t);
+    assert_eq!(right.keys()[0].t, t);
+
+    for t in samples(0., t) {
+      let (a, b) = (spline.clamped_sample(t), left.clamped_sample(t));
+      assert!(
+        (a.unwrap() - b.unwrap()).abs() < 1e-9,
+        "left at {}: {:?} != {:?}",
+        t,
+        a,
+        b
+      );
+    }
+
+    for t in samples(t, 7.) {
+      let (a, b) = (spline.clamped_sample(t), right.clamped_sample(t));
+      assert!(
+        (a.unwrap() - b.unwrap()).abs() < 1e-9,
+        "right at {}: {:?} != {:?}",
+        t,
+        a,
+        b
+      );
+    }
+  }
+
+  assert!(spline.split_at(6.5).is_none());
+  assert!(spline.split_at(-1.).is_none());
+  assert!(spline.split_at(8.).is_none());
+  assert_eq!(spline.split_at(7.).unwrap().1.len(), 1);
+}
+
+#[test]
+fn concat() {
+  use splines::edit::{ConcatPolicy, Junction, TimeShift};
+
+  let section = || {
+    Spline::from_vec(vec![
+      Key::new(0., 0., Interpolation::Linear),
+      Key::new(1., 10., Interpolation::Linear),
+    ])
+  };
+  let looped = section().concat(
+    Spline::from_vec(vec![
+      Key::new(0., 10., Interpolation::Cosine),
+      Key::new(1., 0., Interpolation::Linear),
+    ]),
+    ConcatPolicy::default(),
+  );
+
+  assert_eq!(looped.len(), 3);
+  assert_eq!(looped.keys()[1].interpolation, Interpolation::Cosine);
+  assert_eq!(looped.sample(0.5), Some(5.));
+  assert_eq!(looped.sample(1.5), Some(5.));
+
+  let both = section().concat(
+    section(),
+    ConcatPolicy {
+      shift: TimeShift::None,
+      junction: Junction::KeepBoth,
+    },
+  );
+  assert_eq!(both.len(), 4);
+  assert_eq!(
+    both.keys().iter().map(|key| key.value).collect::<Vec<_>>(),
+    vec![0., 0., 10., 10.]
+  );
+
+  let gap = section().concat(
+    section(),
+    ConcatPolicy {
+      shift: TimeShift::By(2.),
+      junction: Junction::KeepSecond,
+    },
+  );
+  assert_eq!(gap.len(), 4);
+  assert_eq!(gap.sample(1.5), Some(5.));
+  assert_eq!(gap.sample(2.5), Some(5.));
+}
+
+#[test]
+fn track_kind() {
+  use splines::builder::SplineBuilder;
+  use splines::track::TrackKind;
+
+  let mut spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Linear),
+  ])
+  .with_kind(TrackKind::Scalar);
+
+  assert_eq!(spline.kind(), Some(TrackKind::Scalar));
+
+  let (left, _) = spline.split_at(0.5).unwrap();
+  assert_eq!(left.kind(), Some(TrackKind::Scalar));
+
+  spline.set_kind(Some(TrackKind::Custom(3)));
+  assert_eq!(spline.kind(), Some(TrackKind::Custom(3)));
+  spline.set_kind(None);
+  assert_eq!(spline.kind(), None);
+
+  // the builder picks the default interpolation mode of the kind
+  let spline = SplineBuilder::new()
+    .key(0., 0.)
+    .kind(TrackKind::Translation)
+    .key(1., 10.)
+    .key(2., 20.)
+    .linear()
+    .build()
+    .unwrap();
+
+  assert_eq!(spline.kind(), Some(TrackKind::Translation));
+  assert_eq!(spline.keys()[0].interpolation, Interpolation::Linear);
+  assert_eq!(spline.keys()[1].interpolation, Interpolation::Cosine);
+  assert_eq!(spline.keys()[2].interpolation, Interpolation::Linear);
+  assert_eq!(
+    TrackKind::Rotation.default_interpolation::<f64, f64>(),
+    Interpolation::Linear
+  );
+  assert_eq!(
+    TrackKind::Custom(3).default_interpolation::<f64, f64>(),
+    Interpolation::default()
+  );
+}
+
+#[test]
+fn retiming() {
+  let mut spline: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::default()),
+  ]);
+
+  spline.shift_t(1.);
+  assert_eq!(spline.sample(1.4), Some(0.));
+  assert_eq!(spline.sample(1.5), Some(10.));
+  assert_eq!(spline.sample(2.5), Some(15.));
+
+  spline.scale_t(2.);
+  assert_eq!(spline.sample(2.9), Some(0.));
+  assert_eq!(spline.sample(3.), Some(10.));
+  assert_eq!(spline.sample(5.), Some(15.));
+
+  // squaring moves the switch of the step segment from 3 to 9, within [4; 16]
+  spline.retime(|t| t * t);
+  let keys = spline.keys().iter().map(|key| key.t).collect::<Vec<_>>();
+  assert_eq!(keys, vec![4., 16., 36.]);
+  assert_eq!(
+    spline.keys()[0].interpolation,
+    Interpolation::Step(5. / 12.)
+  );
+  assert_eq!(spline.sample(8.9), Some(0.));
+  assert_eq!(spline.sample(9.), Some(10.));
+}
+
+#[test]
+fn map_values() {
+  let spline: Spline<f64, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(1.)),
+    Key::new(1., 2., Interpolation::StrokeBezier(3., 1.)),
+    Key::new(2., 0., Interpolation::default()),
+  ])
+  .with_fallback(-1.);
+  let doubled = spline.map(|v| v * 2.);
+
+  assert_eq!(doubled.keys()[0].interpolation, Interpolation::Bezier(2.));
+  assert_eq!(
+    doubled.keys()[1].interpolation,
+    Interpolation::StrokeBezier(6., 2.)
+  );
+  assert_eq!(doubled.fallback(), Some(&-2.));
+
+  for &t in &[0.25, 0.5, 1.25, 1.75] {
+    assert_eq!(doubled.sample(t), spline.sample(t).map(|v| v * 2.));
+  }
+
+  let as_f32: Spline<f64, f32> = spline.map(|&v| v as f32);
+  assert_eq!(as_f32.sample(0.5), Some(spline.sample(0.5).unwrap() as f32));
+}
+
+#[test]
+fn map_sampling_values() {
+  let seconds: Spline<f32, f64> = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Step(0.25)),
+    Key::new(2., 10., Interpolation::Linear),
+    Key::new(4., 20., Interpolation::default()),
+  ]);
+  let frames: Spline<f64, f64> = seconds.map_t(|t| t as f64 * 24.);
+
+  assert_eq!(
+    frames.keys().iter().map(|key| key.t).collect::<Vec<_>>(),
+    vec![0., 48., 96.]
+  );
+  assert_eq!(frames.keys()[0].interpolation, Interpolation::Step(0.25));
+  assert_eq!(frames.sample(11.), Some(0.));
+  assert_eq!(frames.sample(12.), Some(10.));
+  assert_eq!(frames.sample(72.), Some(15.));
+
+  let reversed = frames.map_t(|t| -t);
+  assert_eq!(reversed.keys()[0].t, -96.);
+}
+
+#[test]
+fn simplify() {
+  // a dense line with a single bump
+  let keys = (0..=20)
+    .map(|i| {
+      let t = i as f64;
+      let value = if i == 10 { 5. } else { t };
+      Key::new(t, value, Interpolation::Linear)
+    })
+    .collect();
+  let spline = Spline::<f64, f64>::from_vec(keys);
+
+  let (simplified, report) = spline.simplify(1e-9).unwrap();
+  assert_eq!(
+    simplified
+      .keys()
+      .iter()
+      .map(|key| key.t)
+      .collect::<Vec<_>>(),
+    vec![0., 9., 10., 11., 20.]
+  );
+  assert!(report.is_within(1e-9));
+
+  let (simplified, report) = spline.simplify(100.).unwrap();
+  assert_eq!(simplified.len(), 2);
+  assert_eq!(report.max_error, 5.);
+
+  assert_eq!(Spline::<f64, f64>::from_vec(vec![]).simplify(1.), None);
+}
+
+#[test]
+fn least_squares_fit() {
+  let reference = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 1., Interpolation::Linear),
+    Key::new(1., 3., Interpolation::Linear),
+    Key::new(2., 2., Interpolation::Linear),
+  ]);
+  let points: Vec<_> = (0..=20)
+    .map(|i| {
+      let t = i as f64 / 10.;
+      (t, reference.clamped_sample(t).unwrap())
+    })
+    .collect();
+
+  let (spline, report) = Spline::fit(&points, 3, Interpolation::Linear).unwrap();
+  assert_eq!(
+    spline.keys().iter().map(|key| key.t).collect::<Vec<_>>(),
+    vec![0., 1., 2.]
+  );
+
+  for (key, expected) in spline.keys().iter().zip([1., 3., 2.]) {
+    assert!((key.value - expected).abs() < 1e-9);
+  }
+
+  assert!(report.is_within(1e-9));
+
+  // a step holds the mean value of the points it covers
+  let points = [(0., 0.), (1., 2.), (2., 4.)];
+  let (spline, report) = Spline::fit(&points, 2, Interpolation::Step(1.)).unwrap();
+  assert_eq!(spline.keys()[0].value, 1.);
+  assert_eq!(report.max_error, 1.);
+
+  let catmull_rom = Spline::<f64, f64>::from_vec(
+    [0., 1., 4., 2., 3., 0.]
+      .iter()
+      .enumerate()
+      .map(|(i, &value)| Key::new(i as f64 - 1., value, Interpolation::CatmullRom))
+      .collect(),
+  );
+  let mut points: Vec<_> = (0..30)
+    .map(|i| {
+      let t = i as f64 / 10.;
+      (t, catmull_rom.sample(t).unwrap())
+    })
+    .collect();
+  points.push((3., catmull_rom.sample_segment(3, 1.).unwrap()));
+  let (spline, report) = Spline::fit(&points, 6, Interpolation::CatmullRom).unwrap();
+  assert_eq!(spline.keys()[0].t, -1.);
+  assert_eq!(spline.keys()[5].t, 4.);
+  assert!(report.is_within(1e-9));
+
+  assert!(Spline::fit(&points, 3, Interpolation::CatmullRom).is_none());
+  assert!(Spline::fit(&points, 3, Interpolation::Bezier(0.)).is_none());
+  assert!(Spline::<f64, f64>::fit(&[], 3, Interpolation::Linear).is_none());
+}
+
+#[test]
+fn keyframe_reduction() {
+  // a smooth curve baked at 60 Hz over two seconds
+  let samples: Vec<(f64, f64)> = (0..=120)
+    .map(|i| {
+      let t = i as f64 / 60.;
+      (t, (t * 3.).sin())
+    })
+    .collect();
+
+  let (spline, report) = Spline::reduce(&samples, 1e-3).unwrap();
+  assert!(spline.len() < 20);
+  assert!(report.is_within(1e-3));
+  assert_eq!(spline.keys().first().unwrap().t, 0.);
+  assert_eq!(spline.keys().last().unwrap().t, 2.);
+
+  for &(t, value) in &samples {
+    assert!((spline.clamped_sample(t).unwrap() - value).abs() <= 1e-3);
+  }
+
+  // a tolerance of zero keeps every sample
+  let (spline, _) = Spline::reduce(&samples[..10], 0.).unwrap();
+  assert_eq!(spline.len(), 10);
+
+  assert_eq!(Spline::<f64, f64>::reduce(&[], 1.), None);
+}
+
+#[test]
+fn smoothing() {
+  use splines::smooth::{Kernel, SmoothingWindow};
+
+  let spline = Spline::<f64, f64>::from_vec(
+    [0., 3., 0., 3., 0.]
+      .iter()
+      .enumerate()
+      .map(|(i, &value)| Key::new(i as f64, value, Interpolation::Linear))
+      .collect(),
+  );
+
+  let smoothed = spline.smooth(SmoothingWindow::default());
+  assert_eq!(
+    smoothed
+      .keys()
+      .iter()
+      .map(|key| key.value)
+      .collect::<Vec<_>>(),
+    vec![0., 1., 2., 1., 0.]
+  );
+
+  let smoothed = spline.smooth(SmoothingWindow {
+    preserve_endpoints: false,
+    ..SmoothingWindow::default()
+  });
+  assert_eq!(smoothed.keys()[0].value, 1.5);
+
+  let smoothed = spline.smooth(SmoothingWindow {
+    radius: 2,
+    kernel: Kernel::Gaussian(1.),
+    preserve_endpoints: true,
+  });
+  let values: Vec<_> = smoothed.keys().iter().map(|key| key.value).collect();
+  assert!(values[1] > 1. && values[1] < 3.);
+  assert!((values[1] - values[3]).abs() < 1e-12);
+
+  // Bézier control points follow their keys
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 3., Interpolation::Bezier(4.)),
+    Key::new(2., 0., Interpolation::Linear),
+  ]);
+  let smoothed = spline.smooth(SmoothingWindow::default());
+  assert_eq!(smoothed.keys()[1].interpolation, Interpolation::Bezier(2.));
+}
+
+#[test]
+fn catmull_rom_to_bezier() {
+  let spline = Spline::<f64, f64>::from_vec(
+    [0., 1., 4., 2., 3., 0.]
+      .iter()
+      .enumerate()
+      .map(|(i, &value)| Key::new(i as f64, value, Interpolation::CatmullRom))
+      .collect(),
+  );
+  let bezier = spline.to_bezier();
+
+  for (i, key) in bezier.keys().iter().enumerate() {
+    let is_bezier = matches!(key.interpolation, Interpolation::Bezier(_));
+    assert_eq!(is_bezier, (1..=3).contains(&i));
+  }
+
+  assert_eq!(bezier.keys()[0].interpolation, Interpolation::CatmullRom);
+
+  for k in 10..40 {
+    let t = k as f64 / 10.;
+    let expected = spline.sample(t).unwrap();
+    assert!((bezier.sample(t).unwrap() - expected).abs() < 1e-9);
+  }
+}
+
+#[test]
+fn baked_sampling() {
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::Bezier(5.)),
+    Key::new(3., 2., Interpolation::Step(0.5)),
+    Key::new(4., 3., Interpolation::Cosine),
+    Key::new(5., 0., Interpolation::Linear),
+  ]);
+  let baked = spline.bake();
+
+  assert_eq!(baked.len(), 6);
+
+  for k in -10..=60 {
+    let t = k as f64 / 10.;
+
+    match (spline.sample(t), baked.sample(t)) {
+      (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9),
+      (a, b) => assert_eq!(a, b),
+    }
+
+    let (a, b) = (spline.clamped_sample(t), baked.clamped_sample(t));
+    assert_eq!(a.is_some(), b.is_some());
+    assert!(a.zip(b).is_none_or(|(a, b)| (a - b).abs() < 1e-9));
+  }
+
+  let single = Spline::<f64, f64>::from_vec(vec![Key::new(1., 2., Interpolation::Linear)]).bake();
+  assert_eq!(single.sample(1.), Some(2.));
+  assert_eq!(single.sample(0.), None);
+
+  let empty = Spline::<f64, f64>::from_vec(vec![])
+    .with_fallback(1.)
+    .bake();
+  assert!(empty.is_empty());
+  assert_eq!(empty.clamped_sample(0.), Some(1.));
+}
+
+#[test]
+fn polynomials() {
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 4., Interpolation::Bezier(6.)),
+    Key::new(4., 2., Interpolation::Step(0.5)),
+    Key::new(5., 3., Interpolation::Linear),
+  ]);
+  let polynomials = spline.to_polynomials();
+
+  assert_eq!(polynomials.len(), 3);
+  assert!(polynomials[2].is_none());
+
+  let linear = polynomials[0].unwrap();
+  assert_eq!(linear.coefficients, [0., 4., 0., 0.]);
+  assert_eq!(linear.coefficients_in_t(), [0., 2., 0., 0.]);
+
+  let bezier = polynomials[1].unwrap();
+  assert_eq!((bezier.start, bezier.end), (2., 4.));
+
+  for k in 0..10 {
+    let t = 2. + k as f64 / 5.;
+    assert!((bezier.value(t) - spline.sample(t).unwrap()).abs() < 1e-9);
+
+    let [a, b, c, d] = bezier.coefficients_in_t();
+    let x = t - 2.;
+    assert!((a + b * x + c * x * x + d * x * x * x - spline.sample(t).unwrap()).abs() < 1e-9);
+  }
+}
+
+#[test]
+fn inversion() {
+  // time to distance
+  let distance = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Bezier(3.)),
+    Key::new(3., 6., Interpolation::Linear),
+  ]);
+  let (time, report) = distance.invert(1e-6).unwrap();
+
+  assert!(report.is_within(1e-6));
+  assert_eq!(time.keys().first().unwrap().t, 0.);
+  assert_eq!(time.keys().last().unwrap().t, 6.);
+
+  for k in 0..30 {
+    let t = k as f64 / 10.;
+    let d = distance.sample(t).unwrap();
+    assert!((time.clamped_sample(d).unwrap() - t).abs() < 1e-4);
+  }
+
+  let decreasing = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 4., Interpolation::Cosine),
+    Key::new(2., 0., Interpolation::Linear),
+  ]);
+  let (inverse, _) = decreasing.invert(1e-6).unwrap();
+  assert_eq!(inverse.keys().first().unwrap().value, 2.);
+  assert!((inverse.clamped_sample(2.).unwrap() - 1.).abs() < 1e-4);
+
+  let step = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 1., Interpolation::Linear),
+  ]);
+  assert!(step.invert(1e-6).is_none());
+
+  let bump = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Bezier(3.)),
+    Key::new(1., 1., Interpolation::Linear),
+  ]);
+  assert!(bump.invert(1e-6).is_none());
+}
+
+#[test]
+fn truncate() {
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Bezier(3.)),
+    Key::new(3., 1., Interpolation::Linear),
+    Key::new(4., 5., Interpolation::Linear),
+  ]);
+  let clipped = spline.truncate(0.5, 2.).unwrap();
+
+  assert_eq!(clipped.keys().first().unwrap().t, 0.5);
+  assert_eq!(clipped.keys().last().unwrap().t, 2.);
+
+  for k in 5..=20 {
+    let t = k as f64 / 10.;
+    let expected = spline.sample(t).unwrap();
+    assert!((clipped.clamped_sample(t).unwrap() - expected).abs() < 1e-9);
+  }
+
+  let whole = spline.truncate(-1., 10.).unwrap();
+  assert_eq!(whole, spline);
+  assert_eq!(spline.truncate(3., 4.).unwrap().len(), 2);
+
+  assert!(spline.truncate(2., 1.).is_none());
+  assert!(spline.truncate(5., 6.).is_none());
+}
+
+#[test]
+fn normalize_domain() {
+  let mut spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(2., 0., Interpolation::Step(0.25)),
+    Key::new(4., 1., Interpolation::Linear),
+    Key::new(6., 3., Interpolation::Linear),
+  ]);
+  spline.normalize_domain();
+
+  assert_eq!(
+    spline.keys().iter().map(|key| key.t).collect::<Vec<_>>(),
+    vec![0., 0.5, 1.]
+  );
+  assert_eq!(spline.keys()[0].interpolation, Interpolation::Step(0.25));
+
+  let mut single = Spline::<f64, f64>::from_vec(vec![Key::new(3., 1., Interpolation::Linear)]);
+  single.normalize_domain();
+  assert_eq!(single.keys()[0].t, 0.);
+}
+
+#[test]
+fn equalize_spacing() {
+  let keys = vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(0.5, 1., Interpolation::CatmullRom),
+    Key::new(3., 4., Interpolation::Step(0.5)),
+    Key::new(4., 6., Interpolation::Linear),
+  ];
+
+  let mut spline = Spline::<f64, f64>::from_vec(keys.clone());
+  spline.equalize_spacing();
+  assert_eq!(
+    spline.keys().iter().map(|key| key.t).collect::<Vec<_>>(),
+    vec![0., 4. / 3., 8. / 3., 4.]
+  );
+  assert_eq!(spline.keys()[2].value, 4.);
+  assert_eq!(spline.keys()[2].interpolation, Interpolation::Step(0.5));
+
+  let mut spline = Spline::<f64, f64>::from_vec(keys);
+  spline.equalize_spacing_by_distance();
+  assert_eq!(
+    spline.keys().iter().map(|key| key.t).collect::<Vec<_>>(),
+    vec![0., 4. / 6., 4. * 4. / 6., 4.]
+  );
+
+  let mut flat = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 1., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Linear),
+    Key::new(4., 1., Interpolation::Linear),
+  ]);
+  flat.equalize_spacing_by_distance();
+  assert_eq!(flat.keys()[1].t, 2.);
+}
+
+#[test]
+fn uniform_spline() {
+  use splines::uniform::UniformSpline;
+
+  let values: Vec<f64> = vec![0., 1., 4., 2., 3., 0.];
+  let uniform = UniformSpline::new(1., 0.5, values, Interpolation::CatmullRom);
+  let spline = Spline::from(uniform.clone());
+
+  assert_eq!(uniform.len(), 6);
+  assert_eq!(uniform.keys()[5].t, 3.5);
+
+  for k in 0..=40 {
+    let t = k as f64 / 10.;
+
+    match (uniform.sample(t), spline.sample(t)) {
+      (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9),
+      (a, b) => assert_eq!(a, b, "t = {}", t),
+    }
+
+    assert_eq!(
+      uniform.clamped_sample(t).is_some(),
+      spline.clamped_sample(t).is_some()
+    );
+  }
+
+  let linear = UniformSpline::new(0., 0.25, vec![0., 1., 3.0f64], Interpolation::Linear);
+  assert_eq!(linear.sample(0.375), Some(2.));
+  assert_eq!(linear.sample(0.5), None);
+  assert_eq!(linear.clamped_sample(0.5), Some(3.));
+  assert_eq!(linear.clamped_sample(-1.), Some(0.));
+}
+
+#[test]
+fn lookup_table() {
+  // unevenly spaced keys
+  let spline = Spline::<f64, f64>::from_vec(
+    (0..50)
+      .map(|i| {
+        let t = (i * i) as f64 / 10.;
+        Key::new(t, (i % 7) as f64, Interpolation::Linear)
+      })
+      .collect(),
+  );
+  let table = spline.lookup_table();
+
+  for k in -10..2500 {
+    let t = k as f64 / 10.;
+    assert_eq!(table.sample(t), spline.sample(t), "t = {}", t);
+    assert_eq!(
+      table.clamped_sample(t),
+      spline.clamped_sample(t),
+      "t = {}",
+      t
+    );
+  }
+
+  let single = Spline::<f64, f64>::from_vec(vec![Key::new(1., 2., Interpolation::Linear)]);
+  assert_eq!(single.lookup_table().sample(1.), Some(2.));
+  assert_eq!(single.lookup_table().search(0.), None);
+
+  let empty = Spline::<f64, f64>::from_vec(vec![]);
+  assert_eq!(empty.lookup_table().clamped_sample(0.), None);
+}
+
+#[test]
+fn struct_of_arrays() {
+  use splines::spline::SoaSpline;
+
+  let keys = vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::Bezier(5.)),
+    Key::new(3., 2., Interpolation::Step(0.5)),
+    Key::new(4., 3., Interpolation::Cosine),
+    Key::new(5., 0., Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, f64>::from_vec(keys.clone());
+  let soa = SoaSpline::from_vec(keys);
+
+  assert_eq!(soa.len(), 6);
+  assert_eq!(soa.ts(), &[0., 1., 2., 3., 4., 5.]);
+  assert_eq!(soa.get(2), Some(spline.keys()[2]));
+
+  for k in -10..=60 {
+    let t = k as f64 / 10.;
+    assert_eq!(soa.sample(t), spline.sample(t), "t = {}", t);
+    assert_eq!(soa.clamped_sample(t), spline.clamped_sample(t), "t = {}", t);
+    assert_eq!(
+      soa.sample_with_key(t),
+      spline.sample_with_key(t),
+      "t = {}",
+      t
+    );
+    assert_eq!(
+      soa.clamped_sample_with_key(t),
+      spline.clamped_sample_with_key(t),
+      "t = {}",
+      t
+    );
+    assert_eq!(soa.try_sample(t), spline.try_sample(t), "t = {}", t);
+    assert_eq!(
+      soa.try_clamped_sample(t),
+      spline.try_clamped_sample(t),
+      "t = {}",
+      t
+    );
+  }
+
+  // batch sampling
+  let ts: Vec<_> = [4.5, 0.5, 1.5, 1.25, 3.5, 6., 2.5].to_vec();
+  let mut sorted = ts.clone();
+  sorted.sort_by(f64::total_cmp);
+  assert_eq!(soa.sample_many(&ts), spline.sample_many(&ts));
+  assert_eq!(soa.sample_sorted(&sorted), spline.sample_sorted(&sorted));
+
+  let (mut soa_out, mut out) = (vec![-1.; ts.len()], vec![-1.; ts.len()]);
+  assert_eq!(
+    soa.sample_into(&ts, &mut soa_out),
+    spline.sample_into(&ts, &mut out)
+  );
+  assert_eq!(soa_out, out);
+  assert_eq!(
+    soa.clamped_sample_into(&ts, &mut soa_out),
+    spline.clamped_sample_into(&ts, &mut out)
+  );
+  assert_eq!(soa_out, out);
+  assert_eq!(soa.sample_segment(2, 0.5), spline.sample_segment(2, 0.5));
+
+  // lookups
+  assert_eq!(soa.key_index_at(2.), spline.key_index_at(2.));
+  assert_eq!(soa.key_index_at(2.5), spline.key_index_at(2.5));
+  assert_eq!(soa.keys_between(1.0..=3.), 1..4);
+  assert_eq!(soa.keys_between(3.0..1.), 3..3);
+  assert_eq!(soa.duration(), 5.);
+
+  // fallback and tag
+  let mut soa = soa.with_fallback(-1.);
+  let mut spline = spline.with_fallback(-1.);
+  assert_eq!(soa.sample(0.5), Some(-1.));
+  assert_eq!(soa.sample_many(&ts), spline.sample_many(&ts));
+  assert_eq!(soa.try_sample(0.5), spline.try_sample(0.5));
+  soa.set_kind(Some(splines::track::TrackKind::Scalar));
+  spline.set_kind(Some(splines::track::TrackKind::Scalar));
+  assert_eq!(Spline::from(soa.clone()), spline);
+  assert_eq!(SoaSpline::from(spline.clone()), soa);
+
+  // editing
+  let key = Key::new(2.5, 7., Interpolation::Linear);
+  soa.add(key);
+  spline.add(key);
+  assert_eq!(soa.remove(1), spline.remove(1));
+  assert_eq!(soa.remove(10), None);
+
+  let added = vec![
+    Key::new(-1., 1., Interpolation::Linear),
+    Key::new(3., 5., Interpolation::Cosine),
+  ];
+  soa.add_many(added.clone());
+  spline.add_many(added);
+  assert_eq!(soa.fallback(), Some(&-1.));
+
+  let ts = soa.ts().to_vec();
+  soa.retain(|i| ts[i] != 3.);
+  spline.retain(|key| key.t != 3.);
+
+  soa.values_mut()[0] = 2.;
+  *spline.get_mut(0).unwrap().value = 2.;
+  assert_eq!(Spline::from(soa.clone()), spline);
+
+  soa.clear();
+  assert!(soa.is_empty());
+  assert_eq!(soa.try_sample(0.), Err(splines::spline::SampleError::Empty));
+}
+
+#[test]
+fn const_spline() {
+  use splines::array::ConstSpline;
+
+  let mut spline = ConstSpline::<f64, f64, 4>::new();
+  assert_eq!(spline.capacity(), 4);
+  assert!(spline.is_empty());
+  assert_eq!(spline.sample(0.), None);
+
+  spline.add(Key::new(2., 4., Interpolation::Linear)).unwrap();
+  assert_eq!(spline.sample(2.), Some(4.));
+  assert_eq!(spline.sample(1.), None);
+
+  spline.add(Key::new(0., 0., Interpolation::Linear)).unwrap();
+  spline.add(Key::new(1., 1., Interpolation::Cosine)).unwrap();
+  spline.add(Key::new(3., 2., Interpolation::Linear)).unwrap();
+  assert!(spline.is_full());
+  assert_eq!(
+    spline.add(Key::new(4., 0., Interpolation::Linear)),
+    Err(Key::new(4., 0., Interpolation::Linear))
+  );
+
+  let reference = Spline::from_vec(spline.keys().to_vec());
+  for k in -10..=40 {
+    let t = k as f64 / 10.;
+    assert_eq!(spline.sample(t), reference.sample(t));
+    assert_eq!(spline.clamped_sample(t), reference.clamped_sample(t));
+  }
+
+  *spline.get_mut(0).unwrap().value = 1.;
+  assert_eq!(spline.sample(0.5), Some(1.));
+
+  assert_eq!(
+    spline.remove(1),
+    Some(Key::new(1., 1., Interpolation::Cosine))
+  );
+  assert_eq!(spline.len(), 3);
+  assert_eq!(spline.sample(1.), Some(2.5));
+  assert_eq!(spline.remove(3), None);
+}
+
+#[test]
+fn spline_ref() {
+  use splines::spline::SplineRef;
+
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::Bezier(5.)),
+    Key::new(3., 2., Interpolation::Step(0.5)),
+    Key::new(4., 3., Interpolation::Linear),
+    Key::new(5., 0., Interpolation::Linear),
+  ];
+  let view = SplineRef::new(&keys);
+  let spline = view.to_spline();
+
+  assert_eq!(view.len(), 6);
+  assert_eq!(view.duration(), 5.);
+  assert_eq!(spline.keys(), view.keys());
+
+  let ts: Vec<f64> = (-10..=60).map(|k| k as f64 / 10.).collect();
+  for &t in &ts {
+    assert_eq!(view.sample(t), spline.sample(t));
+    assert_eq!(view.clamped_sample(t), spline.clamped_sample(t));
+    assert_eq!(view.sample_with_key(t), spline.sample_with_key(t));
+  }
+
+  assert_eq!(view.sample_many(&ts), spline.sample_many(&ts));
+  assert_eq!(view.sample_sorted(&ts), spline.sample_sorted(&ts));
+  assert_eq!(SplineRef::from(&spline).keys(), &keys);
+}
+
+#[test]
+fn arc_spline() {
+  use splines::spline::ArcSpline;
+
+  let spline = Spline::from_vec(vec![
+    Key::new(1., 1., Interpolation::Cosine),
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 4., Interpolation::Linear),
+  ]);
+  let shared = ArcSpline::from(spline.clone());
+  let other = shared.clone();
+
+  assert!(shared.ptr_eq(&other));
+  assert_eq!(shared, ArcSpline::from_vec(spline.keys().to_vec()));
+  assert!(!shared.ptr_eq(&ArcSpline::from_vec(spline.keys().to_vec())));
+  assert_eq!(other.keys(), spline.keys());
+
+  for k in -5..=25 {
+    let t = k as f64 / 10.;
+    assert_eq!(other.sample(t), spline.sample(t));
+    assert_eq!(other.clamped_sample(t), spline.clamped_sample(t));
+  }
+
+  assert_eq!(Spline::from(other), spline);
+}
+
+#[test]
+fn non_copy_values() {
+  use splines::periodic::PeriodicSpline;
+  use splines::spline::ArcSpline;
+  use splines::uniform::UniformSpline;
+  use splines::Interpolate;
+
+  // blend-shape weights, living on the heap
+  #[derive(Clone, Debug, PartialEq)]
+  struct Weights(Vec<f64>);
+
+  impl Weights {
+    // apply f to the weights at each index
+    fn each(len: usize, f: impl Fn(usize) -> f64) -> Self {
+      Weights((0..len).map(f).collect())
+    }
+  }
+
+  impl Interpolate<f64> for Weights {
+    fn step(t: f64, threshold: f64, a: Self, b: Self) -> Self {
+      Weights::each(a.0.len(), |i| f64::step(t, threshold, a.0[i], b.0[i]))
+    }
+
+    fn lerp(t: f64, a: Self, b: Self) -> Self {
+      Weights::each(a.0.len(), |i| f64::lerp(t, a.0[i], b.0[i]))
+    }
+
+    fn cosine(t: f64, a: Self, b: Self) -> Self {
+      Weights::each(a.0.len(), |i| f64::cosine(t, a.0[i], b.0[i]))
+    }
+
+    fn cubic_hermite(
+      t: f64,
+      x: (f64, Self),
+      a: (f64, Self),
+      b: (f64, Self),
+      y: (f64, Self),
+    ) -> Self {
+      Weights::each(a.1 .0.len(), |i| {
+        f64::cubic_hermite(
+          t,
+          (x.0, x.1 .0[i]),
+          (a.0, a.1 .0[i]),
+          (b.0, b.1 .0[i]),
+          (y.0, y.1 .0[i]),
+        )
+      })
+    }
+
+    fn quadratic_bezier(t: f64, a: Self, u: Self, b: Self) -> Self {
+      Weights::each(a.0.len(), |i| {
+        f64::quadratic_bezier(t, a.0[i], u.0[i], b.0[i])
+      })
+    }
+
+    fn cubic_bezier(t: f64, a: Self, u: Self, v: Self, b: Self) -> Self {
+      Weights::each(a.0.len(), |i| {
+        f64::cubic_bezier(t, a.0[i], u.0[i], v.0[i], b.0[i])
+      })
+    }
+
+    fn cubic_bezier_mirrored(t: f64, a: Self, u: Self, v: Self, b: Self) -> Self {
+      Weights::each(a.0.len(), |i| {
+        f64::cubic_bezier_mirrored(t, a.0[i], u.0[i], v.0[i], b.0[i])
+      })
+    }
+  }
+
+  // every weight follows the scalar spline with the same keys
+  let scalar = |keys: &[(f64, [f64; 2], Interpolation<f64, f64>)], j: usize| {
+    Spline::from_vec(
+      keys
+        .iter()
+        .map(|&(t, values, interpolation)| Key::new(t, values[j], interpolation))
+        .collect(),
+    )
+  };
+  let keys = [
+    (0., [0., 1.], Interpolation::Linear),
+    (1., [1., 0.], Interpolation::Cosine),
+    (2., [3., -1.], Interpolation::CatmullRom),
+    (3., [2., 2.], Interpolation::CatmullRom),
+    (4., [0., 1.], Interpolation::Step(0.5)),
+    (5., [1., 1.], Interpolation::Linear),
+  ];
+  let spline = Spline::from_vec(
+    keys
+      .iter()
+      .map(|&(t, values, interpolation)| {
+        let interpolation = match interpolation {
+          Interpolation::Step(threshold) => Interpolation::Step(threshold),
+          Interpolation::Linear => Interpolation::Linear,
+          Interpolation::Cosine => Interpolation::Cosine,
+          Interpolation::CatmullRom => Interpolation::CatmullRom,
+          _ => unreachable!(),
+        };
+        Key::new(t, Weights(values.to_vec()), interpolation)
+      })
+      .collect(),
+  );
+  let (first, second) = (scalar(&keys, 0), scalar(&keys, 1));
+
+  // linear
+  assert_eq!(spline.sample(0.25), Some(Weights(vec![0.25, 0.75])));
+  // cosine
+  assert_eq!(
+    spline.sample(1.5),
+    Some(Weights(vec![
+      first.sample(1.5).unwrap(),
+      second.sample(1.5).unwrap()
+    ]))
+  );
+  // Catmull-Rom
+  for t in [2., 2.25, 2.5, 2.9] {
+    assert_eq!(
+      spline.sample(t),
+      Some(Weights(vec![
+        first.sample(t).unwrap(),
+        second.sample(t).unwrap()
+      ]))
+    );
+  }
+  assert_eq!(spline.sample(4.25), Some(Weights(vec![0., 1.])));
+  assert_eq!(spline.clamped_sample(6.), Some(Weights(vec![1., 1.])));
+  assert_eq!(
+    spline.sample_with_key(2.5).map(|sampled| sampled.key),
+    Some(2)
+  );
+
+  // APIs moving or cloning keys don’t need copyable values
+  let mut retimed = spline.clone();
+  retimed.retime(|t| t * 2.);
+  assert_eq!(retimed.sample(0.5), Some(Weights(vec![0.25, 0.75])));
+
+  let concatenated = spline.clone().concat(retimed, Default::default());
+  assert_eq!(concatenated.len(), 11);
+
+  let periodic = PeriodicSpline::new(spline.clone(), 6.).unwrap();
+  assert_eq!(periodic.sample(6.25), Some(Weights(vec![0.25, 0.75])));
+
+  let uniform = UniformSpline::new(
+    0.,
+    1.,
+    vec![Weights(vec![0.]), Weights(vec![2.])],
+    Interpolation::Linear,
+  );
+  assert_eq!(uniform.sample(0.5), Some(Weights(vec![1.])));
+
+  assert_eq!(spline.view().to_spline(), spline);
+  assert_eq!(Spline::from(ArcSpline::from(spline.clone())), spline);
+}
+
+#[test]
+fn streaming_spline() {
+  use splines::stream::StreamingSpline;
+
+  let mut stream = StreamingSpline::new(4);
+  for i in 0..10 {
+    stream.push(Key::new(i as f64, (i * i) as f64, Interpolation::Linear));
+  }
+
+  assert_eq!(stream.len(), 4);
+  assert_eq!(stream.iter().next().map(|key| key.t), Some(6.));
+  assert_eq!(stream.sample(5.5), None);
+  assert_eq!(stream.sample(6.5), Some(42.5));
+  assert_eq!(stream.sample(9.), None);
+  assert_eq!(stream.clamped_sample(9.), Some(81.));
+  assert_eq!(stream.clamped_sample(0.), Some(36.));
+
+  // out of order keys are inserted where they belong
+  stream.push(Key::new(7.5, 0., Interpolation::Linear));
+  let ts: Vec<f64> = stream.iter().map(|key| key.t).collect();
+  assert_eq!(ts, vec![7., 7.5, 8., 9.]);
+
+  // catmull-rom segments spanning the whole ring buffer
+  let mut stream = StreamingSpline::with_window(100, 2.);
+  let mut spline = Spline::from_vec(vec![]);
+  for i in 0..20 {
+    let key = Key::new(i as f64 * 0.5, (i % 3) as f64, Interpolation::CatmullRom);
+    stream.push(key);
+    spline.add(key);
+  }
+
+  assert_eq!(stream.len(), 5);
+  assert_eq!(stream.iter().next().map(|key| key.t), Some(7.5));
+
+  for k in 75..=95 {
+    let t = k as f64 / 10.;
+    let reference = Spline::from_vec(stream.iter().copied().collect());
+    assert_eq!(stream.sample(t), reference.sample(t));
+  }
+}
+
+#[test]
+fn compressed_spline() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::Bezier(5.)),
+    Key::new(3., 2., Interpolation::Step(0.45)),
+    Key::new(4., 3., Interpolation::Cosine),
+    Key::new(5., -1., Interpolation::Linear),
+  ]);
+  let compressed = spline.compress().unwrap();
+
+  assert_eq!(compressed.len(), 6);
+  assert!(compressed.value_resolution() < 1e-4);
+  assert!(compressed.time_resolution() < 1e-4);
+  assert_eq!(compressed.get(2).unwrap().t, 2.);
+  assert_eq!(compressed.decompress().len(), 6);
+
+  for k in -10..=60 {
+    let t = k as f64 / 10.;
+
+    match (compressed.sample(t), spline.sample(t)) {
+      (Some(a), Some(b)) => assert!((a - b).abs() < 1e-3, "t = {}: {} != {}", t, a, b),
+      (a, b) => assert_eq!(a, b, "t = {}", t),
+    }
+
+    match (compressed.clamped_sample(t), spline.clamped_sample(t)) {
+      (Some(a), Some(b)) => assert!((a - b).abs() < 1e-3, "t = {}: {} != {}", t, a, b),
+      (a, b) => assert_eq!(a, b, "t = {}", t),
+    }
+  }
+
+  let empty = Spline::<f64, f64>::from_vec(vec![]);
+  assert!(empty.compress().is_none());
+
+  let crowded = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1e-9, 1., Interpolation::Linear),
+    Key::new(1., 0., Interpolation::Linear),
+  ]);
+  assert!(crowded.compress().is_none());
+}
+
+#[test]
+fn spline_builder() {
+  use splines::builder::{BuildError, SplineBuilder};
+
+  let spline = SplineBuilder::new()
+    .key(0., 0.)
+    .step(0.5)
+    .key(1., 1.)
+    .key(2., 4.)
+    .bezier(5.)
+    .tangent_constraint(TangentConstraint::Mirrored)
+    .key(3., 2.)
+    .incoming(Interpolation::StrokeBezier(1., 3.))
+    .cosine()
+    .key(4., 3.)
+    .fallback(-1.)
+    .build()
+    .unwrap();
+
+  let mut bezier = Key::new(2., 4., Interpolation::Bezier(5.));
+  bezier.tangent_constraint = TangentConstraint::Mirrored;
+  let mut cosine = Key::new(3., 2., Interpolation::Cosine);
+  cosine.in_interpolation = Some(Interpolation::StrokeBezier(1., 3.));
+
+  assert_eq!(
+    spline.keys(),
+    &[
+      Key::new(0., 0., Interpolation::Step(0.5)),
+      Key::new(1., 1., Interpolation::Linear),
+      bezier,
+      cosine,
+      Key::new(4., 3., Interpolation::Linear),
+    ]
+  );
+  assert_eq!(spline.sample(10.), Some(-1.));
+
+  assert_eq!(
+    SplineBuilder::<f64, f64>::new()
+      .linear()
+      .key(0., 0.)
+      .build(),
+    Err(BuildError::NoKey)
+  );
+  assert_eq!(
+    SplineBuilder::new()
+      .key(0., 0.)
+      .key(2., 1.)
+      .key(1., 2.)
+      .key(f64::NAN, 3.)
+      .build(),
+    Err(BuildError::UnsortedKeys(2))
+  );
+}
+
+#[test]
+fn spline_macro() {
+  use splines::spline;
+
+  let value = |x: f64| x * 2.;
+  let spline: Spline<f64, f64> = spline![
+    3. => value(1.); cosine,
+    0. => 0.; linear,
+    1. => -1.; step(0.25),
+    2. => 1. + 1.; bezier(5.),
+    2.5 => 4.; stroke_bezier(3., 5.),
+    4. => 0.; catmull_rom,
+    5. => 1.
+  ];
+
+  assert_eq!(
+    spline.keys(),
+    &[
+      Key::new(0., 0., Interpolation::Linear),
+      Key::new(1., -1., Interpolation::Step(0.25)),
+      Key::new(2., 2., Interpolation::Bezier(5.)),
+      Key::new(2.5, 4., Interpolation::StrokeBezier(3., 5.)),
+      Key::new(3., 2., Interpolation::Cosine),
+      Key::new(4., 0., Interpolation::CatmullRom),
+      Key::new(5., 1., Interpolation::default()),
+    ]
+  );
+
+  let single: Spline<f64, f64> = spline![0. => 1.,];
+  assert_eq!(single.keys(), &[Key::new(0., 1., Interpolation::default())]);
+
+  let empty: Spline<f64, f64> = spline![];
+  assert!(empty.is_empty());
+
+  // keys are not munched one by one, so long lists don’t hit the recursion limit
+  let long: Spline<f64, f64> = spline![
+    0. => 0.; linear, 1. => 1., 2. => 2., 3. => 3.; linear, 4. => 4., 5. => 5., 6. => 6.; linear,
+    7. => 0., 8. => 1., 9. => 2.; linear, 10. => 3., 11. => 4., 12. => 5.; linear, 13. => 6.,
+    14. => 0., 15. => 1.; linear, 16. => 2., 17. => 3., 18. => 4.; linear, 19. => 5., 20. => 6.,
+    21. => 0.; linear, 22. => 1., 23. => 2., 24. => 3.; linear, 25. => 4., 26. => 5.,
+    27. => 6.; linear, 28. => 0., 29. => 1., 30. => 2.; linear, 31. => 3., 32. => 4.,
+    33. => 5.; linear, 34. => 6., 35. => 0., 36. => 1.; linear, 37. => 2., 38. => 3.,
+    39. => 4.; linear, 40. => 5., 41. => 6., 42. => 0.; linear, 43. => 1., 44. => 2.,
+    45. => 3.; linear, 46. => 4., 47. => 5., 48. => 6.; linear, 49. => 0., 50. => 1.,
+    51. => 2.; linear, 52. => 3., 53. => 4., 54. => 5.; linear, 55. => 6., 56. => 0.,
+    57. => 1.; linear, 58. => 2., 59. => 3., 60. => 4.; linear, 61. => 5., 62. => 6.,
+    63. => 0.; linear, 64. => 1., 65. => 2., 66. => 3.; linear, 67. => 4., 68. => 5.,
+    69. => 6.; linear, 70. => 0., 71. => 1., 72. => 2.; linear, 73. => 3., 74. => 4.,
+    75. => 5.; linear, 76. => 6., 77. => 0., 78. => 1.; linear, 79. => 2., 80. => 3.,
+    81. => 4.; linear, 82. => 5., 83. => 6., 84. => 0.; linear, 85. => 1., 86. => 2.,
+    87. => 3.; linear, 88. => 4., 89. => 5., 90. => 6.; linear, 91. => 0., 92. => 1.,
+    93. => 2.; linear, 94. => 3., 95. => 4., 96. => 5.; linear, 97. => 6., 98. => 0.,
+    99. => 1.; linear, 100. => 2., 101. => 3., 102. => 4.; linear, 103. => 5., 104. => 6.,
+    105. => 0.; linear, 106. => 1., 107. => 2., 108. => 3.; linear, 109. => 4., 110. => 5.,
+    111. => 6.; linear, 112. => 0., 113. => 1., 114. => 2.; linear, 115. => 3., 116. => 4.,
+    117. => 5.; linear, 118. => 6., 119. => 0., 120. => 1.; linear, 121. => 2., 122. => 3.,
+    123. => 4.; linear, 124. => 5., 125. => 6., 126. => 0.; linear, 127. => 1., 128. => 2.,
+    129. => 3.; linear, 130. => 4., 131. => 5., 132. => 6.; linear, 133. => 0., 134. => 1.,
+    135. => 2.; linear, 136. => 3., 137. => 4., 138. => 5.; linear, 139. => 6., 140. => 0.,
+    141. => 1.; linear, 142. => 2., 143. => 3., 144. => 4.; linear, 145. => 5., 146. => 6.,
+    147. => 0.; linear, 148. => 1., 149. => 2., 150. => 3.; linear, 151. => 4., 152. => 5.,
+    153. => 6.; linear, 154. => 0., 155. => 1., 156. => 2.; linear, 157. => 3., 158. => 4.,
+    159. => 5.; linear, 160. => 6., 161. => 0., 162. => 1.; linear, 163. => 2., 164. => 3.,
+    165. => 4.; linear, 166. => 5., 167. => 6., 168. => 0.; linear, 169. => 1., 170. => 2.,
+    171. => 3.; linear, 172. => 4., 173. => 5., 174. => 6.; linear, 175. => 0., 176. => 1.,
+    177. => 2.; linear, 178. => 3., 179. => 4., 180. => 5.; linear, 181. => 6., 182. => 0.,
+    183. => 1.; linear, 184. => 2., 185. => 3., 186. => 4.; linear, 187. => 5., 188. => 6.,
+    189. => 0.; linear, 190. => 1., 191. => 2., 192. => 3.; linear, 193. => 4., 194. => 5.,
+    195. => 6.; linear, 196. => 0., 197. => 1., 198. => 2.; linear, 199. => 3., 200. => 4.,
+    201. => 5.; linear, 202. => 6., 203. => 0., 204. => 1.; linear, 205. => 2., 206. => 3.,
+    207. => 4.; linear, 208. => 5., 209. => 6., 210. => 0.; linear, 211. => 1., 212. => 2.,
+    213. => 3.; linear, 214. => 4., 215. => 5., 216. => 6.; linear, 217. => 0., 218. => 1.,
+    219. => 2.; linear, 220. => 3., 221. => 4., 222. => 5.; linear, 223. => 6., 224. => 0.,
+    225. => 1.; linear, 226. => 2., 227. => 3., 228. => 4.; linear, 229. => 5., 230. => 6.,
+    231. => 0.; linear, 232. => 1., 233. => 2., 234. => 3.; linear, 235. => 4., 236. => 5.,
+    237. => 6.; linear, 238. => 0., 239. => 1., 240. => 2.; linear, 241. => 3., 242. => 4.,
+    243. => 5.; linear, 244. => 6., 245. => 0., 246. => 1.; linear, 247. => 2., 248. => 3.,
+    249. => 4.; linear, 250. => 5., 251. => 6., 252. => 0.; linear, 253. => 1., 254. => 2.,
+    255. => 3.; linear, 256. => 4., 257. => 5., 258. => 6.; linear, 259. => 0., 260. => 1.,
+    261. => 2.; linear, 262. => 3., 263. => 4., 264. => 5.; linear, 265. => 6., 266. => 0.,
+    267. => 1.; linear, 268. => 2., 269. => 3., 270. => 4.; linear, 271. => 5., 272. => 6.,
+    273. => 0.; linear, 274. => 1., 275. => 2., 276. => 3.; linear, 277. => 4., 278. => 5.,
+    279. => 6.; linear, 280. => 0., 281. => 1., 282. => 2.; linear, 283. => 3., 284. => 4.,
+    285. => 5.; linear, 286. => 6., 287. => 0., 288. => 1.; linear, 289. => 2., 290. => 3.,
+    291. => 4.; linear, 292. => 5., 293. => 6., 294. => 0.; linear, 295. => 1., 296. => 2.,
+    297. => 3.; linear, 298. => 4., 299. => 5.,
+  ];
+  assert_eq!(long.len(), 300);
+  assert_eq!(
+    long.keys()[299],
+    Key::new(299., 5., Interpolation::default())
+  );
+  assert_eq!(long.keys()[297], Key::new(297., 3., Interpolation::Linear));
+}
+
+#[test]
+fn try_sample() {
+  use splines::spline::SampleError;
+
+  let empty = Spline::<f64, f64>::from_vec(vec![]);
+  assert_eq!(empty.try_sample(0.), Err(SampleError::Empty));
+  assert_eq!(empty.try_clamped_sample(0.), Err(SampleError::Empty));
+
+  let mut spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::CatmullRom),
+    Key::new(3., 2., Interpolation::CatmullRom),
+    Key::new(4., 0., Interpolation::Linear),
+  ]);
+  spline.set_fallback(Some(-1.));
+
+  assert_eq!(spline.try_sample(-1.), Err(SampleError::OutOfDomain));
+  assert_eq!(spline.try_sample(4.), Err(SampleError::OutOfDomain));
+  assert_eq!(spline.try_sample(0.5), Err(SampleError::NotEnoughKeys(0)));
+  assert_eq!(spline.try_sample(2.5), Ok(3.3125));
+  assert_eq!(spline.try_sample(3.5), Err(SampleError::NotEnoughKeys(3)));
+  assert_eq!(spline.try_clamped_sample(-1.), Ok(0.));
+  assert_eq!(spline.try_clamped_sample(5.), Ok(0.));
+  assert_eq!(
+    spline.try_clamped_sample(0.5),
+    Err(SampleError::NotEnoughKeys(0))
+  );
+
+  let single = Spline::from_vec(vec![Key::new(1., 3., Interpolation::Linear)]);
+  assert_eq!(single.try_sample(1.), Ok(3.));
+  assert_eq!(single.try_sample(2.), Err(SampleError::OutOfDomain));
+}
+
+#[test]
+fn index_keys() {
+  let mut spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+  ]);
+
+  assert_eq!(spline[1].value, 10.);
+
+  *spline.key_mut(0).value = 5.;
+  *spline.key_mut(1).interpolation = Interpolation::Step(0.5);
+
+  assert_eq!(spline[0], Key::new(0., 5., Interpolation::Linear));
+  assert_eq!(spline[1].interpolation, Interpolation::Step(0.5));
+  assert_eq!(spline.sample(0.5), Some(7.5));
+}
+
+#[test]
+#[should_panic]
+fn index_keys_out_of_bounds() {
+  let mut spline = Spline::<f64, f64>::from_vec(vec![]);
+  spline.key_mut(0);
+}
+
+#[test]
+fn collect_and_extend_keys() {
+  let mut spline: Spline<f64, f64> = [2., 0., 1.]
+    .iter()
+    .map(|&t| Key::new(t, t * 10., Interpolation::Linear))
+    .collect();
+
+  assert_eq!(
+    spline.keys().iter().map(|k| k.t).collect::<Vec<_>>(),
+    [0., 1., 2.]
+  );
+
+  spline.extend(vec![
+    Key::new(3., 30., Interpolation::Linear),
+    Key::new(0.5, 5., Interpolation::Linear),
+  ]);
+
+  assert_eq!(
+    spline.keys().iter().map(|k| k.t).collect::<Vec<_>>(),
+    [0., 0.5, 1., 2., 3.]
+  );
+  assert_eq!(spline.sample(2.5), Some(25.));
+}
+
+#[test]
+fn into_keys() {
+  let spline = Spline::from_vec(vec![
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(0., 0., Interpolation::Linear),
+  ]);
+  let keys = spline.clone().into_keys();
+
+  assert_eq!(keys, spline.keys());
+
+  let shifted: Spline<f64, f64> = spline
+    .into_iter()
+    .map(|key| Key::new(key.t + 1., key.value, key.interpolation))
+    .collect();
+
+  assert_eq!(shifted.sample(1.5), Some(5.));
+  assert_eq!(shifted.into_iter().next_back().map(|key| key.t), Some(2.));
+}
+
+#[test]
+fn iter_mut_keys() {
+  let mut spline = Spline::from_vec(vec![
+    Key::new(0., 1., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Linear),
+    Key::new(2., 3., Interpolation::Linear),
+  ]);
+
+  for value in spline.values_mut() {
+    *value *= 10.;
+  }
+
+  assert_eq!(
+    spline.keys().iter().map(|k| k.value).collect::<Vec<_>>(),
+    [10., 20., 30.]
+  );
+
+  for key in spline.iter_mut().skip(1) {
+    *key.interpolation = Interpolation::Step(0.5);
+  }
+
+  assert_eq!(spline.iter_mut().len(), 3);
+  assert_eq!(spline[0].interpolation, Interpolation::Linear);
+  assert_eq!(spline[2].interpolation, Interpolation::Step(0.5));
+  assert_eq!(spline.sample(0.5), Some(15.));
+  assert_eq!(spline.sample(1.75), Some(30.));
+}
+
+#[test]
+fn retain_and_drain_keys() {
+  let mut spline: Spline<f64, f64> = (0..6)
+    .map(|i| Key::new(i as f64, i as f64 * 10., Interpolation::Linear))
+    .collect();
+
+  spline.retain(|key| key.t != 2.);
+  assert_eq!(
+    spline.keys().iter().map(|k| k.t).collect::<Vec<_>>(),
+    [0., 1., 3., 4., 5.]
+  );
+
+  let drained: Vec<_> = spline.drain(1..3).map(|key| key.t).collect();
+  assert_eq!(drained, [1., 3.]);
+  assert_eq!(
+    spline.keys().iter().map(|k| k.t).collect::<Vec<_>>(),
+    [0., 4., 5.]
+  );
+  assert_eq!(spline.sample(2.), Some(20.));
+
+  spline.drain(..);
+  assert!(spline.is_empty());
+}
+
+#[test]
+fn keys_between() {
+  let spline: Spline<f64, f64> = (0..5)
+    .map(|i| Key::new(i as f64, 0., Interpolation::Linear))
+    .collect();
+  let ts = |keys: &[Key<f64, f64>]| keys.iter().map(|k| k.t).collect::<Vec<_>>();
+
+  assert_eq!(ts(spline.keys_between(1.0..3.)), [1., 2.]);
+  assert_eq!(ts(spline.keys_between(0.5..=3.)), [1., 2., 3.]);
+  assert_eq!(ts(spline.keys_between(..2.)), [0., 1.]);
+  assert_eq!(ts(spline.keys_between(3.5..)), [4.]);
+  assert_eq!(ts(spline.keys_between(..)), [0., 1., 2., 3., 4.]);
+  assert!(spline.keys_between(3.0..1.).is_empty());
+  assert!(spline.keys_between(5.0..6.).is_empty());
+}
+
+#[test]
+fn key_index_at() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(3., 20., Interpolation::Linear),
+  ]);
+
+  assert_eq!(spline.key_index_at(1.), Ok(1));
+  assert_eq!(spline.key_index_at(2.), Err(2));
+  assert_eq!(spline.key_index_at(-1.), Err(0));
+  assert_eq!(spline.key_index_at(4.), Err(3));
+  assert_eq!(
+    Spline::<f64, f64>::from_vec(vec![]).key_index_at(0.),
+    Err(0)
+  );
+}
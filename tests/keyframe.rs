@@ -0,0 +1,35 @@
+#![cfg(feature = "keyframe")]
+
+use keyframe::AnimationSequence;
+use splines::{convert::ConversionError, Interpolation, Key, Spline};
+
+#[test]
+fn animation_sequence() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(2., 20., Interpolation::Step(0.5)),
+    Key::new(3., 30., Interpolation::Linear),
+  ]);
+  let mut sequence = AnimationSequence::try_from(&spline).unwrap();
+
+  assert_eq!(sequence.keyframes(), 4);
+  assert_eq!(sequence.duration(), 3.);
+
+  for &t in &[0.25, 1.25, 1.5, 2.25, 2.75] {
+    sequence.advance_to(t);
+    let expected: f64 = spline.sample(t).unwrap();
+    assert!((sequence.now() - expected).abs() < 1e-9, "at t = {}", t);
+  }
+
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Bezier(15.)),
+    Key::new(2., 20., Interpolation::Linear),
+  ]);
+
+  assert_eq!(
+    AnimationSequence::try_from(&spline).err(),
+    Some(ConversionError::UnsupportedSegment(1))
+  );
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "kurbo")]
+
+use kurbo::{BezPath, ParamCurve, PathEl, Point, Vec2};
+use splines::{convert::ConversionError, Interpolation, Key, Spline};
+
+#[test]
+fn bez_path_round_trip() {
+  let mut path = BezPath::new();
+  path.move_to((0., 0.));
+  path.line_to((1., 0.));
+  path.quad_to((2., 1.), (3., 0.));
+  path.curve_to((3., 2.), (1., 2.), (0., 1.));
+  path.close_path();
+
+  let spline = Spline::<f64, Vec2>::try_from(&path).unwrap();
+  assert_eq!(spline.len(), 5);
+  assert_eq!(spline.sample(0.5), Some(Vec2::new(0.5, 0.)));
+  assert_eq!(spline.sample(1.5), Some(Vec2::new(2., 0.5)));
+  assert_eq!(spline.clamped_sample(4.), Some(Vec2::new(0., 0.)));
+
+  let curve = path.segments().nth(2).unwrap();
+  let sampled = spline.sample(2.25).unwrap();
+  assert!((curve.eval(0.25).to_vec2() - sampled).hypot() < 1e-12);
+
+  let back = BezPath::try_from(&spline).unwrap();
+  assert_eq!(
+    back.elements(),
+    &[
+      PathEl::MoveTo(Point::new(0., 0.)),
+      PathEl::LineTo(Point::new(1., 0.)),
+      PathEl::QuadTo(Point::new(2., 1.), Point::new(3., 0.)),
+      PathEl::CurveTo(Point::new(3., 2.), Point::new(1., 2.), Point::new(0., 1.)),
+      PathEl::LineTo(Point::new(0., 0.)),
+    ]
+  );
+}
+
+#[test]
+fn bez_path_errors() {
+  let mut path = BezPath::new();
+  path.move_to((0., 0.));
+  path.line_to((1., 0.));
+  path.move_to((2., 0.));
+  path.line_to((3., 0.));
+
+  assert_eq!(
+    Spline::<f64, Vec2>::try_from(&path),
+    Err(ConversionError::Disconnected)
+  );
+
+  let spline = Spline::from_vec(vec![
+    Key::new(0., Vec2::new(0., 0.), Interpolation::Linear),
+    Key::new(1., Vec2::new(1., 0.), Interpolation::Cosine),
+    Key::new(2., Vec2::new(1., 1.), Interpolation::Linear),
+  ]);
+
+  assert_eq!(
+    BezPath::try_from(&spline),
+    Err(ConversionError::UnsupportedSegment(1))
+  );
+}
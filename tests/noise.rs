@@ -0,0 +1,32 @@
+#![cfg(feature = "noise")]
+
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn noise_modulation() {
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(10., 10., Interpolation::Linear),
+  ]);
+  let noisy = spline.modulate_with_noise(42, 4., 0.5);
+  let other = spline.modulate_with_noise(43, 4., 0.5);
+  let mut previous = noisy.sample(0.).unwrap();
+  let mut differs = false;
+
+  for i in 1..1000 {
+    let t = i as f64 / 100.;
+    let value = noisy.sample(t).unwrap();
+
+    // bounded by the amplitude, deterministic and continuous
+    assert!((value - t).abs() <= 0.5);
+    assert_eq!(noisy.sample(t), Some(value));
+    assert!((value - previous).abs() < 0.1);
+
+    differs |= other.sample(t) != Some(value);
+    previous = value;
+  }
+
+  assert!(differs);
+  assert_eq!(noisy.sample(11.), None);
+  assert!(noisy.clamped_sample(11.).is_some());
+}
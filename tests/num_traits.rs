@@ -0,0 +1,207 @@
+#![cfg(feature = "num-traits")]
+
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn blanket_real_sampling() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let k1 = Key::new(1., 5., Interpolation::Cosine);
+  let end = Key::new(2., 10., Interpolation::default());
+  let spline = Spline::<f64, f64>::from_vec(vec![start, k1, end]);
+
+  assert_eq!(spline.sample(0.5), Some(2.5));
+  assert!((spline.sample(1.5).unwrap() - 7.5).abs() < 1e-9);
+  assert!((spline.arc_length(0., 2.).unwrap() - 10.).abs() < 1e-9);
+}
+
+#[test]
+fn blanket_real_mixed_precision() {
+  let start = Key::new(0f32, 0f64, Interpolation::Linear);
+  let end = Key::new(1f32, 10f64, Interpolation::default());
+  let spline = Spline::from_vec(vec![start, end]);
+
+  assert_eq!(spline.sample(0.5), Some(5.));
+}
+
+// A real number type that is not a primitive, as software-emulated floating-point types would be.
+// It wraps a f64 for simplicity, forwarding every operation to it.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Double(f64);
+
+macro_rules! forward_ops {
+  ($($op:ident, $f:ident);*) => {
+    $(
+      impl std::ops::$op for Double {
+        type Output = Double;
+
+        fn $f(self, rhs: Double) -> Double {
+          Double(std::ops::$op::$f(self.0, rhs.0))
+        }
+      }
+    )*
+  };
+}
+
+forward_ops!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+impl std::ops::Neg for Double {
+  type Output = Double;
+
+  fn neg(self) -> Double {
+    Double(-self.0)
+  }
+}
+
+impl num_traits::Zero for Double {
+  fn zero() -> Self {
+    Double(0.)
+  }
+
+  fn is_zero(&self) -> bool {
+    self.0 == 0.
+  }
+}
+
+impl num_traits::One for Double {
+  fn one() -> Self {
+    Double(1.)
+  }
+}
+
+impl num_traits::Num for Double {
+  type FromStrRadixErr = num_traits::ParseFloatError;
+
+  fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+    f64::from_str_radix(s, radix).map(Double)
+  }
+}
+
+impl num_traits::ToPrimitive for Double {
+  fn to_i64(&self) -> Option<i64> {
+    self.0.to_i64()
+  }
+
+  fn to_u64(&self) -> Option<u64> {
+    self.0.to_u64()
+  }
+
+  fn to_f64(&self) -> Option<f64> {
+    Some(self.0)
+  }
+}
+
+impl num_traits::NumCast for Double {
+  fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+    n.to_f64().map(Double)
+  }
+}
+
+macro_rules! forward_float {
+  (
+    constants: $($constant:ident),*;
+    predicates: $($predicate:ident),*;
+    unary: $($unary:ident),*;
+    binary: $($binary:ident),*;
+  ) => {
+    $(
+      fn $constant() -> Self {
+        Double(<f64 as num_traits::Float>::$constant())
+      }
+    )*
+
+    $(
+      fn $predicate(self) -> bool {
+        <f64 as num_traits::Float>::$predicate(self.0)
+      }
+    )*
+
+    $(
+      fn $unary(self) -> Self {
+        Double(<f64 as num_traits::Float>::$unary(self.0))
+      }
+    )*
+
+    $(
+      fn $binary(self, other: Self) -> Self {
+        Double(<f64 as num_traits::Float>::$binary(self.0, other.0))
+      }
+    )*
+  };
+}
+
+impl num_traits::Float for Double {
+  forward_float! {
+    constants: nan, infinity, neg_infinity, neg_zero, min_value, min_positive_value, max_value;
+    predicates: is_nan, is_infinite, is_finite, is_normal, is_sign_positive, is_sign_negative;
+    unary: floor, ceil, round, trunc, fract, abs, signum, recip, sqrt, exp, exp2, ln, log2, log10,
+      cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh, asinh, acosh, atanh;
+    binary: powf, log, max, min, abs_sub, hypot, atan2;
+  }
+
+  fn classify(self) -> std::num::FpCategory {
+    self.0.classify()
+  }
+
+  fn mul_add(self, a: Self, b: Self) -> Self {
+    Double(self.0.mul_add(a.0, b.0))
+  }
+
+  fn powi(self, n: i32) -> Self {
+    Double(self.0.powi(n))
+  }
+
+  fn sin_cos(self) -> (Self, Self) {
+    let (sin, cos) = self.0.sin_cos();
+    (Double(sin), Double(cos))
+  }
+
+  fn integer_decode(self) -> (u64, i16, i8) {
+    num_traits::Float::integer_decode(self.0)
+  }
+}
+
+#[test]
+fn blanket_real_custom_type() {
+  let modes = [
+    Interpolation::Linear,
+    Interpolation::Cosine,
+    Interpolation::CatmullRom,
+    Interpolation::CatmullRom,
+    Interpolation::Bezier(4.),
+    Interpolation::default(),
+  ];
+  let keys: Vec<_> = modes
+    .iter()
+    .enumerate()
+    .map(|(i, &mode)| Key::new(i as f64, (i * i) as f64, mode))
+    .collect();
+  let spline = Spline::from_vec(keys.clone());
+  let real = Spline::from_vec(
+    keys
+      .iter()
+      .map(|key| {
+        let mode = match key.interpolation {
+          Interpolation::Bezier(u) => Interpolation::Bezier(Double(u)),
+          Interpolation::Cosine => Interpolation::Cosine,
+          Interpolation::CatmullRom => Interpolation::CatmullRom,
+          _ => Interpolation::Linear,
+        };
+
+        Key::new(Double(key.t), Double(key.value), mode)
+      })
+      .collect(),
+  );
+
+  for i in 0..50 {
+    let t = i as f64 / 10.;
+    assert_eq!(
+      real.sample(Double(t)).map(|x| x.0),
+      spline.sample(t),
+      "t = {}",
+      t
+    );
+  }
+
+  let length = real.arc_length(Double(0.), Double(1.)).unwrap();
+  assert!((length.0 - 1.).abs() < 1e-9);
+}
@@ -0,0 +1,54 @@
+use splines::periodic::PeriodicSpline;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn periodic_wraps() {
+  let spline = Spline::<f64, f64>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::Linear),
+  ]);
+  let periodic = PeriodicSpline::new(spline, 4.).unwrap();
+
+  assert_eq!(periodic.len(), 3);
+  assert_eq!(periodic.sample(0.5), Some(5.));
+  assert_eq!(periodic.sample(4.5), Some(5.));
+  assert_eq!(periodic.sample(-3.5), Some(5.));
+
+  // the seam goes from the last key back to the first one
+  assert_eq!(periodic.sample(3.), Some(10.));
+  assert_eq!(periodic.sample_with_key(3.).unwrap().key, 2);
+  assert_eq!(periodic.sample(4.), Some(0.));
+}
+
+#[test]
+fn periodic_catmull_rom_seam() {
+  // points on a circle, sampled with Catmull-Rom: the curve must be smooth across the seam
+  let keys = (0..8)
+    .map(|i| {
+      let angle = i as f64 * std::f64::consts::PI / 4.;
+      Key::new(i as f64, angle.cos(), Interpolation::CatmullRom)
+    })
+    .collect();
+  let periodic = PeriodicSpline::new(Spline::from_vec(keys), 8.).unwrap();
+
+  for &t in &[7.5, 0.5, -0.5, 100.25] {
+    assert!(periodic.sample(t).is_some());
+  }
+
+  // symmetric around the first key, which is a maximum
+  let before = periodic.sample(-0.25).unwrap();
+  let after = periodic.sample(0.25).unwrap();
+  assert!((before - after).abs() < 1e-12);
+
+  // just below the seam, wrapping rounds up to the end of the period
+  assert_eq!(periodic.wrap(-1e-20), 0.);
+  assert_eq!(periodic.sample(-1e-20), periodic.sample(0.));
+
+  assert!(PeriodicSpline::new(Spline::<f64, f64>::from_vec(vec![]), 1.).is_none());
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Linear),
+  ]);
+  assert!(PeriodicSpline::<f64, f64>::new(spline, 1.).is_none());
+}
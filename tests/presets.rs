@@ -0,0 +1,59 @@
+use splines::presets;
+use splines::Spline;
+
+// Maximum distance between a spline and a function, sampled densely over the spline.
+fn max_error(spline: &Spline<f64, f64>, f: impl Fn(f64) -> f64) -> f64 {
+  let keys = spline.keys();
+  let start = keys[0].t;
+  let end = keys[keys.len() - 1].t;
+
+  (0..=1000)
+    .map(|i| {
+      let t = start + (end - start) * i as f64 / 1000.;
+      (spline.clamped_sample(t).unwrap() - f(t)).abs()
+    })
+    .fold(0., f64::max)
+}
+
+#[test]
+fn sigmoid_ramp() {
+  let spline: Spline<f64, f64> = presets::sigmoid(2., 1., 5., 10., 1e-4);
+  let logistic = |x: f64| 1. / (1. + (-10. * (x - 0.5)).exp());
+  let f = |t: f64| 1. + 4. * (logistic(t / 2.) - logistic(0.)) / (logistic(1.) - logistic(0.));
+
+  assert_eq!(spline.clamped_sample(0.), Some(1.));
+  assert!((spline.clamped_sample(2.).unwrap() - 5.).abs() < 1e-12);
+  assert!(max_error(&spline, f) < 1e-4);
+}
+
+#[test]
+fn exponential_decay_and_bell() {
+  let decay = presets::exponential_decay(5., 10., 2., 1., 1e-4);
+  assert!(max_error(&decay, |t| 2. + 8. * 0.5f64.powf(t)) < 1e-4);
+
+  let bell = presets::bell(4., 3., 0.5, 1e-4);
+  assert!(max_error(&bell, |t| 3. * (-(t - 2.) * (t - 2.) / 0.5).exp()) < 1e-4);
+  assert!((bell.sample(2.).unwrap() - 3.).abs() < 1e-4);
+}
+
+#[test]
+fn lfos() {
+  let sine = presets::sine(2., -1., 1., 1e-5);
+  assert!(max_error(&sine, |t| (std::f64::consts::PI * t).sin()) < 1e-5);
+
+  let sawtooth = presets::sawtooth(2., 0., 4.);
+  assert_eq!(sawtooth.sample(0.5), Some(1.));
+
+  let triangle = presets::triangle(2., 0., 4.);
+  assert_eq!(triangle.sample(0.5), Some(2.));
+  assert_eq!(triangle.sample(1.5), Some(2.));
+}
+
+#[test]
+fn tolerance_drives_key_count() {
+  let coarse = presets::sine(1., -1., 1., 1e-2);
+  let fine = presets::sine(1., -1., 1., 1e-6);
+
+  assert!(coarse.len() < fine.len());
+  assert!(presets::sine(0., -1., 1., 1e-2).is_empty());
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "rayon")]
+
+use splines::{Interpolation, Key, Spline};
+
+fn spline() -> Spline<f64, f64> {
+  Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::Bezier(5.)),
+    Key::new(3., 2., Interpolation::Step(0.5)),
+    Key::new(4., 3., Interpolation::Cosine),
+    Key::new(5., 0., Interpolation::Linear),
+  ])
+}
+
+#[test]
+fn par_sample_many() {
+  let spline = spline();
+  let ts: Vec<f64> = (-100..10_000).map(|k| (k % 600) as f64 / 100.).collect();
+
+  assert_eq!(spline.par_sample_many(&ts), spline.sample_many(&ts));
+}
+
+#[test]
+fn par_bake() {
+  let spline = spline();
+  let baked = spline.par_bake(-1., 6., 701);
+
+  assert_eq!(baked.len(), 701);
+
+  for (i, value) in baked.into_iter().enumerate() {
+    let t = if i == 700 { 6. } else { -1. + 0.01 * i as f64 };
+    assert_eq!(value, spline.clamped_sample(t), "t = {}", t);
+  }
+
+  assert_eq!(
+    spline.par_bake(2.5, 3., 1),
+    vec![spline.clamped_sample(2.5)]
+  );
+  assert!(spline.par_bake(0., 1., 0).is_empty());
+}
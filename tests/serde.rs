@@ -0,0 +1,440 @@
+#![cfg(feature = "serde")]
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use splines::{schema::Schema, Interpolation, Key, Spline, TangentConstraint};
+use std::fmt::Debug;
+
+fn all_modes() -> Vec<Interpolation<f64, f64>> {
+  vec![
+    Interpolation::Step(0.25),
+    Interpolation::Linear,
+    Interpolation::Cosine,
+    Interpolation::CatmullRom,
+    Interpolation::Bezier(2.),
+    Interpolation::StrokeBezier(-1., 3.),
+  ]
+}
+
+fn spline() -> Spline<f64, f64> {
+  let constraints = [
+    TangentConstraint::Free,
+    TangentConstraint::Aligned,
+    TangentConstraint::Mirrored,
+  ];
+
+  let keys = all_modes()
+    .into_iter()
+    .enumerate()
+    .flat_map(|(i, interpolation)| {
+      constraints.iter().enumerate().map(move |(j, &constraint)| {
+        let mut key = Key::new((i * 3 + j) as f64, j as f64 * 0.5, interpolation);
+        key.tangent_constraint = constraint;
+        key
+      })
+    })
+    .collect();
+
+  Spline::from_vec(keys)
+}
+
+#[test]
+fn round_trip() {
+  let spline = spline();
+
+  let json = serde_json::to_string(&spline).unwrap();
+  assert_eq!(
+    serde_json::from_str::<Spline<f64, f64>>(&json).unwrap(),
+    spline
+  );
+
+  let value = serde_json::to_value(&spline).unwrap();
+  assert_eq!(
+    serde_json::from_value::<Spline<f64, f64>>(value).unwrap(),
+    spline
+  );
+
+  let mut broken = Key::new(1., 2., Interpolation::Step(0.5));
+  broken.in_interpolation = Some(Interpolation::Bezier(3.));
+  let spline = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 1., Interpolation::StrokeBezier(0.5, 1.5)),
+    broken,
+  ]);
+  let json = serde_json::to_string(&spline).unwrap();
+  assert_eq!(
+    serde_json::from_str::<Spline<f32, f32>>(&json).unwrap(),
+    spline
+  );
+}
+
+#[test]
+fn values_without_default() {
+  #[derive(Debug, PartialEq, serde::Deserialize)]
+  struct Label(f32);
+
+  let json = r#"[{ "t": 0, "value": 1, "interpolation": "linear" }]"#;
+  let spline = serde_json::from_str::<Spline<f32, Label>>(json).unwrap();
+
+  assert_eq!(spline.keys()[0].value, Label(1.));
+}
+
+#[test]
+fn tagged_spline_round_trip() {
+  use splines::track::{TaggedSpline, TrackKind};
+
+  let spline = spline().with_kind(TrackKind::Rotation);
+
+  // the tag is not part of the format of splines…
+  let json = serde_json::to_string(&spline).unwrap();
+  assert_eq!(
+    serde_json::from_str::<Spline<f64, f64>>(&json)
+      .unwrap()
+      .kind(),
+    None
+  );
+
+  // … but is kept by tagged splines
+  let value = serde_json::to_value(TaggedSpline(spline.clone())).unwrap();
+  assert_eq!(value["kind"], "rotation");
+  assert_eq!(value["keys"], serde_json::to_value(&spline).unwrap());
+  assert_eq!(
+    serde_json::from_value::<TaggedSpline<f64, f64>>(value).unwrap(),
+    TaggedSpline(spline)
+  );
+
+  let json = r#"{ "keys": [{ "t": 0, "value": 1, "interpolation": "linear" }] }"#;
+  let tagged = serde_json::from_str::<TaggedSpline<f32, f32>>(json).unwrap();
+  assert_eq!(tagged.0.kind(), None);
+  assert_eq!(serde_json::to_value(&tagged).unwrap().get("kind"), None);
+}
+
+#[test]
+fn tangent_constraint_is_optional() {
+  let json = r#"[{ "t": 0, "value": 1, "interpolation": "linear" }]"#;
+  let spline = serde_json::from_str::<Spline<f32, f32>>(json).unwrap();
+
+  assert_eq!(spline.keys()[0].tangent_constraint, TangentConstraint::Free);
+
+  // free constraints and missing incoming modes are not serialized, keeping the format of keys
+  // from previous versions
+  let value = serde_json::to_value(&spline).unwrap();
+  assert_eq!(
+    value,
+    serde_json::json!([{ "t": 0., "value": 1., "interpolation": "linear" }])
+  );
+}
+
+#[test]
+fn schema_describes_serialized_keys() {
+  let schema: Value = serde_json::from_str(&Spline::<f64, f64>::schema_json()).unwrap();
+  let defs = &schema["$defs"];
+  let key_properties = defs["key"]["properties"].as_object().unwrap();
+  let modes = defs["interpolation"]["oneOf"].as_array().unwrap();
+  let constraints = defs["tangent_constraint"]["enum"].as_array().unwrap();
+
+  assert_eq!(schema["type"], "array");
+
+  for key in serde_json::to_value(spline()).unwrap().as_array().unwrap() {
+    for field in key.as_object().unwrap().keys() {
+      assert!(key_properties.contains_key(field), "{field} not in schema");
+    }
+
+    let described = match &key["interpolation"] {
+      Value::String(mode) => modes[0]["enum"]
+        .as_array()
+        .unwrap()
+        .contains(&mode.as_str().into()),
+      Value::Object(mode) => {
+        let name = mode.keys().next().unwrap();
+        modes[1..]
+          .iter()
+          .any(|schema| schema["properties"].get(name).is_some())
+      }
+      _ => false,
+    };
+    assert!(described, "{} not in schema", key["interpolation"]);
+
+    if let Some(constraint) = key.get("tangent_constraint") {
+      assert!(constraints.contains(constraint));
+    }
+  }
+}
+
+// Check a JSON value against a JSON Schema, supporting the subset of keywords used by
+// Spline::schema_json.
+fn validate(value: &Value, schema: &Value, root: &Value) -> Result<(), String> {
+  if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+    let name = reference.strip_prefix("#/$defs/").unwrap();
+    return validate(value, &root["$defs"][name], root);
+  }
+
+  if let Some(ty) = schema.get("type").and_then(Value::as_str) {
+    let valid = match ty {
+      "number" => value.is_number(),
+      "array" => value.is_array(),
+      "object" => value.is_object(),
+      _ => return Err(format!("unsupported type {ty}")),
+    };
+
+    if !valid {
+      return Err(format!("{value} is not of type {ty}"));
+    }
+  }
+
+  if let Some(variants) = schema.get("enum").and_then(Value::as_array) {
+    if !variants.contains(value) {
+      return Err(format!("{value} not in {variants:?}"));
+    }
+  }
+
+  if let Some(schemas) = schema.get("oneOf").and_then(Value::as_array) {
+    let matching = schemas
+      .iter()
+      .filter(|schema| validate(value, schema, root).is_ok())
+      .count();
+
+    if matching != 1 {
+      return Err(format!("{value} matches {matching} schemas of oneOf"));
+    }
+  }
+
+  if let Some(object) = value.as_object() {
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    for field in schema
+      .get("required")
+      .and_then(Value::as_array)
+      .into_iter()
+      .flatten()
+    {
+      if !object.contains_key(field.as_str().unwrap()) {
+        return Err(format!("{value} misses required field {field}"));
+      }
+    }
+
+    for (field, field_value) in object {
+      match properties.and_then(|properties| properties.get(field)) {
+        Some(field_schema) => validate(field_value, field_schema, root)?,
+        None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+          return Err(format!("{value} has unexpected field {field}"));
+        }
+        None => (),
+      }
+    }
+  }
+
+  if let Some(items) = value.as_array() {
+    let len = items.len() as u64;
+
+    if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+      if len < min {
+        return Err(format!("{value} has less than {min} items"));
+      }
+    }
+
+    if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+      if len > max {
+        return Err(format!("{value} has more than {max} items"));
+      }
+    }
+
+    let prefix = schema
+      .get("prefixItems")
+      .and_then(Value::as_array)
+      .map_or(&[][..], Vec::as_slice);
+
+    for (i, item) in items.iter().enumerate() {
+      if let Some(item_schema) = prefix.get(i).or_else(|| schema.get("items")) {
+        validate(item, item_schema, root)?;
+      }
+    }
+  }
+
+  Ok(())
+}
+
+// Serialize a spline, check the result against its schema and deserialize it back.
+fn assert_schema_round_trip<T, V>(spline: &Spline<T, V>)
+where
+  T: Schema + Serialize + DeserializeOwned + PartialEq + Debug,
+  V: Schema + Serialize + DeserializeOwned + PartialEq + Debug,
+{
+  let schema: Value = serde_json::from_str(&Spline::<T, V>::schema_json()).unwrap();
+  let value = serde_json::to_value(spline).unwrap();
+
+  if let Err(e) = validate(&value, &schema, &schema) {
+    panic!("{e}");
+  }
+
+  assert_eq!(
+    &serde_json::from_value::<Spline<T, V>>(value).unwrap(),
+    spline
+  );
+}
+
+// Keys using every interpolation mode, incoming mode and tangent constraint, with the given
+// values.
+fn keys_with_values<V: Copy>(a: V, b: V) -> Vec<Key<f32, V>> {
+  let modes = [
+    Interpolation::Step(0.25),
+    Interpolation::Linear,
+    Interpolation::Cosine,
+    Interpolation::CatmullRom,
+    Interpolation::Bezier(b),
+    Interpolation::StrokeBezier(a, b),
+  ];
+  let constraints = [
+    TangentConstraint::Free,
+    TangentConstraint::Aligned,
+    TangentConstraint::Mirrored,
+  ];
+
+  modes
+    .iter()
+    .enumerate()
+    .map(|(i, &interpolation)| {
+      let mut key = Key::new(i as f32, if i % 2 == 0 { a } else { b }, interpolation);
+      key.in_interpolation = modes.get(i + 1).copied();
+      key.tangent_constraint = constraints[i % constraints.len()];
+      key
+    })
+    .collect()
+}
+
+#[test]
+fn serialized_keys_match_schema() {
+  assert_schema_round_trip(&spline());
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(-1., 2.)));
+
+  // make sure the validator actually rejects keys not described by the schema
+  let schema: Value = serde_json::from_str(&Spline::<f64, f64>::schema_json()).unwrap();
+  let invalid = [
+    serde_json::json!([{ "t": 0., "value": 1. }]),
+    serde_json::json!([{ "t": 0., "value": 1., "interpolation": "cubic" }]),
+    serde_json::json!([{ "t": 0., "value": 1., "interpolation": { "step": 0.5, "bezier": 1. } }]),
+    serde_json::json!([{ "t": 0., "value": 1., "interpolation": { "stroke_bezier": [1.] } }]),
+    serde_json::json!([{ "t": 0., "value": 1., "interpolation": "linear", "tangent_constraint": "smooth" }]),
+    serde_json::json!([{ "t": "0", "value": 1., "interpolation": "linear" }]),
+  ];
+
+  for value in &invalid {
+    assert!(
+      validate(value, &schema, &schema).is_err(),
+      "{value} accepted"
+    );
+  }
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_keys_match_schema() {
+  use glam::{Quat, Vec2, Vec3, Vec3A, Vec4};
+
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vec2::new(1., 2.),
+    Vec2::new(-3., 4.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vec3::new(1., 2., 3.),
+    Vec3::new(-3., 4., 0.5),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vec3A::new(1., 2., 3.),
+    Vec3A::new(-3., 4., 0.5),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vec4::new(1., 2., 3., 4.),
+    Vec4::new(-3., 4., 0.5, 0.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Quat::IDENTITY,
+    Quat::from_xyzw(0., 1., 0., 0.),
+  )));
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn cgmath_keys_match_schema() {
+  use cgmath::{Quaternion, Vector1, Vector2, Vector3, Vector4};
+
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector1::new(1.),
+    Vector1::new(-3.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector2::new(1., 2.),
+    Vector2::new(-3., 4.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector3::new(1., 2., 3.),
+    Vector3::new(-3., 4., 0.5),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector4::new(1., 2., 3., 4.),
+    Vector4::new(-3., 4., 0.5, 0.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Quaternion::new(1., 0., 0., 0.),
+    Quaternion::new(0., 1., 0., 0.),
+  )));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_keys_match_schema() {
+  use nalgebra::{Quaternion, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6};
+
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector1::new(1.),
+    Vector1::new(-3.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector2::new(1., 2.),
+    Vector2::new(-3., 4.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector3::new(1., 2., 3.),
+    Vector3::new(-3., 4., 0.5),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector4::new(1., 2., 3., 4.),
+    Vector4::new(-3., 4., 0.5, 0.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector5::new(1., 2., 3., 4., 5.),
+    Vector5::new(-3., 4., 0.5, 0., 1.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Vector6::new(1., 2., 3., 4., 5., 6.),
+    Vector6::new(-3., 4., 0.5, 0., 1., 2.),
+  )));
+  assert_schema_round_trip(&Spline::from_vec(keys_with_values(
+    Quaternion::new(1., 0., 0., 0.),
+    Quaternion::new(0., 1., 0., 0.),
+  )));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn extra_fields_round_trip() {
+  use splines::extra::AnnotatedSpline;
+
+  let json = serde_json::json!([
+    { "t": 1., "value": 10., "interpolation": "linear", "color": "red" },
+    { "t": 0., "value": 0., "interpolation": "linear", "locked": true },
+  ]);
+  let spline: AnnotatedSpline<f64, f64> = serde_json::from_value(json).unwrap();
+
+  assert_eq!(spline.spline().sample(0.5), Some(5.));
+  assert_eq!(spline.extras()[0]["locked"], Value::Bool(true));
+  assert_eq!(spline.extras()[1]["color"], Value::from("red"));
+
+  let back = serde_json::to_value(&spline).unwrap();
+  assert_eq!(back[0]["locked"], Value::Bool(true));
+  assert_eq!(back[1]["color"], Value::from("red"));
+  assert_eq!(back[1]["t"], Value::from(1.));
+  assert_eq!(
+    serde_json::from_value::<AnnotatedSpline<f64, f64>>(back).unwrap(),
+    spline
+  );
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "shader")]
+
+use splines::{
+  shader::{ShaderLanguage, ShaderSpline},
+  Interpolation, Key, Spline,
+};
+
+// evaluate the data the way the generated functions do
+fn evaluate(data: &[f32], t: f32) -> f32 {
+  let count = data[0] as usize;
+
+  if count == 0 || t <= data[4] {
+    return data[1];
+  }
+
+  if t >= data[8 * count - 3] {
+    return data[2];
+  }
+
+  let i = (0..count).rev().find(|&i| data[4 + 8 * i] <= t).unwrap();
+  let s = &data[4 + 8 * i..12 + 8 * i];
+  let nt = (t - s[0]) / (s[1] - s[0]);
+
+  match s[2] {
+    1. if nt < s[3] => s[4],
+    1. => s[5],
+    2. => s[4] + (s[5] - s[4]) * (1. - (std::f32::consts::PI * nt).cos()) * 0.5,
+    _ => s[4] + (s[5] + (s[6] + s[7] * nt) * nt) * nt,
+  }
+}
+
+#[test]
+fn shader_data() {
+  let spline = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Bezier(4.)),
+    Key::new(2., 1., Interpolation::Step(0.5)),
+    Key::new(3., 3., Interpolation::Cosine),
+    Key::new(4., 0., Interpolation::Linear),
+  ]);
+  let ShaderSpline { source, data } = spline.to_shader(ShaderLanguage::Wgsl, "curve").unwrap();
+
+  assert!(source.starts_with("fn curve(t: f32) -> f32 {"));
+  assert!(source.contains("curve_data[0]"));
+  assert!(!source.contains("NAME"));
+  assert_eq!(data.len(), 4 + 8 * 4);
+  assert_eq!(&data[..4], &[4., 0., 0., 0.]);
+  assert_eq!(&data[4..12], &[0., 1., 0., 0., 0., 2., 0., 0.]);
+
+  for k in -10..=50 {
+    let t = k as f32 / 10.;
+    let expected = spline.clamped_sample(t).unwrap();
+    assert!((evaluate(&data, t) - expected).abs() < 1e-5, "t = {}", t);
+  }
+
+  let glsl = spline.to_shader(ShaderLanguage::Glsl, "curve").unwrap();
+  assert!(glsl.source.starts_with("float curve(float t) {"));
+  assert_eq!(glsl.data, data);
+}
+
+#[test]
+fn shader_unsupported() {
+  let single = Spline::<f32, f32>::from_vec(vec![Key::new(1., 2., Interpolation::Linear)]);
+  let ShaderSpline { data, .. } = single.to_shader(ShaderLanguage::Glsl, "single").unwrap();
+  assert_eq!(evaluate(&data, 0.), 2.);
+
+  let catmull_rom = Spline::<f32, f32>::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 2., Interpolation::CatmullRom),
+  ]);
+  assert_eq!(catmull_rom.to_shader(ShaderLanguage::Wgsl, "curve"), None);
+  assert_eq!(
+    Spline::<f32, f32>::from_vec(vec![]).to_shader(ShaderLanguage::Wgsl, "curve"),
+    None
+  );
+}
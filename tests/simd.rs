@@ -0,0 +1,59 @@
+#![cfg(feature = "wide")]
+
+use splines::{Interpolation, Key, Spline};
+use wide::f32x8;
+
+fn spline() -> Spline<f32, f32> {
+  Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 4., Interpolation::Bezier(5.)),
+    Key::new(3., 2., Interpolation::Step(0.5)),
+    Key::new(4., 3., Interpolation::Cosine),
+    Key::new(5., 0., Interpolation::Linear),
+  ])
+}
+
+#[test]
+fn simd_segment() {
+  let spline = spline();
+  let nt = [0., 0.125, 0.25, 0.375, 0.5, 0.625, 0.75, 0.875];
+
+  for i in 1..5 {
+    let values = spline
+      .sample_segment_x8(i, f32x8::from(nt))
+      .unwrap()
+      .to_array();
+
+    for (&nt, value) in nt.iter().zip(values) {
+      let expected = spline.sample_segment(i, nt).unwrap();
+      assert!((value - expected).abs() < 1e-5, "segment {} at {}", i, nt);
+    }
+  }
+
+  assert!(spline.sample_segment_x8(0, f32x8::ZERO).is_none());
+  assert!(spline.sample_segment_x8(5, f32x8::ZERO).is_none());
+}
+
+#[test]
+fn simd_batch() {
+  let spline = spline();
+  let ts: Vec<f32> = (-20..260).map(|k| k as f32 / 50.).collect();
+  let mut out = vec![-1.; ts.len()];
+  let mut out4 = vec![-1.; ts.len()];
+
+  let written = spline.sample_into_x8(&ts, &mut out);
+  let written4 = spline.sample_into_x4(&ts, &mut out4);
+
+  assert_eq!(
+    written,
+    ts.iter().filter(|&&t| spline.sample(t).is_some()).count()
+  );
+  assert_eq!(written4, written);
+
+  for ((&t, value), value4) in ts.iter().zip(out).zip(out4) {
+    let expected = spline.sample(t).unwrap_or(-1.);
+    assert!((value - expected).abs() < 1e-5, "t = {}", t);
+    assert!((value4 - expected).abs() < 1e-5, "t = {}", t);
+  }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "smallvec")]
+
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn inline_keys() {
+  let keys = vec![
+    Key::new(2., 4., Interpolation::Linear),
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(3., 2., Interpolation::Linear),
+  ];
+  let spline = Spline::from_vec(keys);
+
+  assert_eq!(spline.len(), 4);
+  assert_eq!(spline.keys()[0].t, 0.);
+  assert_eq!(spline.sample(2.5), Some(3.));
+}
+
+#[test]
+fn spilled_keys() {
+  let mut spline = Spline::from_vec(
+    (0..20)
+      .map(|i| Key::new(i as f64, i as f64 * 2., Interpolation::Linear))
+      .collect(),
+  );
+  spline.add(Key::new(20., 40., Interpolation::Linear));
+
+  assert_eq!(spline.len(), 21);
+  assert_eq!(spline.sample(12.5), Some(25.));
+  assert_eq!(spline.clamped_sample(30.), Some(40.));
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "stats")]
+
+use splines::cursor::Cursor;
+use splines::{stats, Interpolation, Key, Spline};
+
+#[test]
+fn stats_samples_and_cursors() {
+  let spline = Spline::from_vec(vec![
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(2., 0., Interpolation::default()),
+  ]);
+
+  stats::reset();
+
+  spline.sample(0.5);
+  spline.sample(1.5);
+  spline.clamped_sample(3.);
+
+  let mut cursor = Cursor::new(&spline, 0.);
+  cursor.advance(0.5);
+  cursor.advance(0.75);
+  cursor.seek(0.25);
+
+  let snapshot = stats::snapshot();
+  assert_eq!(snapshot.linear_samples, 1);
+  assert_eq!(snapshot.cosine_samples, 1);
+  assert_eq!(snapshot.total_samples(), 2);
+  assert_eq!(snapshot.out_of_range, 1);
+  assert_eq!(snapshot.cursor_hits, 3);
+  assert_eq!(snapshot.cursor_misses, 1);
+
+  stats::set_enabled(false);
+  spline.sample(0.5);
+  assert_eq!(stats::snapshot().linear_samples, 1);
+  stats::set_enabled(true);
+}